@@ -0,0 +1,47 @@
+//! Typed decoders for account data owned by the program.
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::{AccountDeserialize, Discriminator};
+
+use crate::state::{
+    ComplianceConfig, FxRate, Fundraiser, HolderLockup, Investment, Investor, InvestorFundraiser, KycAttestation,
+    WireBatchRecord,
+};
+
+/// Decodes account data as `T`, checking its discriminator.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> anchor_lang::Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Any account owned by the program
+pub enum ProgramAccount {
+    Fundraiser(Box<Fundraiser>),
+    Investor(Box<Investor>),
+    InvestorFundraiser(Box<InvestorFundraiser>),
+    Investment(Box<Investment>),
+    ComplianceConfig(ComplianceConfig),
+    KycAttestation(KycAttestation),
+    HolderLockup(HolderLockup),
+    WireBatchRecord(Box<WireBatchRecord>),
+    FxRate(FxRate),
+}
+
+impl ProgramAccount {
+    /// Decodes account data by its discriminator, e.g. for every account returned by
+    /// `getProgramAccounts`.
+    pub fn decode(data: &[u8]) -> anchor_lang::Result<Self> {
+        let discriminator = data.get(..8).ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+        Ok(match discriminator {
+            d if d == Fundraiser::DISCRIMINATOR => Self::Fundraiser(Box::new(decode(data)?)),
+            d if d == Investor::DISCRIMINATOR => Self::Investor(Box::new(decode(data)?)),
+            d if d == InvestorFundraiser::DISCRIMINATOR => Self::InvestorFundraiser(Box::new(decode(data)?)),
+            d if d == Investment::DISCRIMINATOR => Self::Investment(Box::new(decode(data)?)),
+            d if d == ComplianceConfig::DISCRIMINATOR => Self::ComplianceConfig(decode(data)?),
+            d if d == KycAttestation::DISCRIMINATOR => Self::KycAttestation(decode(data)?),
+            d if d == HolderLockup::DISCRIMINATOR => Self::HolderLockup(decode(data)?),
+            d if d == WireBatchRecord::DISCRIMINATOR => Self::WireBatchRecord(Box::new(decode(data)?)),
+            d if d == FxRate::DISCRIMINATOR => Self::FxRate(decode(data)?),
+            _ => return Err(ErrorCode::AccountDiscriminatorMismatch.into()),
+        })
+    }
+}
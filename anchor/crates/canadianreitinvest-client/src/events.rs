@@ -0,0 +1,104 @@
+//! Decoders for the events the program emits.
+//!
+//! `emit!` logs each event as `Program data: <base64>`, where the payload is the event's
+//! discriminator followed by its Borsh encoding.
+
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+pub use canadianreitinvest::events::*;
+
+use crate::ID;
+
+/// Decodes an event payload as `T`, or `None` if it is a different event.
+pub fn decode<T: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<T> {
+    let payload = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut &payload[..]).ok()
+}
+
+/// Any event emitted by the program
+pub enum ProgramEvent {
+    FundraiserInitialized(FundraiserInitialized),
+    FundraiserStatusChanged(FundraiserStatusChanged),
+    InvestmentCreated(InvestmentCreated),
+    InvestmentReleased(InvestmentReleased),
+    InvestmentRefunded(InvestmentRefunded),
+    InvestmentWired(InvestmentWired),
+    WireBatchRecorded(WireBatchRecorded),
+    SharesIssued(SharesIssued),
+    SharesSold(SharesSold),
+    ReitMintCreated(ReitMintCreated),
+    ReitMetadataUpdated(ReitMetadataUpdated),
+    DividendIssued(DividendIssued),
+    InvestmentReceipt(InvestmentReceipt),
+    HolderFrozen(HolderFrozen),
+    HolderThawed(HolderThawed),
+    PositionRecovered(PositionRecovered),
+    AccountMigrated(AccountMigrated),
+}
+
+impl ProgramEvent {
+    /// Decodes an event payload (discriminator + Borsh) into whichever event it holds.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        decode(data)
+            .map(Self::FundraiserInitialized)
+            .or_else(|| decode(data).map(Self::FundraiserStatusChanged))
+            .or_else(|| decode(data).map(Self::InvestmentCreated))
+            .or_else(|| decode(data).map(Self::InvestmentReleased))
+            .or_else(|| decode(data).map(Self::InvestmentRefunded))
+            .or_else(|| decode(data).map(Self::InvestmentWired))
+            .or_else(|| decode(data).map(Self::WireBatchRecorded))
+            .or_else(|| decode(data).map(Self::SharesIssued))
+            .or_else(|| decode(data).map(Self::SharesSold))
+            .or_else(|| decode(data).map(Self::ReitMintCreated))
+            .or_else(|| decode(data).map(Self::ReitMetadataUpdated))
+            .or_else(|| decode(data).map(Self::DividendIssued))
+            .or_else(|| decode(data).map(Self::InvestmentReceipt))
+            .or_else(|| decode(data).map(Self::HolderFrozen))
+            .or_else(|| decode(data).map(Self::HolderThawed))
+            .or_else(|| decode(data).map(Self::PositionRecovered))
+            .or_else(|| decode(data).map(Self::AccountMigrated))
+    }
+}
+
+/// Decodes every event the program emitted in a transaction's log messages, in order.
+/// `Program data:` lines logged by other programs, including CPIs made by this one, are skipped.
+pub fn parse_logs<S: AsRef<str>>(logs: &[S]) -> Vec<ProgramEvent> {
+    let program_id = ID.to_string();
+    let mut invoked: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for line in logs.iter().map(AsRef::as_ref) {
+        if let Some(data) = line.strip_prefix("Program data: ") {
+            if invoked.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            if let Some(event) = STANDARD.decode(data).ok().and_then(|bytes| ProgramEvent::decode(&bytes)) {
+                events.push(event);
+            }
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let program = words.next().unwrap_or_default();
+            match words.next() {
+                Some("invoke") => invoked.push(program),
+                Some("success") | Some("failed:") => {
+                    invoked.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+
+/// Every `DividendIssued` event in a transaction's logs, e.g. to reconcile a dividend run.
+pub fn dividends_issued<S: AsRef<str>>(logs: &[S]) -> Vec<DividendIssued> {
+    parse_logs(logs)
+        .into_iter()
+        .filter_map(|event| match event {
+            ProgramEvent::DividendIssued(dividend) => Some(dividend),
+            _ => None,
+        })
+        .collect()
+}
+
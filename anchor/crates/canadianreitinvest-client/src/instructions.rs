@@ -0,0 +1,759 @@
+//! Instruction builders, one per instruction in the program.
+//!
+//! Builders take the signers and any account the program cannot derive (mints, token
+//! accounts, investments) and derive the rest with [`crate::pda`]. Writable and signer flags
+//! come from the program's own `accounts` structs, so they always match the on-chain checks.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{Discriminator, InstructionData, Space, ToAccountMetas};
+use anchor_spl::{associated_token, token, token_2022};
+use canadianreitinvest::legacy::InvestmentV0;
+use canadianreitinvest::{accounts, instruction, state};
+
+use crate::{pda, ID};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction { program_id: ID, accounts: accounts.to_account_metas(None), data: data.data() }
+}
+
+// Fundraiser lifecycle
+
+/// Creates the fundraiser for `reit_id` (a canonical hyphenated UUID) and its primary escrow vault.
+pub fn initialize_fundraiser(
+    admin: &Pubkey,
+    usdc_mint: &Pubkey,
+    reit_id: &str,
+    reit_accepted_currency: [u8; 3],
+) -> anchor_lang::Result<Instruction> {
+    let reit_id_hash = state::reit_id_hash(reit_id)?;
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    Ok(build(
+        accounts::InitializeFundraiser {
+            fundraiser,
+            admin: *admin,
+            escrow_vault: pda::escrow_vault(&fundraiser),
+            usdc_mint: *usdc_mint,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeFundraiser { reit_id: reit_id.to_string(), reit_id_hash, reit_accepted_currency },
+    ))
+}
+
+pub fn update_fundraiser_terms(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_accepted_currency: [u8; 3],
+    min_raise: u64,
+    max_raise: u64,
+    close_at: i64,
+) -> Instruction {
+    build(
+        accounts::UpdateFundraiserTerms { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::UpdateFundraiserTerms { reit_id_hash, reit_accepted_currency, min_raise, max_raise, close_at },
+    )
+}
+
+pub fn set_fundraiser_status(admin: &Pubkey, reit_id_hash: [u8; 16], status: state::FundraiserStatus) -> Instruction {
+    build(
+        accounts::SetFundraiserStatus { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::SetFundraiserStatus { reit_id_hash, status },
+    )
+}
+
+/// Closes a Terminated fundraiser. `extra_escrow_vaults` are the vaults of
+/// `Fundraiser::accepted_mints[1..]`, in that order.
+pub fn close_fundraiser(admin: &Pubkey, reit_id_hash: [u8; 16], extra_escrow_vaults: &[Pubkey]) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    let mut ix = build(
+        accounts::CloseFundraiser {
+            admin: *admin,
+            fundraiser,
+            escrow_vault: pda::escrow_vault(&fundraiser),
+            token_program: token::ID,
+        },
+        instruction::CloseFundraiser { reit_id_hash },
+    );
+    ix.accounts.extend(extra_escrow_vaults.iter().map(|vault| AccountMeta::new(*vault, false)));
+    ix
+}
+
+/// Accepts `mint`, pegged to `currency`, as an additional stablecoin. `usdc_mint` is the
+/// fundraiser's primary mint.
+pub fn add_accepted_mint(admin: &Pubkey, reit_id_hash: [u8; 16], usdc_mint: &Pubkey, mint: &Pubkey, currency: [u8; 3]) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    build(
+        accounts::AddAcceptedMint {
+            admin: *admin,
+            fundraiser,
+            usdc_mint: *usdc_mint,
+            mint: *mint,
+            escrow_vault: pda::accepted_mint_escrow_vault(&fundraiser, mint),
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::AddAcceptedMint { reit_id_hash, currency },
+    )
+}
+
+// Offering rules
+
+pub fn set_offering_exemption(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    exemption: state::OfferingExemption,
+    investment_limits: state::InvestmentLimits,
+) -> Instruction {
+    build(
+        accounts::SetOfferingExemption { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::SetOfferingExemption { reit_id_hash, exemption, investment_limits },
+    )
+}
+
+pub fn set_jurisdiction_rules(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    mode: state::JurisdictionMode,
+    rules: Vec<state::Jurisdiction>,
+) -> Instruction {
+    build(
+        accounts::SetJurisdictionRules { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::SetJurisdictionRules { reit_id_hash, mode, rules },
+    )
+}
+
+pub fn add_jurisdiction_rule(admin: &Pubkey, reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Instruction {
+    build(
+        accounts::AddJurisdictionRule { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::AddJurisdictionRule { reit_id_hash, jurisdiction },
+    )
+}
+
+pub fn remove_jurisdiction_rule(admin: &Pubkey, reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Instruction {
+    build(
+        accounts::RemoveJurisdictionRule { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::RemoveJurisdictionRule { reit_id_hash, jurisdiction },
+    )
+}
+
+pub fn set_hold_period(admin: &Pubkey, reit_id_hash: [u8; 16], hold_period_seconds: i64) -> Instruction {
+    build(
+        accounts::SetHoldPeriod { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash) },
+        instruction::SetHoldPeriod { reit_id_hash, hold_period_seconds },
+    )
+}
+
+// Investors
+
+pub fn initialize_investor(wallet: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeInvestor { signer: *wallet, investor: pda::investor(wallet), system_program: system_program::ID },
+        instruction::InitializeInvestor {},
+    )
+}
+
+pub fn close_investor(wallet: &Pubkey) -> Instruction {
+    build(accounts::CloseInvestor { signer: *wallet, investor: pda::investor(wallet) }, instruction::CloseInvestor {})
+}
+
+/// Deposits `amount` of `deposit_mint` into `escrow_vault`, creating investment number `counter`.
+/// `escrow_vault` is `Fundraiser::escrow_vault_for(deposit_mint)`.
+pub fn invest(
+    wallet: &Pubkey,
+    reit_id_hash: [u8; 16],
+    deposit_mint: &Pubkey,
+    escrow_vault: &Pubkey,
+    amount: u64,
+    counter: u64,
+) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    build(
+        accounts::Invest {
+            investor_signer: *wallet,
+            investor: pda::investor(wallet),
+            kyc_attestation: pda::kyc_attestation(wallet),
+            investor_fundraiser: pda::investor_fundraiser(wallet, &fundraiser),
+            fundraiser,
+            investment: pda::investment(wallet, &fundraiser, counter),
+            usdc_mint: *deposit_mint,
+            investor_usdc_ata: pda::associated_token_account(wallet, deposit_mint, &token::ID),
+            escrow_vault: *escrow_vault,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::Invest { amount, reit_id_hash, counter },
+    )
+}
+
+/// Closes a Refunded or ShareSold investment, returning its rent to the investor.
+pub fn close_investment(wallet: &Pubkey, fundraiser: &Pubkey, investment: &Pubkey, emit_receipt: bool) -> Instruction {
+    build(
+        accounts::CloseInvestment {
+            investor_signer: *wallet,
+            investment: *investment,
+            investor_fundraiser: pda::investor_fundraiser(wallet, fundraiser),
+        },
+        instruction::CloseInvestment { emit_receipt },
+    )
+}
+
+// Settlement
+
+/// Moves a Pending investment's deposit from `escrow_vault` to the admin's ATA for `deposit_mint`.
+pub fn release(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    deposit_mint: &Pubkey,
+    escrow_vault: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::Release {
+            admin: *admin,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            investment: *investment,
+            admin_usdc_ata: pda::associated_token_account(admin, deposit_mint, &token::ID),
+            usdc_mint: *deposit_mint,
+            escrow_vault: *escrow_vault,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::Release { reit_id_hash },
+    )
+}
+
+/// Returns a released investment's deposit from the admin's ATA to the investor's.
+/// Refunds a Released investment: the admin returns the deposit to `escrow_vault`, the vault
+/// of `deposit_mint`, which pays `wallet`.
+pub fn refund(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    wallet: &Pubkey,
+    deposit_mint: &Pubkey,
+    escrow_vault: &Pubkey,
+) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    build(
+        accounts::Refund {
+            admin: *admin,
+            fundraiser,
+            investment: *investment,
+            investor: *wallet,
+            investor_pda: pda::investor(wallet),
+            investor_fundraiser: pda::investor_fundraiser(wallet, &fundraiser),
+            admin_usdc_ata: pda::associated_token_account(admin, deposit_mint, &token::ID),
+            investor_usdc_ata: pda::associated_token_account(wallet, deposit_mint, &token::ID),
+            usdc_mint: *deposit_mint,
+            escrow_vault: *escrow_vault,
+            token_program: token::ID,
+        },
+        instruction::Refund { reit_id_hash },
+    )
+}
+
+pub fn wire(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    wired_amount: u64,
+    fx_rate: u64,
+    wire_date: i64,
+    wire_reference_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::Wire { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash), investment: *investment },
+        instruction::Wire { reit_id_hash, wired_amount, fx_rate, wire_date, wire_reference_hash },
+    )
+}
+
+/// Records one bank wire covering every investment in `investments` (at most
+/// `state::MAX_WIRE_BATCH_INVESTMENTS`).
+pub fn wire_batch(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investments: &[Pubkey],
+    wired_amount: u64,
+    fx_rate: u64,
+    wire_date: i64,
+    wire_reference_hash: [u8; 32],
+) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    let mut ix = build(
+        accounts::WireBatch {
+            admin: *admin,
+            fundraiser,
+            wire_batch: pda::wire_batch(&fundraiser, &wire_reference_hash),
+            system_program: system_program::ID,
+        },
+        instruction::WireBatch { reit_id_hash, wired_amount, fx_rate, wire_date, wire_reference_hash },
+    );
+    ix.accounts.extend(investments.iter().map(|investment| AccountMeta::new(*investment, false)));
+    ix
+}
+
+// REIT mint and shares
+
+/// Creates a classic SPL REIT mint with Metaplex metadata. `reit_mint` must also sign.
+pub fn create_reit_mint(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_mint: &Pubkey,
+    name: String,
+    symbol: String,
+    metadata_uri: String,
+) -> Instruction {
+    build(
+        accounts::CreateReitMint {
+            admin: *admin,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            reit_mint: *reit_mint,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+            metadata: pda::metadata(reit_mint),
+            token_metadata_program: mpl_token_metadata::ID,
+        },
+        instruction::CreateReitMint { reit_id_hash, name, symbol, metadata_uri },
+    )
+}
+
+/// Creates a Token-2022 REIT mint with the transfer hook, metadata pointer and permanent
+/// delegate extensions, and the transfer hook's extra account list for it. `reit_mint` must also sign.
+pub fn create_reit_mint_2022(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_mint: &Pubkey,
+    transfer_hook_program: &Pubkey,
+    name: String,
+    symbol: String,
+    metadata_uri: String,
+) -> Instruction {
+    build(
+        accounts::CreateReitMint2022 {
+            admin: *admin,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            reit_mint: *reit_mint,
+            transfer_hook_program: *transfer_hook_program,
+            extra_account_meta_list: pda::extra_account_meta_list(reit_mint, transfer_hook_program),
+            token_program: token_2022::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::CreateReitMint2022 { reit_id_hash, name, symbol, metadata_uri },
+    )
+}
+
+/// Updates the REIT mint's name, symbol and URI: in its Metaplex metadata account for a classic
+/// SPL mint, or in the mint's metadata extension when `reit_token_program` is Token-2022.
+pub fn update_reit_mint(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_mint: &Pubkey,
+    reit_token_program: &Pubkey,
+    name: String,
+    symbol: String,
+    metadata_uri: String,
+) -> Instruction {
+    let metaplex = *reit_token_program == token::ID;
+    build(
+        accounts::UpdateReitMint {
+            admin: *admin,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            reit_mint: *reit_mint,
+            token_program: *reit_token_program,
+            system_program: system_program::ID,
+            instructions_sysvar: metaplex.then_some(sysvar::instructions::ID),
+            metadata: metaplex.then(|| pda::metadata(reit_mint)),
+            token_metadata_program: metaplex.then_some(mpl_token_metadata::ID),
+        },
+        instruction::UpdateReitMint { reit_id_hash, name, symbol, metadata_uri },
+    )
+}
+
+/// Mints shares for a Wired investment. `reit_token_program` is `Fundraiser::reit_token_program`.
+/// `share_price` is in cents of the fundraiser currency. Pass `use_lock_vault` when the mint is
+/// classic SPL and the hold period is non-zero, and `fx_rate` to convert the price to the deposit
+/// stablecoin at the published rate instead of the wire's.
+#[allow(clippy::too_many_arguments)]
+pub fn issue_share(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    wallet: &Pubkey,
+    reit_mint: &Pubkey,
+    reit_token_program: &Pubkey,
+    use_lock_vault: bool,
+    fx_rate: Option<Pubkey>,
+    share_price: u64,
+) -> Instruction {
+    build(
+        accounts::IssueShare {
+            admin: *admin,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            investment: *investment,
+            investor: pda::investor(wallet),
+            kyc_attestation: pda::kyc_attestation(wallet),
+            investor_wallet: *wallet,
+            reit_mint: *reit_mint,
+            investor_ata: pda::associated_token_account(wallet, reit_mint, reit_token_program),
+            lock_vault: use_lock_vault.then(|| pda::lock_vault(investment)),
+            holder_lockup: pda::holder_lockup(reit_mint, wallet),
+            fx_rate,
+            token_program: *reit_token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::IssueShare { investor_pubkey: *wallet, reit_id_hash, share_price },
+    )
+}
+
+/// Marks a ShareIssued investment as sold, so its investor can close it.
+pub fn record_share_sale(admin: &Pubkey, reit_id_hash: [u8; 16], investment: &Pubkey) -> Instruction {
+    build(
+        accounts::RecordShareSale { admin: *admin, fundraiser: pda::fundraiser(&reit_id_hash), investment: *investment },
+        instruction::RecordShareSale { reit_id_hash },
+    )
+}
+
+/// Moves an investment's shares out of its lock vault once the hold period has passed.
+pub fn unlock_shares(
+    wallet: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    admin: &Pubkey,
+    reit_mint: &Pubkey,
+    reit_token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::UnlockShares {
+            investor_signer: *wallet,
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            investment: *investment,
+            lock_vault: pda::lock_vault(investment),
+            reit_mint: *reit_mint,
+            investor_ata: pda::associated_token_account(wallet, reit_mint, reit_token_program),
+            admin: *admin,
+            token_program: *reit_token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::UnlockShares { reit_id_hash },
+    )
+}
+
+/// Pays `amount` of `usdc_mint` from the admin's ATA to the holder of a ShareIssued investment.
+pub fn issue_dividend(
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    wallet: &Pubkey,
+    usdc_mint: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        accounts::IssueDividend {
+            admin: *admin,
+            investment: *investment,
+            investor: *wallet,
+            investor_profile: pda::investor(wallet),
+            kyc_attestation: pda::kyc_attestation(wallet),
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            admin_usdc_ata: pda::associated_token_account(admin, usdc_mint, &token::ID),
+            investor_usdc_ata: pda::associated_token_account(wallet, usdc_mint, &token::ID),
+            usdc_mint: *usdc_mint,
+            token_program: token::ID,
+        },
+        instruction::IssueDividend { amount },
+    )
+}
+
+// Compliance
+
+/// Creates the compliance configuration. `payer` must be the program's upgrade authority.
+pub fn initialize_compliance(payer: &Pubkey, authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializeCompliance {
+            payer: *payer,
+            compliance_config: pda::compliance_config(),
+            program: ID,
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeCompliance { authority: *authority },
+    )
+}
+
+pub fn set_compliance_authority(authority: &Pubkey, new_authority: &Pubkey) -> Instruction {
+    build(
+        accounts::SetComplianceAuthority { authority: *authority, compliance_config: pda::compliance_config() },
+        instruction::SetComplianceAuthority { new_authority: *new_authority },
+    )
+}
+
+pub fn attest_investor(
+    authority: &Pubkey,
+    wallet: &Pubkey,
+    status: state::KycStatus,
+    jurisdiction: state::Jurisdiction,
+    expires_at: i64,
+    accreditation: state::AccreditationCategory,
+    kyc_record_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::AttestInvestor {
+            authority: *authority,
+            compliance_config: pda::compliance_config(),
+            investor: pda::investor(wallet),
+            kyc_attestation: pda::kyc_attestation(wallet),
+            system_program: system_program::ID,
+        },
+        instruction::AttestInvestor {
+            investor_wallet: *wallet,
+            status,
+            jurisdiction,
+            expires_at,
+            accreditation,
+            kyc_record_hash,
+        },
+    )
+}
+
+pub fn freeze_holder(
+    authority: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_mint: &Pubkey,
+    holder_token_account: &Pubkey,
+    reit_token_program: &Pubkey,
+    legal_reference_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::FreezeHolder {
+            authority: *authority,
+            compliance_config: pda::compliance_config(),
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            reit_mint: *reit_mint,
+            holder_token_account: *holder_token_account,
+            token_program: *reit_token_program,
+        },
+        instruction::FreezeHolder { reit_id_hash, legal_reference_hash },
+    )
+}
+
+pub fn thaw_holder(
+    authority: &Pubkey,
+    reit_id_hash: [u8; 16],
+    reit_mint: &Pubkey,
+    holder_token_account: &Pubkey,
+    reit_token_program: &Pubkey,
+    legal_reference_hash: [u8; 32],
+) -> Instruction {
+    build(
+        accounts::ThawHolder {
+            authority: *authority,
+            compliance_config: pda::compliance_config(),
+            fundraiser: pda::fundraiser(&reit_id_hash),
+            reit_mint: *reit_mint,
+            holder_token_account: *holder_token_account,
+            token_program: *reit_token_program,
+        },
+        instruction::ThawHolder { reit_id_hash, legal_reference_hash },
+    )
+}
+
+/// Moves a ShareIssued position held by `old_owner` from `old_token_account` to `new_owner`'s
+/// ATA. `new_owner` needs an Investor PDA and a valid KYC attestation. Both the compliance
+/// `authority` and the fundraiser `admin` must sign.
+#[allow(clippy::too_many_arguments)]
+pub fn recover_position(
+    authority: &Pubkey,
+    admin: &Pubkey,
+    reit_id_hash: [u8; 16],
+    investment: &Pubkey,
+    old_owner: &Pubkey,
+    reit_mint: &Pubkey,
+    reit_token_program: &Pubkey,
+    old_token_account: &Pubkey,
+    new_owner: &Pubkey,
+    legal_reference_hash: [u8; 32],
+) -> Instruction {
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    build(
+        accounts::RecoverPosition {
+            authority: *authority,
+            admin: *admin,
+            compliance_config: pda::compliance_config(),
+            fundraiser,
+            investment: *investment,
+            reit_mint: *reit_mint,
+            old_token_account: *old_token_account,
+            old_owner: *old_owner,
+            old_investor_fundraiser: pda::investor_fundraiser(old_owner, &fundraiser),
+            new_owner: *new_owner,
+            new_investor: pda::investor(new_owner),
+            new_owner_kyc_attestation: pda::kyc_attestation(new_owner),
+            new_investor_fundraiser: pda::investor_fundraiser(new_owner, &fundraiser),
+            new_owner_ata: pda::associated_token_account(new_owner, reit_mint, reit_token_program),
+            new_owner_lockup: pda::holder_lockup(reit_mint, new_owner),
+            token_program: *reit_token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::RecoverPosition { reit_id_hash, new_owner: *new_owner, legal_reference_hash },
+    )
+}
+
+// FX rates
+
+/// Creates the FX rate account for a pair. `payer` must be the program's upgrade authority.
+pub fn initialize_fx_rate(
+    payer: &Pubkey,
+    base_currency: [u8; 3],
+    quote_currency: [u8; 3],
+    publisher: &Pubkey,
+    max_staleness_seconds: i64,
+) -> Instruction {
+    build(
+        accounts::InitializeFxRate {
+            payer: *payer,
+            fx_rate: pda::fx_rate(&base_currency, &quote_currency),
+            program: ID,
+            program_data: pda::program_data(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeFxRate { base_currency, quote_currency, publisher: *publisher, max_staleness_seconds },
+    )
+}
+
+pub fn publish_fx_rate(
+    publisher: &Pubkey,
+    base_currency: [u8; 3],
+    quote_currency: [u8; 3],
+    rate: u64,
+    confidence: u64,
+    published_at: i64,
+) -> Instruction {
+    build(
+        accounts::PublishFxRate { publisher: *publisher, fx_rate: pda::fx_rate(&base_currency, &quote_currency) },
+        instruction::PublishFxRate { base_currency, quote_currency, rate, confidence, published_at },
+    )
+}
+
+/// Replaces the publisher of a pair. `authority` must be the program's upgrade authority.
+pub fn set_fx_rate_publisher(
+    authority: &Pubkey,
+    base_currency: [u8; 3],
+    quote_currency: [u8; 3],
+    new_publisher: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::SetFxRatePublisher {
+            authority: *authority,
+            fx_rate: pda::fx_rate(&base_currency, &quote_currency),
+            program: ID,
+            program_data: pda::program_data(),
+        },
+        instruction::SetFxRatePublisher { base_currency, quote_currency, new_publisher: *new_publisher },
+    )
+}
+
+// Migration
+
+fn migrate_accounts(payer: &Pubkey, account: &Pubkey) -> accounts::Migrate {
+    accounts::Migrate { payer: *payer, account: *account, system_program: system_program::ID }
+}
+
+/// Rewrites a Fundraiser written by an earlier program version in the current layout, storing
+/// `reit_id`, the REIT id its address was derived from.
+pub fn migrate_fundraiser(payer: &Pubkey, account: &Pubkey, reit_id: String) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateFundraiser { reit_id })
+}
+
+/// Rewrites an Investor written by an earlier program version in the current layout.
+pub fn migrate_investor(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateInvestor {})
+}
+
+/// Rewrites an InvestorFundraiser written by an earlier program version in the current layout.
+pub fn migrate_investor_fundraiser(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateInvestorFundraiser {})
+}
+
+/// Rewrites an Investment written by an earlier program version in the current layout and
+/// counts it on the accounts of `wallet`, its investor, and `fundraiser`. Those accounts must be
+/// migrated first.
+pub fn migrate_investment(payer: &Pubkey, account: &Pubkey, wallet: &Pubkey, fundraiser: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateInvestment {
+            payer: *payer,
+            account: *account,
+            fundraiser: *fundraiser,
+            investor: pda::investor(wallet),
+            investor_fundraiser: pda::investor_fundraiser(wallet, fundraiser),
+            system_program: system_program::ID,
+        },
+        instruction::MigrateInvestment {},
+    )
+}
+
+/// Rewrites a ComplianceConfig written by an earlier program version in the current layout.
+pub fn migrate_compliance_config(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateComplianceConfig {})
+}
+
+/// Rewrites a KycAttestation written by an earlier program version in the current layout.
+pub fn migrate_kyc_attestation(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateKycAttestation {})
+}
+
+/// Rewrites a HolderLockup written by an earlier program version in the current layout.
+pub fn migrate_holder_lockup(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateHolderLockup {})
+}
+
+/// Rewrites a WireBatchRecord written by an earlier program version in the current layout.
+pub fn migrate_wire_batch_record(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateWireBatchRecord {})
+}
+
+/// Rewrites an FxRate written by an earlier program version in the current layout.
+pub fn migrate_fx_rate(payer: &Pubkey, account: &Pubkey) -> Instruction {
+    build(migrate_accounts(payer, account), instruction::MigrateFxRate {})
+}
+
+/// The `migrate_*` instruction for an account holding `data`, picked by its discriminator, or
+/// `None` if `data` isn't a program account. Fundraisers also need the `reit_id` their address
+/// was derived from, and are `None` without it.
+pub fn migrate(payer: &Pubkey, account: &Pubkey, data: &[u8], reit_id: Option<&str>) -> Option<Instruction> {
+    let discriminator = data.get(..8)?;
+    let builder: fn(&Pubkey, &Pubkey) -> Instruction = match discriminator {
+        d if d == state::Fundraiser::DISCRIMINATOR => return Some(migrate_fundraiser(payer, account, reit_id?.to_string())),
+        d if d == state::Investor::DISCRIMINATOR => migrate_investor,
+        d if d == state::InvestorFundraiser::DISCRIMINATOR => migrate_investor_fundraiser,
+        d if d == state::Investment::DISCRIMINATOR => {
+            // Every Investment layout starts with investor and fundraiser, after the version
+            // byte in versioned ones
+            let start = if data.len() == 8 + InvestmentV0::INIT_SPACE { 8 } else { 9 };
+            let wallet = Pubkey::try_from(data.get(start..start + 32)?).ok()?;
+            let fundraiser = Pubkey::try_from(data.get(start + 32..start + 64)?).ok()?;
+            return Some(migrate_investment(payer, account, &wallet, &fundraiser));
+        }
+        d if d == state::ComplianceConfig::DISCRIMINATOR => migrate_compliance_config,
+        d if d == state::KycAttestation::DISCRIMINATOR => migrate_kyc_attestation,
+        d if d == state::HolderLockup::DISCRIMINATOR => migrate_holder_lockup,
+        d if d == state::WireBatchRecord::DISCRIMINATOR => migrate_wire_batch_record,
+        d if d == state::FxRate::DISCRIMINATOR => migrate_fx_rate,
+        _ => return None,
+    };
+    Some(builder(payer, account))
+}
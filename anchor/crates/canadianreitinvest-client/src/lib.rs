@@ -0,0 +1,20 @@
+//! Rust client for the `canadianreitinvest` program.
+//!
+//! Back-office services use this crate instead of hand-rolling seeds and account lists:
+//!
+//! - [`pda`] derives every program address from the same seeds the program checks
+//! - [`instructions`] builds an [`Instruction`] for each instruction in the program
+//! - [`accounts`] decodes raw account data into the program's state structs
+//! - [`events`] decodes the Anchor events found in transaction logs
+//!
+//! The program's `state`, `errors` and `events` modules are re-exported so callers don't need
+//! a direct dependency on the program crate.
+//!
+//! [`Instruction`]: anchor_lang::solana_program::instruction::Instruction
+
+pub mod accounts;
+pub mod events;
+pub mod instructions;
+pub mod pda;
+
+pub use canadianreitinvest::{errors, state, ID};
@@ -0,0 +1,95 @@
+//! Program-derived addresses, using the seeds from the program's account constraints.
+//!
+//! Each helper returns the address only; the bump is stored on every program-owned account.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use crate::ID;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+/// Singleton compliance configuration, `[b"compliance_config"]`
+pub fn compliance_config() -> Pubkey {
+    find(&[b"compliance_config"])
+}
+
+/// Fundraiser for a REIT, `[b"fundraiser", reit_id_hash]`
+pub fn fundraiser(reit_id_hash: &[u8; 16]) -> Pubkey {
+    find(&[b"fundraiser", reit_id_hash])
+}
+
+/// Escrow vault for the fundraiser's primary stablecoin, `[b"escrow_vault", fundraiser]`
+pub fn escrow_vault(fundraiser: &Pubkey) -> Pubkey {
+    find(&[b"escrow_vault", fundraiser.as_ref()])
+}
+
+/// Escrow vault for a stablecoin added with `add_accepted_mint`, `[b"escrow_vault", fundraiser, mint]`
+pub fn accepted_mint_escrow_vault(fundraiser: &Pubkey, mint: &Pubkey) -> Pubkey {
+    find(&[b"escrow_vault", fundraiser.as_ref(), mint.as_ref()])
+}
+
+/// Investor profile for a wallet, `[b"investor", wallet]`
+pub fn investor(wallet: &Pubkey) -> Pubkey {
+    find(&[b"investor", wallet.as_ref()])
+}
+
+/// KYC attestation for a wallet, `[b"kyc_attestation", investor(wallet)]`
+pub fn kyc_attestation(wallet: &Pubkey) -> Pubkey {
+    find(&[b"kyc_attestation", investor(wallet).as_ref()])
+}
+
+/// Per-fundraiser investor record, `[b"investor_fundraiser", wallet, fundraiser]`
+pub fn investor_fundraiser(wallet: &Pubkey, fundraiser: &Pubkey) -> Pubkey {
+    find(&[b"investor_fundraiser", wallet.as_ref(), fundraiser.as_ref()])
+}
+
+/// Investment number `counter` of a wallet in a fundraiser, `[b"investment", wallet, fundraiser, counter]`.
+/// The next counter is `InvestorFundraiser::investment_counter` (0 before the first investment).
+pub fn investment(wallet: &Pubkey, fundraiser: &Pubkey, counter: u64) -> Pubkey {
+    find(&[b"investment", wallet.as_ref(), fundraiser.as_ref(), &counter.to_le_bytes()])
+}
+
+/// Lock vault holding an investment's shares during the hold period, `[b"lock_vault", investment]`
+pub fn lock_vault(investment: &Pubkey) -> Pubkey {
+    find(&[b"lock_vault", investment.as_ref()])
+}
+
+/// Resale hold period of a holder of a REIT mint, `[b"holder_lockup", reit_mint, holder]`
+pub fn holder_lockup(reit_mint: &Pubkey, holder: &Pubkey) -> Pubkey {
+    find(&[b"holder_lockup", reit_mint.as_ref(), holder.as_ref()])
+}
+
+/// Record of a batch wire, `[b"wire_batch", fundraiser, wire_reference_hash]`
+pub fn wire_batch(fundraiser: &Pubkey, wire_reference_hash: &[u8; 32]) -> Pubkey {
+    find(&[b"wire_batch", fundraiser.as_ref(), wire_reference_hash])
+}
+
+/// Published rate for a currency pair, `[b"fx_rate", base_currency, quote_currency]`
+pub fn fx_rate(base_currency: &[u8; 3], quote_currency: &[u8; 3]) -> Pubkey {
+    find(&[b"fx_rate", base_currency, quote_currency])
+}
+
+/// The program's ProgramData account, whose upgrade authority may initialize compliance and FX rates
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// The transfer hook's ExtraAccountMetaList for a Token-2022 REIT mint,
+/// `[b"extra-account-metas", reit_mint]` under `transfer_hook_program`
+pub fn extra_account_meta_list(reit_mint: &Pubkey, transfer_hook_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"extra-account-metas", reit_mint.as_ref()], transfer_hook_program).0
+}
+
+/// Metaplex metadata account of a classic SPL REIT mint
+pub fn metadata(reit_mint: &Pubkey) -> Pubkey {
+    mpl_token_metadata::accounts::Metadata::find_pda(reit_mint).0
+}
+
+/// Associated token account of `owner` for `mint` under `token_program`
+pub fn associated_token_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountSerialize, Discriminator, Event};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use canadianreitinvest_client::accounts::ProgramAccount;
+use canadianreitinvest_client::events::{self, DividendIssued, ProgramEvent};
+use canadianreitinvest_client::{instructions as ix, state, ID};
+
+fn dividend(amount: u64) -> DividendIssued {
+    DividendIssued {
+        investment: Pubkey::new_unique(),
+        investor: Pubkey::new_unique(),
+        fundraiser: Pubkey::new_unique(),
+        amount,
+        timestamp: 1_760_000_000,
+    }
+}
+
+#[test]
+fn parses_events_emitted_by_the_program_only() {
+    let token_program = anchor_spl::token::ID;
+    let logs = [
+        format!("Program {ID} invoke [1]"),
+        "Program log: Instruction: IssueDividend".to_string(),
+        format!("Program {token_program} invoke [2]"),
+        format!("Program data: {}", STANDARD.encode(dividend(1).data())),
+        format!("Program {token_program} success"),
+        format!("Program data: {}", STANDARD.encode(dividend(2).data())),
+        format!("Program {ID} consumed 20000 of 200000 compute units"),
+        format!("Program {ID} success"),
+    ];
+
+    let dividends = events::dividends_issued(&logs);
+    assert_eq!(dividends.len(), 1);
+    assert_eq!(dividends[0].amount, 2);
+}
+
+#[test]
+fn decodes_each_event_by_discriminator() {
+    let data = dividend(5).data();
+    assert!(matches!(ProgramEvent::decode(&data), Some(ProgramEvent::DividendIssued(event)) if event.amount == 5));
+    assert!(events::decode::<events::InvestmentReceipt>(&data).is_none());
+}
+
+#[test]
+fn decodes_accounts_by_discriminator() {
+    let lockup = state::HolderLockup {
+        version: 1,
+        holder: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        locked_until: 1_770_000_000,
+        bump: 254,
+        reserved: [0; state::RESERVED_SPACE],
+    };
+    let mut data = Vec::new();
+    lockup.try_serialize(&mut data).unwrap();
+
+    match ProgramAccount::decode(&data).unwrap() {
+        ProgramAccount::HolderLockup(decoded) => assert_eq!(decoded.locked_until, lockup.locked_until),
+        _ => panic!("decoded as the wrong account type"),
+    }
+    assert!(ProgramAccount::decode(&data[..4]).is_err());
+}
+
+#[test]
+fn picks_the_migration_by_discriminator() {
+    let lockup = state::HolderLockup {
+        version: 0,
+        holder: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        locked_until: 0,
+        bump: 255,
+        reserved: [0; state::RESERVED_SPACE],
+    };
+    let mut data = Vec::new();
+    lockup.try_serialize(&mut data).unwrap();
+    let (payer, account) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    assert_eq!(ix::migrate(&payer, &account, &data, None), Some(ix::migrate_holder_lockup(&payer, &account)));
+    assert_ne!(ix::migrate(&payer, &account, &data, None), Some(ix::migrate_fx_rate(&payer, &account)));
+    assert_eq!(ix::migrate(&payer, &account, &[0u8; 8], None), None);
+
+    // Fundraisers are only migrated with the REIT id their address was derived from
+    let fundraiser = state::Fundraiser::DISCRIMINATOR.to_vec();
+    let reit_id = "123e4567-e89b-12d3-a456-426614174000";
+    assert_eq!(ix::migrate(&payer, &account, &fundraiser, None), None);
+    assert_eq!(ix::migrate(&payer, &account, &fundraiser, Some(reit_id)), Some(ix::migrate_fundraiser(&payer, &account, reit_id.to_string())));
+}
@@ -0,0 +1,109 @@
+//! CSV-driven bulk operations. Each row becomes its own transaction, so one bad row is
+//! reported in its outcome without stopping the rest of the file.
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use serde::Deserialize;
+
+use crate::commands::{dividend_instruction, issue_share_instruction, wire_instruction, WireDetails};
+use crate::context::{Context, Outcome};
+use crate::parse;
+use crate::Result;
+
+#[derive(clap::Args)]
+pub struct WireListArgs {
+    /// CSV with columns investment,wired_amount,fx_rate,wire_date,wire_reference
+    pub file: PathBuf,
+}
+
+#[derive(clap::Args)]
+pub struct IssueSharesArgs {
+    /// CSV with columns investment[,share_price]
+    pub file: PathBuf,
+    /// Price for rows without a share_price column value, in cents of the fundraiser currency
+    #[arg(long)]
+    pub share_price: Option<u64>,
+    /// Convert prices at the published rate of each deposit currency instead of the wire's rate
+    #[arg(long)]
+    pub fx_rate: bool,
+}
+
+#[derive(clap::Args)]
+pub struct DividendScheduleArgs {
+    /// CSV with columns investment,amount, paid in each fundraiser's primary mint
+    pub file: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct WireRow {
+    investment: String,
+    wired_amount: u64,
+    fx_rate: u64,
+    wire_date: i64,
+    wire_reference: String,
+}
+
+#[derive(Deserialize)]
+struct IssueShareRow {
+    investment: String,
+    #[serde(default)]
+    share_price: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct DividendRow {
+    investment: String,
+    amount: u64,
+}
+
+pub fn wire(ctx: &Context, args: WireListArgs) -> Result<Vec<Outcome>> {
+    run_rows(ctx, "wire", &args.file, |row: WireRow| {
+        let investment = parse::pubkey(&row.investment)?;
+        let (_, _, reit_id_hash) = ctx.investment(&investment)?;
+        let details = WireDetails {
+            wired_amount: row.wired_amount,
+            fx_rate: row.fx_rate,
+            wire_date: row.wire_date,
+            wire_reference: parse::reference_hash(&row.wire_reference)?,
+        };
+        Ok((investment, wire_instruction(&ctx.pubkey(), reit_id_hash, &investment, &details)))
+    })
+}
+
+pub fn issue_shares(ctx: &Context, args: IssueSharesArgs) -> Result<Vec<Outcome>> {
+    run_rows(ctx, "issue-share", &args.file, |row: IssueShareRow| {
+        let investment = parse::pubkey(&row.investment)?;
+        let share_price = row.share_price.or(args.share_price).ok_or("no share_price in row and no --share-price given")?;
+        Ok((investment, issue_share_instruction(ctx, &investment, share_price, args.fx_rate)?))
+    })
+}
+
+pub fn pay_dividends(ctx: &Context, args: DividendScheduleArgs) -> Result<Vec<Outcome>> {
+    run_rows(ctx, "issue-dividend", &args.file, |row: DividendRow| {
+        let investment = parse::pubkey(&row.investment)?;
+        Ok((investment, dividend_instruction(ctx, &investment, row.amount)?))
+    })
+}
+
+/// Builds and submits one transaction per CSV row. `row` in each outcome is the 1-based
+/// data row, not counting the header.
+fn run_rows<T, F>(ctx: &Context, command: &str, file: &PathBuf, mut build: F) -> Result<Vec<Outcome>>
+where
+    T: for<'de> Deserialize<'de>,
+    F: FnMut(T) -> Result<(Pubkey, Instruction)>,
+{
+    let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_path(file)?;
+    let mut outcomes = Vec::new();
+    for (index, record) in reader.deserialize::<T>().enumerate() {
+        let outcome = match record.map_err(Into::into).and_then(&mut build) {
+            Ok((investment, instruction)) => {
+                ctx.submit(command, &[instruction], &[]).with_account("investment", &investment)
+            }
+            Err(err) => Outcome::failed(command, ctx.dry_run, err),
+        };
+        outcomes.push(outcome.at_row(index + 1));
+    }
+    Ok(outcomes)
+}
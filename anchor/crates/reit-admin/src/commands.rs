@@ -0,0 +1,692 @@
+//! One subcommand per program instruction.
+//!
+//! Commands take the fewest arguments that identify the accounts involved and fetch the rest
+//! (fundraiser mints, deposit mints, investor wallets) from chain. The `--keypair` signer is
+//! the admin, compliance authority, FX publisher or investor, depending on the instruction.
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token;
+use canadianreitinvest_client::{instructions as ix, pda, state};
+use clap::Subcommand;
+use solana_keypair::{read_keypair_file, Keypair};
+use solana_signer::Signer;
+
+use crate::bulk::{self, DividendScheduleArgs, IssueSharesArgs, WireListArgs};
+use crate::context::{Context, Outcome};
+use crate::parse;
+use crate::Result;
+
+/// `reit_transfer_hook` program id, as listed in Anchor.toml
+const TRANSFER_HOOK_PROGRAM_ID: &str = "98ABkLqEXy5MPkVEZv7Wz4TtSTr9be5sCrM1LLKxjj66";
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create a fundraiser and its primary escrow vault
+    InitializeFundraiser {
+        #[arg(long)]
+        reit_id: String,
+        #[arg(long, value_parser = parse::pubkey)]
+        usdc_mint: Pubkey,
+        #[arg(long, value_parser = parse::currency, default_value = "CAD")]
+        currency: [u8; 3],
+    },
+    /// Set the raise currency, minimum, cap and deadline of a Draft fundraiser
+    UpdateFundraiserTerms {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::currency)]
+        currency: [u8; 3],
+        #[arg(long, default_value_t = 0)]
+        min_raise: u64,
+        /// 0 = uncapped
+        #[arg(long, default_value_t = 0)]
+        max_raise: u64,
+        /// Unix timestamp, 0 = no deadline
+        #[arg(long, default_value_t = 0)]
+        close_at: i64,
+    },
+    /// Move a fundraiser to its next lifecycle status
+    SetFundraiserStatus {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_enum)]
+        status: parse::FundraiserStatus,
+    },
+    /// Close a Terminated fundraiser and its empty escrow vaults
+    CloseFundraiser {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+    },
+    /// Accept another stablecoin, creating its escrow vault
+    AddAcceptedMint {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::pubkey)]
+        mint: Pubkey,
+        /// Currency the stablecoin is pegged to
+        #[arg(long, value_parser = parse::currency)]
+        currency: [u8; 3],
+    },
+    /// Set the prospectus exemption and per-category investment limits
+    SetOfferingExemption {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_enum)]
+        exemption: parse::OfferingExemption,
+        /// Rolling 12-month cap for non-eligible investors, in escrow token base units
+        #[arg(long, default_value_t = 0)]
+        non_eligible_limit: u64,
+        /// Rolling 12-month cap for eligible investors, in escrow token base units
+        #[arg(long, default_value_t = 0)]
+        eligible_limit: u64,
+    },
+    /// Replace the jurisdiction allowlist or denylist
+    SetJurisdictionRules {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_enum)]
+        mode: parse::JurisdictionMode,
+        /// Jurisdiction such as `CA` or `CA-ON`; repeat for each rule
+        #[arg(long = "rule", value_parser = parse::jurisdiction)]
+        rules: Vec<state::Jurisdiction>,
+    },
+    /// Add one jurisdiction rule
+    AddJurisdictionRule {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::jurisdiction)]
+        jurisdiction: state::Jurisdiction,
+    },
+    /// Remove one jurisdiction rule
+    RemoveJurisdictionRule {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::jurisdiction)]
+        jurisdiction: state::Jurisdiction,
+    },
+    /// Set the resale hold period applied to new share issuances
+    SetHoldPeriod {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long)]
+        seconds: i64,
+    },
+    /// Create the investor profile of the `--keypair` wallet
+    InitializeInvestor,
+    /// Close the investor profile of the `--keypair` wallet
+    CloseInvestor,
+    /// Invest from the `--keypair` wallet
+    Invest {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long)]
+        amount: u64,
+        /// Stablecoin to deposit; defaults to the fundraiser's primary mint
+        #[arg(long, value_parser = parse::pubkey)]
+        mint: Option<Pubkey>,
+    },
+    /// Close a Refunded or ShareSold investment of the `--keypair` wallet
+    CloseInvestment {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+        /// Emit an InvestmentReceipt event with the final figures
+        #[arg(long)]
+        receipt: bool,
+    },
+    /// Release a Pending investment's deposit to the admin
+    Release {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+    },
+    /// Refund a Released investment, returning it from the admin's account through its escrow vault
+    Refund {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+    },
+    /// Record the bank wire for a Released investment
+    Wire {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+        #[command(flatten)]
+        details: WireDetails,
+    },
+    /// Record one bank wire covering several Released investments
+    WireBatch {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        /// Repeat for each investment in the wire
+        #[arg(long = "investment", value_parser = parse::pubkey, required = true)]
+        investments: Vec<Pubkey>,
+        #[command(flatten)]
+        details: WireDetails,
+    },
+    /// Create a classic SPL REIT mint with Metaplex metadata
+    CreateReitMint {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[command(flatten)]
+        metadata: MintMetadata,
+        /// Keypair for the new mint; a fresh one is generated if omitted
+        #[arg(long)]
+        mint_keypair: Option<PathBuf>,
+    },
+    /// Create a Token-2022 REIT mint with the transfer hook
+    #[command(name = "create-reit-mint-2022")]
+    CreateReitMint2022 {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[command(flatten)]
+        metadata: MintMetadata,
+        /// Keypair for the new mint; a fresh one is generated if omitted
+        #[arg(long)]
+        mint_keypair: Option<PathBuf>,
+        #[arg(long, value_parser = parse::pubkey, default_value = TRANSFER_HOOK_PROGRAM_ID)]
+        transfer_hook_program: Pubkey,
+    },
+    /// Update the name, symbol and URI of the REIT mint
+    UpdateReitMint {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[command(flatten)]
+        metadata: MintMetadata,
+    },
+    /// Issue shares for a Wired investment
+    IssueShare {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+        /// Cents of the fundraiser currency per share
+        #[arg(long)]
+        share_price: u64,
+        /// Convert the price at the published rate of the deposit currency instead of the wire's rate
+        #[arg(long)]
+        fx_rate: bool,
+    },
+    /// Move the `--keypair` wallet's shares out of their lock vault
+    UnlockShares {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+    },
+    /// Record that a ShareIssued investment's shares were sold or redeemed, so its investor can close it
+    RecordShareSale {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+    },
+    /// Pay a dividend, in the fundraiser's primary mint, to the holder of a ShareIssued investment
+    IssueDividend {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Create the compliance configuration (program upgrade authority only)
+    InitializeCompliance {
+        #[arg(long, value_parser = parse::pubkey)]
+        authority: Pubkey,
+    },
+    /// Hand the compliance authority to a new key
+    SetComplianceAuthority {
+        #[arg(long, value_parser = parse::pubkey)]
+        new_authority: Pubkey,
+    },
+    /// Record the KYC outcome for an investor wallet
+    AttestInvestor {
+        #[arg(long, value_parser = parse::pubkey)]
+        wallet: Pubkey,
+        #[arg(long, value_enum)]
+        status: parse::KycStatus,
+        #[arg(long, value_parser = parse::jurisdiction)]
+        jurisdiction: state::Jurisdiction,
+        /// Unix timestamp the attestation expires
+        #[arg(long)]
+        expires_at: i64,
+        #[arg(long, value_enum)]
+        accreditation: parse::AccreditationCategory,
+        /// KYC record id, or its 64-character hex hash
+        #[arg(long, value_parser = parse::reference_hash)]
+        kyc_record: [u8; 32],
+    },
+    /// Freeze a holder's REIT token account on regulatory order
+    FreezeHolder {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::pubkey)]
+        holder_token_account: Pubkey,
+        /// Legal order reference, or its 64-character hex hash
+        #[arg(long, value_parser = parse::reference_hash)]
+        legal_reference: [u8; 32],
+    },
+    /// Thaw a frozen REIT token account
+    ThawHolder {
+        #[arg(long, value_parser = parse::reit_id_hash)]
+        reit_id: [u8; 16],
+        #[arg(long, value_parser = parse::pubkey)]
+        holder_token_account: Pubkey,
+        /// Legal order reference, or its 64-character hex hash
+        #[arg(long, value_parser = parse::reference_hash)]
+        legal_reference: [u8; 32],
+    },
+    /// Move a ShareIssued position to a new owner on regulatory order
+    RecoverPosition {
+        #[arg(long, value_parser = parse::pubkey)]
+        investment: Pubkey,
+        #[arg(long, value_parser = parse::pubkey)]
+        old_token_account: Pubkey,
+        #[arg(long, value_parser = parse::pubkey)]
+        new_owner: Pubkey,
+        /// Legal order reference, or its 64-character hex hash
+        #[arg(long, value_parser = parse::reference_hash)]
+        legal_reference: [u8; 32],
+        /// Fundraiser admin keypair, when it differs from the compliance authority `--keypair`
+        #[arg(long)]
+        admin_keypair: Option<PathBuf>,
+    },
+    /// Create the FX rate account for a pair (program upgrade authority only)
+    InitializeFxRate {
+        #[arg(long, value_parser = parse::currency, default_value = "USD")]
+        base: [u8; 3],
+        #[arg(long, value_parser = parse::currency)]
+        quote: [u8; 3],
+        #[arg(long, value_parser = parse::pubkey)]
+        publisher: Pubkey,
+        #[arg(long)]
+        max_staleness_seconds: i64,
+    },
+    /// Publish a rate for a currency pair as its publisher
+    PublishFxRate {
+        #[arg(long, value_parser = parse::currency, default_value = "USD")]
+        base: [u8; 3],
+        #[arg(long, value_parser = parse::currency)]
+        quote: [u8; 3],
+        /// Quote per base, scaled by 1_000_000
+        #[arg(long)]
+        rate: u64,
+        #[arg(long)]
+        confidence: u64,
+        /// Unix timestamp of the quote; defaults to now
+        #[arg(long)]
+        published_at: Option<i64>,
+    },
+    /// Replace the publisher of a currency pair (program upgrade authority only)
+    SetFxRatePublisher {
+        #[arg(long, value_parser = parse::currency, default_value = "USD")]
+        base: [u8; 3],
+        #[arg(long, value_parser = parse::currency)]
+        quote: [u8; 3],
+        #[arg(long, value_parser = parse::pubkey)]
+        publisher: Pubkey,
+    },
+    /// Rewrite program accounts written by an earlier program version in the current layout
+    Migrate {
+        /// Repeat for each account; each is migrated in its own transaction
+        #[arg(long = "account", value_parser = parse::pubkey, required = true)]
+        accounts: Vec<Pubkey>,
+        /// REIT id of each fundraiser among the accounts, which baseline fundraisers never stored
+        #[arg(long = "reit-id")]
+        reit_ids: Vec<String>,
+    },
+    /// Record bank wires for every investment listed in a CSV file
+    WireCsv(WireListArgs),
+    /// Issue shares for every investment listed in a CSV file
+    IssueSharesCsv(IssueSharesArgs),
+    /// Pay every dividend in a CSV schedule
+    PayDividendsCsv(DividendScheduleArgs),
+}
+
+#[derive(clap::Args)]
+pub struct WireDetails {
+    /// Fiat received, in cents of the fundraiser currency
+    #[arg(long)]
+    pub wired_amount: u64,
+    /// Fundraiser currency per USD, scaled by 1_000_000
+    #[arg(long)]
+    pub fx_rate: u64,
+    /// Unix timestamp the wire settled
+    #[arg(long)]
+    pub wire_date: i64,
+    /// Bank confirmation number, or its 64-character hex hash
+    #[arg(long, value_parser = parse::reference_hash)]
+    pub wire_reference: [u8; 32],
+}
+
+#[derive(clap::Args)]
+pub struct MintMetadata {
+    #[arg(long)]
+    pub name: String,
+    #[arg(long)]
+    pub symbol: String,
+    #[arg(long)]
+    pub uri: String,
+}
+
+impl Command {
+    pub fn run(self, ctx: &Context) -> Result<Vec<Outcome>> {
+        let signer = ctx.pubkey();
+        let outcome = match self {
+            Command::InitializeFundraiser { reit_id, usdc_mint, currency } => {
+                let instruction = ix::initialize_fundraiser(&signer, &usdc_mint, &reit_id, currency)?;
+                let fundraiser = pda::fundraiser(&parse::reit_id_hash(&reit_id)?);
+                ctx.submit("initialize-fundraiser", &[instruction], &[])
+                    .with_account("fundraiser", &fundraiser)
+                    .with_account("escrow_vault", &pda::escrow_vault(&fundraiser))
+            }
+            Command::UpdateFundraiserTerms { reit_id, currency, min_raise, max_raise, close_at } => ctx.submit(
+                "update-fundraiser-terms",
+                &[ix::update_fundraiser_terms(&signer, reit_id, currency, min_raise, max_raise, close_at)],
+                &[],
+            ),
+            Command::SetFundraiserStatus { reit_id, status } => ctx.submit(
+                "set-fundraiser-status",
+                &[ix::set_fundraiser_status(&signer, reit_id, status.into())],
+                &[],
+            ),
+            Command::CloseFundraiser { reit_id } => {
+                let fundraiser: state::Fundraiser = ctx.account(&pda::fundraiser(&reit_id))?;
+                let extra_vaults: Vec<Pubkey> =
+                    fundraiser.accepted_mints.iter().skip(1).map(|accepted| accepted.escrow_vault).collect();
+                ctx.submit("close-fundraiser", &[ix::close_fundraiser(&signer, reit_id, &extra_vaults)], &[])
+            }
+            Command::AddAcceptedMint { reit_id, mint, currency } => {
+                let address = pda::fundraiser(&reit_id);
+                let fundraiser: state::Fundraiser = ctx.account(&address)?;
+                ctx.submit("add-accepted-mint", &[ix::add_accepted_mint(&signer, reit_id, &fundraiser.usdc_mint, &mint, currency)], &[])
+                    .with_account("escrow_vault", &pda::accepted_mint_escrow_vault(&address, &mint))
+            }
+            Command::SetOfferingExemption { reit_id, exemption, non_eligible_limit, eligible_limit } => {
+                let limits = state::InvestmentLimits { non_eligible: non_eligible_limit, eligible: eligible_limit };
+                ctx.submit("set-offering-exemption", &[ix::set_offering_exemption(&signer, reit_id, exemption.into(), limits)], &[])
+            }
+            Command::SetJurisdictionRules { reit_id, mode, rules } => {
+                ctx.submit("set-jurisdiction-rules", &[ix::set_jurisdiction_rules(&signer, reit_id, mode.into(), rules)], &[])
+            }
+            Command::AddJurisdictionRule { reit_id, jurisdiction } => {
+                ctx.submit("add-jurisdiction-rule", &[ix::add_jurisdiction_rule(&signer, reit_id, jurisdiction)], &[])
+            }
+            Command::RemoveJurisdictionRule { reit_id, jurisdiction } => {
+                ctx.submit("remove-jurisdiction-rule", &[ix::remove_jurisdiction_rule(&signer, reit_id, jurisdiction)], &[])
+            }
+            Command::SetHoldPeriod { reit_id, seconds } => {
+                ctx.submit("set-hold-period", &[ix::set_hold_period(&signer, reit_id, seconds)], &[])
+            }
+            Command::InitializeInvestor => ctx
+                .submit("initialize-investor", &[ix::initialize_investor(&signer)], &[])
+                .with_account("investor", &pda::investor(&signer)),
+            Command::CloseInvestor => ctx.submit("close-investor", &[ix::close_investor(&signer)], &[]),
+            Command::Invest { reit_id, amount, mint } => {
+                let address = pda::fundraiser(&reit_id);
+                let fundraiser: state::Fundraiser = ctx.account(&address)?;
+                let mint = mint.unwrap_or(fundraiser.usdc_mint);
+                let escrow_vault =
+                    fundraiser.escrow_vault_for(&mint).ok_or_else(|| format!("mint {mint} is not accepted by this fundraiser"))?;
+                let counter = ctx
+                    .optional_account::<state::InvestorFundraiser>(&pda::investor_fundraiser(&signer, &address))?
+                    .map_or(0, |record| record.investment_counter);
+                ctx.submit("invest", &[ix::invest(&signer, reit_id, &mint, &escrow_vault, amount, counter)], &[])
+                    .with_account("investment", &pda::investment(&signer, &address, counter))
+            }
+            Command::CloseInvestment { investment, receipt } => {
+                let record: state::Investment = ctx.account(&investment)?;
+                ctx.submit("close-investment", &[ix::close_investment(&signer, &record.fundraiser, &investment, receipt)], &[])
+            }
+            Command::Release { investment } => {
+                let (record, fundraiser, reit_id_hash) = ctx.investment(&investment)?;
+                let escrow_vault = fundraiser
+                    .escrow_vault_for(&record.deposit_mint)
+                    .ok_or_else(|| format!("deposit mint {} has no escrow vault", record.deposit_mint))?;
+                ctx.submit(
+                    "release",
+                    &[ix::release(&signer, reit_id_hash, &investment, &record.deposit_mint, &escrow_vault)],
+                    &[],
+                )
+            }
+            Command::Refund { investment } => {
+                let (record, fundraiser, reit_id_hash) = ctx.investment(&investment)?;
+                let escrow_vault = fundraiser
+                    .escrow_vault_for(&record.deposit_mint)
+                    .ok_or_else(|| format!("deposit mint {} has no escrow vault", record.deposit_mint))?;
+                ctx.submit(
+                    "refund",
+                    &[ix::refund(&signer, reit_id_hash, &investment, &record.investor, &record.deposit_mint, &escrow_vault)],
+                    &[],
+                )
+            }
+            Command::Wire { investment, details } => {
+                let (_, _, reit_id_hash) = ctx.investment(&investment)?;
+                ctx.submit("wire", &[wire_instruction(&signer, reit_id_hash, &investment, &details)], &[])
+            }
+            Command::WireBatch { reit_id, investments, details } => {
+                let instruction = ix::wire_batch(
+                    &signer,
+                    reit_id,
+                    &investments,
+                    details.wired_amount,
+                    details.fx_rate,
+                    details.wire_date,
+                    details.wire_reference,
+                );
+                ctx.submit("wire-batch", &[instruction], &[])
+                    .with_account("wire_batch", &pda::wire_batch(&pda::fundraiser(&reit_id), &details.wire_reference))
+            }
+            Command::CreateReitMint { reit_id, metadata, mint_keypair } => {
+                let mint = load_or_generate(mint_keypair)?;
+                let instruction = ix::create_reit_mint(
+                    &signer,
+                    reit_id,
+                    &mint.pubkey(),
+                    metadata.name,
+                    metadata.symbol,
+                    metadata.uri,
+                );
+                ctx.submit("create-reit-mint", &[instruction], &[&mint]).with_account("reit_mint", &mint.pubkey())
+            }
+            Command::CreateReitMint2022 { reit_id, metadata, mint_keypair, transfer_hook_program } => {
+                let mint = load_or_generate(mint_keypair)?;
+                let instruction = ix::create_reit_mint_2022(
+                    &signer,
+                    reit_id,
+                    &mint.pubkey(),
+                    &transfer_hook_program,
+                    metadata.name,
+                    metadata.symbol,
+                    metadata.uri,
+                );
+                ctx.submit("create-reit-mint-2022", &[instruction], &[&mint]).with_account("reit_mint", &mint.pubkey())
+            }
+            Command::UpdateReitMint { reit_id, metadata } => {
+                let fundraiser: state::Fundraiser = ctx.account(&pda::fundraiser(&reit_id))?;
+                let instruction = ix::update_reit_mint(
+                    &signer,
+                    reit_id,
+                    &fundraiser.reit_mint,
+                    &fundraiser.reit_token_program,
+                    metadata.name,
+                    metadata.symbol,
+                    metadata.uri,
+                );
+                ctx.submit("update-reit-mint", &[instruction], &[])
+            }
+            Command::IssueShare { investment, share_price, fx_rate } => {
+                ctx.submit("issue-share", &[issue_share_instruction(ctx, &investment, share_price, fx_rate)?], &[])
+            }
+            Command::UnlockShares { investment } => {
+                let (_, fundraiser, reit_id_hash) = ctx.investment(&investment)?;
+                let instruction = ix::unlock_shares(
+                    &signer,
+                    reit_id_hash,
+                    &investment,
+                    &fundraiser.admin,
+                    &fundraiser.reit_mint,
+                    &fundraiser.reit_token_program,
+                );
+                ctx.submit("unlock-shares", &[instruction], &[])
+            }
+            Command::RecordShareSale { investment } => {
+                let (_, _, reit_id_hash) = ctx.investment(&investment)?;
+                ctx.submit("record-share-sale", &[ix::record_share_sale(&signer, reit_id_hash, &investment)], &[])
+            }
+            Command::IssueDividend { investment, amount } => {
+                ctx.submit("issue-dividend", &[dividend_instruction(ctx, &investment, amount)?], &[])
+            }
+            Command::InitializeCompliance { authority } => ctx
+                .submit("initialize-compliance", &[ix::initialize_compliance(&signer, &authority)], &[])
+                .with_account("compliance_config", &pda::compliance_config()),
+            Command::SetComplianceAuthority { new_authority } => {
+                ctx.submit("set-compliance-authority", &[ix::set_compliance_authority(&signer, &new_authority)], &[])
+            }
+            Command::AttestInvestor { wallet, status, jurisdiction, expires_at, accreditation, kyc_record } => {
+                let instruction = ix::attest_investor(
+                    &signer,
+                    &wallet,
+                    status.into(),
+                    jurisdiction,
+                    expires_at,
+                    accreditation.into(),
+                    kyc_record,
+                );
+                ctx.submit("attest-investor", &[instruction], &[])
+                    .with_account("kyc_attestation", &pda::kyc_attestation(&wallet))
+            }
+            Command::FreezeHolder { reit_id, holder_token_account, legal_reference } => {
+                let fundraiser: state::Fundraiser = ctx.account(&pda::fundraiser(&reit_id))?;
+                let instruction = ix::freeze_holder(
+                    &signer,
+                    reit_id,
+                    &fundraiser.reit_mint,
+                    &holder_token_account,
+                    &fundraiser.reit_token_program,
+                    legal_reference,
+                );
+                ctx.submit("freeze-holder", &[instruction], &[])
+            }
+            Command::ThawHolder { reit_id, holder_token_account, legal_reference } => {
+                let fundraiser: state::Fundraiser = ctx.account(&pda::fundraiser(&reit_id))?;
+                let instruction = ix::thaw_holder(
+                    &signer,
+                    reit_id,
+                    &fundraiser.reit_mint,
+                    &holder_token_account,
+                    &fundraiser.reit_token_program,
+                    legal_reference,
+                );
+                ctx.submit("thaw-holder", &[instruction], &[])
+            }
+            Command::RecoverPosition { investment, old_token_account, new_owner, legal_reference, admin_keypair } => {
+                let (position, fundraiser, reit_id_hash) = ctx.investment(&investment)?;
+                let admin = admin_keypair.map(read_keypair).transpose()?;
+                let instruction = ix::recover_position(
+                    &signer,
+                    &fundraiser.admin,
+                    reit_id_hash,
+                    &investment,
+                    &position.investor,
+                    &fundraiser.reit_mint,
+                    &fundraiser.reit_token_program,
+                    &old_token_account,
+                    &new_owner,
+                    legal_reference,
+                );
+                let extra_signers: Vec<&Keypair> = admin.iter().collect();
+                ctx.submit("recover-position", &[instruction], &extra_signers)
+            }
+            Command::InitializeFxRate { base, quote, publisher, max_staleness_seconds } => ctx
+                .submit("initialize-fx-rate", &[ix::initialize_fx_rate(&signer, base, quote, &publisher, max_staleness_seconds)], &[])
+                .with_account("fx_rate", &pda::fx_rate(&base, &quote)),
+            Command::PublishFxRate { base, quote, rate, confidence, published_at } => {
+                let published_at = published_at.unwrap_or_else(now);
+                ctx.submit("publish-fx-rate", &[ix::publish_fx_rate(&signer, base, quote, rate, confidence, published_at)], &[])
+            }
+            Command::SetFxRatePublisher { base, quote, publisher } => ctx
+                .submit("set-fx-rate-publisher", &[ix::set_fx_rate_publisher(&signer, base, quote, &publisher)], &[])
+                .with_account("fx_rate", &pda::fx_rate(&base, &quote)),
+            Command::Migrate { accounts, reit_ids } => {
+                let fundraisers = reit_ids
+                    .iter()
+                    .map(|reit_id| Ok((pda::fundraiser(&parse::reit_id_hash(reit_id)?), reit_id.trim())))
+                    .collect::<std::result::Result<Vec<_>, String>>()?;
+                return Ok(accounts
+                    .iter()
+                    .map(|account| {
+                        let reit_id = fundraisers.iter().find(|(fundraiser, _)| fundraiser == account).map(|(_, reit_id)| *reit_id);
+                        let instruction = match ctx.rpc.get_account_data(account) {
+                            Ok(data) => ix::migrate(&signer, account, &data, reit_id),
+                            Err(err) => return Outcome::failed("migrate", ctx.dry_run, format!("account {account}: {err}")),
+                        };
+                        match instruction {
+                            Some(instruction) => ctx.submit("migrate", &[instruction], &[]),
+                            None => Outcome::failed(
+                                "migrate",
+                                ctx.dry_run,
+                                format!("account {account} is not a program account, or a fundraiser without its --reit-id"),
+                            ),
+                        }
+                        .with_account("account", account)
+                    })
+                    .collect());
+            }
+            Command::WireCsv(args) => return bulk::wire(ctx, args),
+            Command::IssueSharesCsv(args) => return bulk::issue_shares(ctx, args),
+            Command::PayDividendsCsv(args) => return bulk::pay_dividends(ctx, args),
+        };
+        Ok(vec![outcome])
+    }
+}
+
+pub fn wire_instruction(admin: &Pubkey, reit_id_hash: [u8; 16], investment: &Pubkey, details: &WireDetails) -> Instruction {
+    ix::wire(
+        admin,
+        reit_id_hash,
+        investment,
+        details.wired_amount,
+        details.fx_rate,
+        details.wire_date,
+        details.wire_reference,
+    )
+}
+
+/// Builds `issue_share` for an investment, choosing the lock vault and FX rate accounts the
+/// same way the program does.
+pub fn issue_share_instruction(ctx: &Context, investment: &Pubkey, share_price: u64, use_fx_rate: bool) -> Result<Instruction> {
+    let (record, fundraiser, reit_id_hash) = ctx.investment(investment)?;
+    let use_lock_vault = fundraiser.reit_token_program == token::ID && fundraiser.hold_period_seconds > 0;
+    // The program prices the deposit in the currency its stablecoin is pegged to
+    let fx_rate = if use_fx_rate {
+        let deposit = fundraiser
+            .accepted_mint(&record.deposit_mint)
+            .ok_or_else(|| format!("deposit mint {} is not accepted", record.deposit_mint))?;
+        Some(pda::fx_rate(&deposit.currency, &fundraiser.reit_accepted_currency))
+    } else {
+        None
+    };
+    Ok(ix::issue_share(
+        &ctx.pubkey(),
+        reit_id_hash,
+        investment,
+        &record.investor,
+        &fundraiser.reit_mint,
+        &fundraiser.reit_token_program,
+        use_lock_vault,
+        fx_rate,
+        share_price,
+    ))
+}
+
+/// Dividends are paid in the fundraiser's primary mint, the only one `issue_dividend` accepts.
+pub fn dividend_instruction(ctx: &Context, investment: &Pubkey, amount: u64) -> Result<Instruction> {
+    let (record, fundraiser, reit_id_hash) = ctx.investment(investment)?;
+    Ok(ix::issue_dividend(&ctx.pubkey(), reit_id_hash, investment, &record.investor, &fundraiser.usdc_mint, amount))
+}
+
+pub fn read_keypair(path: PathBuf) -> Result<Keypair> {
+    read_keypair_file(&path).map_err(|err| format!("keypair {}: {err}", path.display()).into())
+}
+
+fn load_or_generate(path: Option<PathBuf>) -> Result<Keypair> {
+    path.map_or_else(|| Ok(Keypair::new()), read_keypair)
+}
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs() as i64)
+}
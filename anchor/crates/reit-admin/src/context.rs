@@ -0,0 +1,143 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AccountDeserialize;
+use canadianreitinvest_client::{accounts, state};
+use serde::Serialize;
+use solana_commitment_config::CommitmentConfig;
+use solana_keypair::Keypair;
+use solana_rpc_client::rpc_client::RpcClient;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::Result;
+
+/// RPC connection, signing keypair and dry-run setting shared by every command
+pub struct Context {
+    pub rpc: RpcClient,
+    pub keypair: Keypair,
+    pub dry_run: bool,
+}
+
+/// Result of submitting (or simulating) one transaction
+#[derive(Serialize)]
+pub struct Outcome {
+    pub command: String,
+    /// CSV row the transaction came from, for bulk commands
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row: Option<usize>,
+    pub dry_run: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compute_units: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Accounts the command created or acted on, e.g. a new investment or REIT mint
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<String>,
+}
+
+impl Outcome {
+    pub fn new(command: &str, dry_run: bool) -> Self {
+        Outcome {
+            command: command.to_string(),
+            row: None,
+            dry_run,
+            signature: None,
+            compute_units: None,
+            error: None,
+            accounts: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    /// An outcome for a transaction that never got built, under the run's `dry_run` setting.
+    pub fn failed(command: &str, dry_run: bool, error: impl ToString) -> Self {
+        Outcome { error: Some(error.to_string()), ..Outcome::new(command, dry_run) }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn with_account(mut self, name: &str, address: &Pubkey) -> Self {
+        self.accounts.push((name.to_string(), address.to_string()));
+        self
+    }
+
+    pub fn at_row(mut self, row: usize) -> Self {
+        self.row = Some(row);
+        self
+    }
+}
+
+impl Context {
+    pub fn new(url: &str, keypair: Keypair, dry_run: bool) -> Self {
+        Context { rpc: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()), keypair, dry_run }
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    /// Sends `instructions` as one transaction paid and signed by the context keypair plus
+    /// `extra_signers`, or only simulates it with `--dry-run`. Failures are reported in the
+    /// outcome rather than returned, so bulk commands can carry on with the next row.
+    pub fn submit(&self, command: &str, instructions: &[Instruction], extra_signers: &[&Keypair]) -> Outcome {
+        let mut outcome = Outcome::new(command, self.dry_run);
+
+        let blockhash = match self.rpc.get_latest_blockhash() {
+            Ok(blockhash) => blockhash,
+            Err(err) => {
+                outcome.error = Some(err.to_string());
+                return outcome;
+            }
+        };
+        let mut signers: Vec<&Keypair> = vec![&self.keypair];
+        signers.extend_from_slice(extra_signers);
+        let tx = Transaction::new_signed_with_payer(instructions, Some(&self.pubkey()), &signers, blockhash);
+
+        if self.dry_run {
+            match self.rpc.simulate_transaction(&tx) {
+                Ok(response) => {
+                    let simulation = response.value;
+                    outcome.error = simulation.err.map(|err| err.to_string());
+                    outcome.compute_units = simulation.units_consumed;
+                    outcome.logs = simulation.logs.unwrap_or_default();
+                }
+                Err(err) => outcome.error = Some(err.to_string()),
+            }
+        } else {
+            match self.rpc.send_and_confirm_transaction(&tx) {
+                Ok(signature) => outcome.signature = Some(signature.to_string()),
+                Err(err) => outcome.error = Some(err.to_string()),
+            }
+        }
+        outcome
+    }
+
+    /// Fetches and decodes a program account
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let data = self.rpc.get_account_data(address).map_err(|err| format!("account {address}: {err}"))?;
+        Ok(accounts::decode(&data).map_err(|err| format!("account {address}: {err}"))?)
+    }
+
+    /// Fetches a program account, or `None` if it doesn't exist yet
+    pub fn optional_account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        let account = self.rpc.get_account_with_commitment(address, self.rpc.commitment())?.value;
+        match account {
+            Some(account) => Ok(Some(accounts::decode(&account.data).map_err(|err| format!("account {address}: {err}"))?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches an investment together with its fundraiser and the fundraiser's reit_id_hash
+    pub fn investment(&self, address: &Pubkey) -> Result<(state::Investment, state::Fundraiser, [u8; 16])> {
+        let investment: state::Investment = self.account(address)?;
+        let fundraiser: state::Fundraiser = self.account(&investment.fundraiser)?;
+        let reit_id_hash = state::reit_id_hash(&fundraiser.reit_id)?;
+        Ok((investment, fundraiser, reit_id_hash))
+    }
+}
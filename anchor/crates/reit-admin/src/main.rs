@@ -0,0 +1,101 @@
+//! `reit-admin`: command-line operations for canadianreitinvest fundraisers.
+//!
+//! Every program instruction has a subcommand, and `wire-csv`, `issue-shares-csv` and
+//! `pay-dividends-csv` run one per CSV row. `--dry-run` simulates instead of sending and
+//! `--json` prints machine-readable outcomes, e.g.
+//!
+//! ```text
+//! reit-admin --url http://127.0.0.1:8899 --dry-run --json pay-dividends-csv q3-dividends.csv
+//! ```
+
+mod bulk;
+mod commands;
+mod context;
+mod parse;
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::commands::Command;
+use crate::context::{Context, Outcome};
+
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+#[derive(Parser)]
+#[command(name = "reit-admin", version, about = "Fundraiser operations for the canadianreitinvest program")]
+struct Cli {
+    /// RPC endpoint
+    #[arg(long, global = true, env = "REIT_ADMIN_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+    /// Signing keypair: the admin, compliance authority, FX publisher or investor
+    #[arg(long, global = true, env = "REIT_ADMIN_KEYPAIR")]
+    keypair: Option<PathBuf>,
+    /// Simulate transactions instead of sending them
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// Print outcomes as JSON
+    #[arg(long, global = true)]
+    json: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+fn default_keypair_path() -> PathBuf {
+    let home = std::env::var_os("HOME").unwrap_or_default();
+    PathBuf::from(home).join(".config/solana/id.json")
+}
+
+fn print_outcomes(outcomes: &[Outcome], json: bool) {
+    if json {
+        let rendered = match outcomes {
+            [outcome] => serde_json::to_string_pretty(outcome),
+            _ => serde_json::to_string_pretty(outcomes),
+        };
+        println!("{}", rendered.expect("outcomes serialize to JSON"));
+        return;
+    }
+    for outcome in outcomes {
+        let row = outcome.row.map(|row| format!(" (row {row})")).unwrap_or_default();
+        match (&outcome.error, &outcome.signature) {
+            (Some(error), _) => println!("{}{row}: failed: {error}", outcome.command),
+            (None, Some(signature)) => println!("{}{row}: {signature}", outcome.command),
+            (None, None) => println!(
+                "{}{row}: simulated, {} compute units",
+                outcome.command,
+                outcome.compute_units.unwrap_or_default()
+            ),
+        }
+        for (name, address) in &outcome.accounts {
+            println!("  {name}: {address}");
+        }
+        if outcome.error.is_some() {
+            for line in &outcome.logs {
+                println!("  {line}");
+            }
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<Vec<Outcome>> {
+    let keypair = commands::read_keypair(cli.keypair.unwrap_or_else(default_keypair_path))?;
+    let ctx = Context::new(&cli.url, keypair, cli.dry_run);
+    cli.command.run(&ctx)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let (json, dry_run) = (cli.json, cli.dry_run);
+    let outcomes = match run(cli) {
+        Ok(outcomes) => outcomes,
+        Err(err) => vec![Outcome::failed("reit-admin", dry_run, err)],
+    };
+    print_outcomes(&outcomes, json);
+    if outcomes.iter().all(Outcome::is_ok) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
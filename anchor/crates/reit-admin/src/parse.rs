@@ -0,0 +1,199 @@
+//! Argument parsers and CLI-side mirrors of the program's enums.
+
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use canadianreitinvest_client::state;
+use clap::ValueEnum;
+
+pub fn pubkey(value: &str) -> Result<Pubkey, String> {
+    Pubkey::from_str(value.trim()).map_err(|err| format!("invalid pubkey `{value}`: {err}"))
+}
+
+/// A REIT id in canonical UUID form, parsed to the 16-byte hash the program seeds with
+pub fn reit_id_hash(value: &str) -> Result<[u8; 16], String> {
+    state::reit_id_hash(value.trim()).map_err(|_| format!("invalid REIT id `{value}`, expected a hyphenated UUID"))
+}
+
+/// ISO 4217 currency code, e.g. `CAD`
+pub fn currency(value: &str) -> Result<[u8; 3], String> {
+    let value = value.trim().to_ascii_uppercase();
+    value.as_bytes().try_into().map_err(|_| format!("invalid currency `{value}`, expected a 3-letter code"))
+}
+
+/// ISO 3166 jurisdiction, `CA` for a whole country or `CA-ON` for a subdivision
+pub fn jurisdiction(value: &str) -> Result<state::Jurisdiction, String> {
+    let value = value.trim().to_ascii_uppercase();
+    let (country, subdivision) = value.split_once('-').unwrap_or((&value, ""));
+    let country: [u8; 2] = country.as_bytes().try_into().map_err(|_| format!("invalid country in `{value}`"))?;
+    if subdivision.len() > 3 {
+        return Err(format!("invalid subdivision in `{value}`"));
+    }
+    let mut padded = [0u8; 3];
+    padded[..subdivision.len()].copy_from_slice(subdivision.as_bytes());
+    Ok(state::Jurisdiction { country, subdivision: padded })
+}
+
+/// A 32-byte reference hash: 64 hex characters are taken as the hash itself, anything else
+/// (a wire confirmation number, a court order id) is hashed with SHA-256.
+pub fn reference_hash(value: &str) -> Result<[u8; 32], String> {
+    let value = value.trim();
+    if value.len() == 64 && value.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        let mut bytes = [0u8; 32];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16).map_err(|err| err.to_string())?;
+        }
+        return Ok(bytes);
+    }
+    if value.is_empty() {
+        return Err("reference must not be empty".to_string());
+    }
+    Ok(hash(value.as_bytes()).to_bytes())
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum FundraiserStatus {
+    Draft,
+    Open,
+    Closed,
+    Funded,
+    Failed,
+    Operating,
+    WindingDown,
+    Terminated,
+}
+
+impl From<FundraiserStatus> for state::FundraiserStatus {
+    fn from(status: FundraiserStatus) -> Self {
+        match status {
+            FundraiserStatus::Draft => state::FundraiserStatus::Draft,
+            FundraiserStatus::Open => state::FundraiserStatus::Open,
+            FundraiserStatus::Closed => state::FundraiserStatus::Closed,
+            FundraiserStatus::Funded => state::FundraiserStatus::Funded,
+            FundraiserStatus::Failed => state::FundraiserStatus::Failed,
+            FundraiserStatus::Operating => state::FundraiserStatus::Operating,
+            FundraiserStatus::WindingDown => state::FundraiserStatus::WindingDown,
+            FundraiserStatus::Terminated => state::FundraiserStatus::Terminated,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum OfferingExemption {
+    Prospectus,
+    AccreditedInvestor,
+    OfferingMemorandum,
+}
+
+impl From<OfferingExemption> for state::OfferingExemption {
+    fn from(exemption: OfferingExemption) -> Self {
+        match exemption {
+            OfferingExemption::Prospectus => state::OfferingExemption::Prospectus,
+            OfferingExemption::AccreditedInvestor => state::OfferingExemption::AccreditedInvestor,
+            OfferingExemption::OfferingMemorandum => state::OfferingExemption::OfferingMemorandum,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum JurisdictionMode {
+    Allowlist,
+    Denylist,
+}
+
+impl From<JurisdictionMode> for state::JurisdictionMode {
+    fn from(mode: JurisdictionMode) -> Self {
+        match mode {
+            JurisdictionMode::Allowlist => state::JurisdictionMode::Allowlist,
+            JurisdictionMode::Denylist => state::JurisdictionMode::Denylist,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum KycStatus {
+    Pending,
+    Verified,
+    Rejected,
+    Revoked,
+}
+
+impl From<KycStatus> for state::KycStatus {
+    fn from(status: KycStatus) -> Self {
+        match status {
+            KycStatus::Pending => state::KycStatus::Pending,
+            KycStatus::Verified => state::KycStatus::Verified,
+            KycStatus::Rejected => state::KycStatus::Rejected,
+            KycStatus::Revoked => state::KycStatus::Revoked,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum AccreditationCategory {
+    NonEligible,
+    Eligible,
+    Accredited,
+}
+
+impl From<AccreditationCategory> for state::AccreditationCategory {
+    fn from(category: AccreditationCategory) -> Self {
+        match category {
+            AccreditationCategory::NonEligible => state::AccreditationCategory::NonEligible,
+            AccreditationCategory::Eligible => state::AccreditationCategory::Eligible,
+            AccreditationCategory::Accredited => state::AccreditationCategory::Accredited,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pubkeys_are_trimmed_and_validated() {
+        let key = Pubkey::new_unique();
+        assert_eq!(pubkey(&format!("  {key} ")), Ok(key));
+        assert!(pubkey("not-a-key").unwrap_err().contains("not-a-key"));
+    }
+
+    #[test]
+    fn reit_ids_must_be_canonical_uuids() {
+        let id = "3f2b8c1e-9a4d-4e6f-8b7a-1c2d3e4f5a6b";
+        assert_eq!(reit_id_hash(id), Ok(state::reit_id_hash(id).unwrap()));
+        assert_eq!(reit_id_hash(&format!(" {id}\n")), reit_id_hash(id));
+        assert!(reit_id_hash("3f2b8c1e9a4d4e6f8b7a1c2d3e4f5a6b").is_err());
+        assert!(reit_id_hash("reit-1").is_err());
+    }
+
+    #[test]
+    fn currencies_are_three_letter_codes() {
+        assert_eq!(currency("cad"), Ok(*b"CAD"));
+        assert_eq!(currency(" usd "), Ok(*b"USD"));
+        assert!(currency("US").is_err());
+        assert!(currency("USDC").is_err());
+    }
+
+    #[test]
+    fn jurisdictions_pad_the_subdivision() {
+        assert_eq!(jurisdiction("ca"), Ok(state::Jurisdiction { country: *b"CA", subdivision: [0; 3] }));
+        assert_eq!(jurisdiction("CA-ON"), Ok(state::Jurisdiction { country: *b"CA", subdivision: *b"ON\0" }));
+        assert_eq!(jurisdiction("gb-eng"), Ok(state::Jurisdiction { country: *b"GB", subdivision: *b"ENG" }));
+        assert!(jurisdiction("CAN").is_err());
+        assert!(jurisdiction("US-ABCD").is_err());
+    }
+
+    #[test]
+    fn reference_hashes_take_hex_as_is_and_hash_anything_else() {
+        let hex = "00".repeat(31) + "ff";
+        let mut expected = [0u8; 32];
+        expected[31] = 0xff;
+        assert_eq!(reference_hash(&hex), Ok(expected));
+        assert_eq!(reference_hash(&hex.to_uppercase()), Ok(expected));
+        assert_eq!(reference_hash(" WIRE-2024-001 "), Ok(hash(b"WIRE-2024-001").to_bytes()));
+        // 63 hex characters are a reference like any other, not a malformed hash
+        assert_eq!(reference_hash(&hex[1..]), Ok(hash(&hex.as_bytes()[1..]).to_bytes()));
+        assert!(reference_hash("   ").is_err());
+    }
+}
@@ -22,4 +22,60 @@ pub enum CustomError {
     InvalidMint,
     #[msg("Invalid fundraiser mismatch")]
     InvalidFundraiserMismatch,
+    #[msg("Investor KYC attestation is not verified")]
+    KycNotVerified,
+    #[msg("Investor KYC attestation has expired")]
+    KycExpired,
+    #[msg("Investor category is not eligible for this offering")]
+    InvestorNotEligible,
+    #[msg("Investment exceeds the investor's rolling 12-month limit")]
+    InvestmentLimitExceeded,
+    #[msg("Investor jurisdiction is not permitted for this offering")]
+    JurisdictionBlocked,
+    #[msg("Too many jurisdiction rules")]
+    TooManyJurisdictionRules,
+    #[msg("Jurisdiction rule not found")]
+    JurisdictionRuleNotFound,
+    #[msg("Shares are still within the resale hold period")]
+    HoldPeriodActive,
+    #[msg("Lock vault account is required for this REIT mint")]
+    LockVaultRequired,
+    #[msg("Position recovery is not supported for this REIT mint")]
+    RecoveryNotSupported,
+    #[msg("Invalid wire details")]
+    InvalidWireDetails,
+    #[msg("Invalid wire batch")]
+    InvalidWireBatch,
+    #[msg("Invalid FX rate")]
+    InvalidFxRate,
+    #[msg("FX rate is stale")]
+    StaleFxRate,
+    #[msg("FX rate confidence interval is too wide")]
+    FxRateConfidenceTooWide,
+    #[msg("Currency is not an accepted ISO 4217 code")]
+    UnsupportedCurrency,
+    #[msg("Fundraiser terms can no longer be changed")]
+    FundraiserTermsLocked,
+    #[msg("Fundraiser accepts the maximum number of stablecoin mints")]
+    TooManyAcceptedMints,
+    #[msg("Instruction not allowed in the fundraiser's current status")]
+    InvalidFundraiserStatus,
+    #[msg("Fundraiser status transition not allowed")]
+    InvalidFundraiserTransition,
+    #[msg("Investment would exceed the fundraiser cap")]
+    FundraiserCapExceeded,
+    #[msg("Fundraiser deadline has passed")]
+    FundraiserDeadlinePassed,
+    #[msg("Escrow vault still holds funds")]
+    EscrowNotEmpty,
+    #[msg("Fundraiser still has open investments")]
+    InvestmentsOutstanding,
+    #[msg("Investor still has open investments")]
+    InvestorHasOpenPositions,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+    #[msg("Fundraiser already has a REIT mint")]
+    ReitMintAlreadyCreated,
+    #[msg("Stablecoin is not pegged to the fundraiser's deposit currency")]
+    DepositCurrencyMismatch,
 }
\ No newline at end of file
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state;
+
+pub fn handler(ctx: Context<AddAcceptedMint>, _reit_id_hash: [u8; 16], currency: [u8; 3]) -> Result<()> {
+    debug_msg!("AddAcceptedMint handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Draft, state::FundraiserStatus::Open])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Mint: {}", ctx.accounts.mint.key());
+    debug_msg!("Escrow vault: {}", ctx.accounts.escrow_vault.key());
+    debug_msg!("Currency: {:?}", currency);
+    state::require_accepted_currency(&currency)?;
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+
+    // total_raised, min_raise/max_raise and the rolling investment limits add deposits
+    // together, so every stablecoin must be pegged to the primary mint's currency
+    if currency != fundraiser.deposit_currency() {
+        debug_msg!("ERROR: Mint is pegged to {:?}, deposits are in {:?}", currency, fundraiser.deposit_currency());
+        return Err(error!(crate::errors::CustomError::DepositCurrencyMismatch));
+    }
+
+    if fundraiser.escrow_vault_for(&ctx.accounts.mint.key()).is_some() {
+        debug_msg!("ERROR: Mint {} is already accepted", ctx.accounts.mint.key());
+        return Err(error!(crate::errors::CustomError::InvalidMint));
+    }
+
+    // Totals and investment limits are kept in base units, so every stablecoin
+    // must share the primary mint's decimals
+    if ctx.accounts.mint.decimals != ctx.accounts.usdc_mint.decimals {
+        debug_msg!("ERROR: Mint has {} decimals, primary mint has {}", ctx.accounts.mint.decimals, ctx.accounts.usdc_mint.decimals);
+        return Err(error!(crate::errors::CustomError::InvalidMint));
+    }
+
+    if fundraiser.accepted_mints.len() >= state::MAX_ACCEPTED_MINTS {
+        return Err(error!(crate::errors::CustomError::TooManyAcceptedMints));
+    }
+
+    fundraiser.accepted_mints.push(state::AcceptedMint {
+        mint: ctx.accounts.mint.key(),
+        escrow_vault: ctx.accounts.escrow_vault.key(),
+        currency,
+    });
+    debug_msg!("Accepted mints: {}", fundraiser.accepted_mints.len());
+
+    debug_msg!("AddAcceptedMint handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16], currency: [u8; 3])]
+pub struct AddAcceptedMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    /// Primary stablecoin, used to check decimals
+    #[account(address = fundraiser.usdc_mint @ crate::errors::CustomError::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Stablecoin to accept
+    pub mint: Account<'info, Mint>,
+
+    /// Escrow vault for deposits of `mint`, owned by the fundraiser PDA
+    #[account(
+        init,
+        payer = admin,
+        token::mint = mint,
+        token::authority = fundraiser,
+        seeds = [b"escrow_vault", fundraiser.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
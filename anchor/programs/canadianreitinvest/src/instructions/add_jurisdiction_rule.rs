@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(ctx: Context<AddJurisdictionRule>, _reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Result<()> {
+    debug_msg!("AddJurisdictionRule handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Draft, state::FundraiserStatus::Open])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Jurisdiction: {:?}", jurisdiction);
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    if fundraiser.jurisdiction_rules.contains(&jurisdiction) {
+        debug_msg!("Jurisdiction already listed, nothing to do");
+        return Ok(());
+    }
+    if fundraiser.jurisdiction_rules.len() >= state::MAX_JURISDICTION_RULES {
+        debug_msg!("ERROR: Fundraiser already has the maximum of {} rules", state::MAX_JURISDICTION_RULES);
+        return Err(error!(crate::errors::CustomError::TooManyJurisdictionRules));
+    }
+    fundraiser.jurisdiction_rules.push(jurisdiction);
+
+    debug_msg!("AddJurisdictionRule handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct AddJurisdictionRule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
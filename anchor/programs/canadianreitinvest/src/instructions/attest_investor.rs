@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{self, Versioned};
+
+pub fn handler(
+    ctx: Context<AttestInvestor>,
+    investor_wallet: Pubkey,
+    status: state::KycStatus,
+    jurisdiction: state::Jurisdiction,
+    expires_at: i64,
+    accreditation: state::AccreditationCategory,
+    kyc_record_hash: [u8; 32],
+) -> Result<()> {
+    debug_msg!("AttestInvestor handler start");
+    debug_msg!("Investor wallet: {}", investor_wallet);
+    debug_msg!("Investor PDA: {}", ctx.accounts.investor.key());
+    debug_msg!("Status: {:?}, Accreditation: {:?}, Expires at: {}", status, accreditation, expires_at);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // A verified attestation must be valid for at least some time going forward
+    if status == state::KycStatus::Verified && expires_at <= now {
+        debug_msg!("ERROR: Verified attestation expiry {} is not after current time {}", expires_at, now);
+        return Err(error!(crate::errors::CustomError::KycExpired));
+    }
+
+    let kyc_attestation = &mut ctx.accounts.kyc_attestation;
+    kyc_attestation.investor = investor_wallet;
+    kyc_attestation.status = status;
+    kyc_attestation.jurisdiction = jurisdiction;
+    kyc_attestation.expires_at = expires_at;
+    kyc_attestation.accreditation = accreditation;
+    kyc_attestation.kyc_record_hash = kyc_record_hash;
+    kyc_attestation.updated_at = now;
+    kyc_attestation.version = state::KycAttestation::VERSION;
+    kyc_attestation.bump = ctx.bumps.kyc_attestation;
+
+    debug_msg!("AttestInvestor handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(investor_wallet: Pubkey)]
+pub struct AttestInvestor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        constraint = compliance_config.authority == authority.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub compliance_config: Account<'info, state::ComplianceConfig>,
+
+    /// Investor PDA address for the wallet; the account itself does not need to exist yet
+    /// CHECK: only used as a seed, address enforced by the seeds constraint
+    #[account(
+        seeds = [b"investor", investor_wallet.as_ref()],
+        bump
+    )]
+    pub investor: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + state::KycAttestation::INIT_SPACE,
+        seeds = [b"kyc_attestation", investor.key().as_ref()],
+        bump
+    )]
+    pub kyc_attestation: Account<'info, state::KycAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
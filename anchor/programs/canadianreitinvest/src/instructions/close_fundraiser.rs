@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::state;
+
+/// Closes a terminated fundraiser and every escrow vault it owns, returning the rent to the admin.
+/// The primary vault is passed as `escrow_vault`; vaults of the other accepted mints are passed
+/// as remaining accounts in `accepted_mints` order.
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseFundraiser<'info>>, reit_id_hash: [u8; 16]) -> Result<()> {
+    debug_msg!("CloseFundraiser handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Terminated])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+
+    let fundraiser = &ctx.accounts.fundraiser;
+    if fundraiser.open_investment_count != 0 {
+        debug_msg!("ERROR: {} of {} investments are still open", fundraiser.open_investment_count, fundraiser.investment_count);
+        return Err(error!(crate::errors::CustomError::InvestmentsOutstanding));
+    }
+
+    let extra_vaults = &fundraiser.accepted_mints[1..];
+    if ctx.remaining_accounts.len() != extra_vaults.len() {
+        debug_msg!("ERROR: Expected {} additional escrow vaults, got {}", extra_vaults.len(), ctx.remaining_accounts.len());
+        return Err(error!(crate::errors::CustomError::InvalidAuthority));
+    }
+
+    let mut vaults = vec![ctx.accounts.escrow_vault.clone()];
+    for (accepted, account_info) in extra_vaults.iter().zip(ctx.remaining_accounts.iter()) {
+        if account_info.key() != accepted.escrow_vault {
+            debug_msg!("ERROR: Escrow vault {} does not match {} for mint {}", account_info.key(), accepted.escrow_vault, accepted.mint);
+            return Err(error!(crate::errors::CustomError::InvalidAuthority));
+        }
+        vaults.push(Account::<TokenAccount>::try_from(account_info)?);
+    }
+
+    let seeds = &[
+        b"fundraiser",
+        reit_id_hash.as_slice(),
+        &[fundraiser.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    for vault in vaults.iter() {
+        if vault.amount != 0 {
+            debug_msg!("ERROR: Escrow vault {} still holds {}", vault.key(), vault.amount);
+            return Err(error!(crate::errors::CustomError::EscrowNotEmpty));
+        }
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.admin.to_account_info(),
+                authority: ctx.accounts.fundraiser.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+        debug_msg!("Closed escrow vault {}", vault.key());
+    }
+
+    debug_msg!("CloseFundraiser handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct CloseFundraiser<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(mut, address = fundraiser.escrow_vault @ crate::errors::CustomError::InvalidAuthority)]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+/// Closes a settled Investment and returns its rent to the investor. When `emit_receipt` is set,
+/// the final state is emitted as an `InvestmentReceipt` event first so history survives off-chain.
+/// The InvestorFundraiser record is closed as well once its last investment is gone.
+pub fn handler(ctx: Context<CloseInvestment>, emit_receipt: bool) -> Result<()> {
+    debug_msg!("CloseInvestment handler start");
+    debug_msg!("Investor: {}", ctx.accounts.investor_signer.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+
+    let investment = &ctx.accounts.investment;
+    investment.require_terminal()?;
+
+    if emit_receipt {
+        emit!(InvestmentReceipt {
+            investment: investment.key(),
+            investor: investment.investor,
+            fundraiser: investment.fundraiser,
+            status: investment.status,
+            deposit_mint: investment.deposit_mint,
+            usdc_amount: investment.usdc_amount,
+            reit_amount: investment.reit_amount,
+            wired_amount: investment.wired_amount,
+            currency: investment.currency,
+            fx_rate: investment.fx_rate,
+            share_price: investment.share_price,
+            wire_reference_hash: investment.wire_reference_hash,
+            status_history: investment.status_history.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    let investor_fundraiser = &mut ctx.accounts.investor_fundraiser;
+    investor_fundraiser.investment_account_count = investor_fundraiser
+        .investment_account_count
+        .checked_sub(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
+    // With no investment accounts left, counter-derived PDAs can be reused safely
+    if investor_fundraiser.investment_account_count == 0 {
+        debug_msg!("Closing InvestorFundraiser {}", investor_fundraiser.key());
+        investor_fundraiser.close(ctx.accounts.investor_signer.to_account_info())?;
+    }
+
+    debug_msg!("CloseInvestment handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseInvestment<'info> {
+    #[account(mut)]
+    pub investor_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = investor_signer,
+        constraint = investment.investor == investor_signer.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub investment: Account<'info, state::Investment>,
+
+    #[account(
+        mut,
+        seeds = [b"investor_fundraiser", investor_signer.key().as_ref(), investment.fundraiser.as_ref()],
+        bump = investor_fundraiser.bump,
+    )]
+    pub investor_fundraiser: Account<'info, state::InvestorFundraiser>,
+}
+
+#[event]
+pub struct InvestmentReceipt {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub status: state::InvestmentStatus,
+    pub deposit_mint: Pubkey,
+    pub usdc_amount: u64,
+    pub reit_amount: u32,
+    pub wired_amount: u64,
+    pub currency: [u8; 3],
+    pub fx_rate: u64,
+    pub share_price: u64,
+    pub wire_reference_hash: [u8; 32],
+    pub status_history: Vec<state::StatusChange>,
+    pub timestamp: i64,
+}
@@ -2,13 +2,20 @@ use anchor_lang::prelude::*;
 
 use crate::state;
 
-pub fn handler(_ctx: Context<CloseInvestor>) -> Result<()> {
-    msg!("CloseInvestor handler start");
+pub fn handler(ctx: Context<CloseInvestor>) -> Result<()> {
+    debug_msg!("CloseInvestor handler start");
+
+    // issue_share and refund need the Investor PDA, so it must outlive every open position
+    let open = ctx.accounts.investor.open_investment_count;
+    if open != 0 {
+        debug_msg!("ERROR: Investor has {} open investments", open);
+        return Err(error!(crate::errors::CustomError::InvestorHasOpenPositions));
+    }
 
     // The close constraint on the investor account will handle the closure
     // and transfer remaining lamports to the signer
 
-    msg!("CloseInvestor handler complete");
+    debug_msg!("CloseInvestor handler complete");
 
     Ok(())
 }
@@ -22,7 +29,7 @@ pub struct CloseInvestor<'info> {
         mut,
         close = signer,
         seeds = [b"investor", signer.key().as_ref()],
-        bump
+        bump = investor.bump
     )]
     pub investor: Account<'info, state::Investor>,
 }
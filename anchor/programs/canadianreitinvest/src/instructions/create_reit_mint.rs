@@ -14,22 +14,24 @@ impl anchor_lang::Id for MetaplexTokenMetadata {
 
 use crate::state;
 
-pub fn handler(ctx: Context<CreateReitMint>, reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
-    msg!("Create mint handler start");
-    msg!("Admin: {}", ctx.accounts.admin.key());
-    msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
-    msg!("Name: {}, Symbol: {}", name, symbol);
-    msg!("Metadata URI: {}", metadata_uri);
+pub fn handler(ctx: Context<CreateReitMint>, _reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
+    debug_msg!("Create mint handler start");
+    ctx.accounts.fundraiser.require_reit_mint_creatable()?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Name: {}, Symbol: {}", name, symbol);
+    debug_msg!("Metadata URI: {}", metadata_uri);
 
     // Verify admin is the signer and matches fundraiser admin
     if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
-        msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
+        debug_msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
         return Err(error!(crate::errors::CustomError::InvalidAuthority));
     }
 
     // Update fundraiser with mint address
     let fundraiser = &mut ctx.accounts.fundraiser;
     fundraiser.reit_mint = ctx.accounts.reit_mint.key();
+    fundraiser.reit_token_program = ctx.accounts.token_program.key();
 
     // Create metadata using Metaplex Token Metadata program
     CreateV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
@@ -40,18 +42,28 @@ pub fn handler(ctx: Context<CreateReitMint>, reit_id_hash: [u8; 16], name: Strin
         .payer(&ctx.accounts.admin.to_account_info())
         .system_program(&ctx.accounts.system_program.to_account_info())
         .sysvar_instructions(&ctx.accounts.instructions_sysvar.to_account_info())
-        .name(name)
-        .symbol(symbol)
-        .uri(metadata_uri)
+        .name(name.clone())
+        .symbol(symbol.clone())
+        .uri(metadata_uri.clone())
         .token_standard(TokenStandard::FungibleAsset)
         .decimals(0)
         .print_supply(mpl_token_metadata::types::PrintSupply::Zero)
         .seller_fee_basis_points(0)
         .invoke()?;
 
-    msg!("Token metadata created successfully");
-    msg!("REIT mint created successfully: {}", ctx.accounts.reit_mint.key());
-    msg!("Create mint handler complete");
+    debug_msg!("Token metadata created successfully");
+    debug_msg!("REIT mint created successfully: {}", ctx.accounts.reit_mint.key());
+
+    emit!(ReitMintCreated {
+        fundraiser: ctx.accounts.fundraiser.key(),
+        reit_mint: ctx.accounts.reit_mint.key(),
+        token_program: ctx.accounts.token_program.key(),
+        name,
+        symbol,
+        uri: metadata_uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    debug_msg!("Create mint handler complete");
 
     Ok(())
 }
@@ -74,6 +86,7 @@ pub struct CreateReitMint<'info> {
         payer = admin,
         mint::decimals = 0,
         mint::authority = admin,
+        mint::freeze_authority = fundraiser,
     )]
     pub reit_mint: Account<'info, Mint>,
 
@@ -87,4 +100,15 @@ pub struct CreateReitMint<'info> {
     pub metadata: UncheckedAccount<'info>,
 
     pub token_metadata_program: Program<'info, MetaplexTokenMetadata>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ReitMintCreated {
+    pub fundraiser: Pubkey,
+    pub reit_mint: Pubkey,
+    pub token_program: Pubkey, // SPL Token or Token-2022
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_interface::{self, Mint, Token2022, TokenMetadataInitialize};
+
+/// Compliance transfer hook program enforcing KYC and lockups on Token-2022 REIT mints
+#[derive(Clone)]
+pub struct ReitTransferHook;
+
+impl anchor_lang::Id for ReitTransferHook {
+    fn id() -> Pubkey {
+        pubkey!("98ABkLqEXy5MPkVEZv7Wz4TtSTr9be5sCrM1LLKxjj66")
+    }
+}
+
+/// Anchor discriminator of the hook's `initialize_extra_account_meta_list`, the first 8 bytes of
+/// sha256("global:initialize_extra_account_meta_list"). The hook crate depends on this one, so
+/// the CPI is built by hand.
+const INITIALIZE_EXTRA_ACCOUNT_META_LIST: [u8; 8] = [92, 197, 174, 197, 41, 124, 19, 3];
+
+use crate::instructions::create_reit_mint::ReitMintCreated;
+use crate::state;
+
+pub fn handler(ctx: Context<CreateReitMint2022>, _reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
+    debug_msg!("Create Token-2022 mint handler start");
+    ctx.accounts.fundraiser.require_reit_mint_creatable()?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Name: {}, Symbol: {}", name, symbol);
+    debug_msg!("Metadata URI: {}", metadata_uri);
+
+    // Verify admin is the signer and matches fundraiser admin
+    if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
+        debug_msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
+        return Err(error!(crate::errors::CustomError::InvalidAuthority));
+    }
+
+    // Update fundraiser with mint address and owning token program
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    fundraiser.reit_mint = ctx.accounts.reit_mint.key();
+    fundraiser.reit_token_program = ctx.accounts.token_program.key();
+
+    // The metadata extension reallocs the mint, so fund the extra rent up front
+    let token_metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(ctx.accounts.admin.key()))?,
+        mint: ctx.accounts.reit_mint.key(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: metadata_uri.clone(),
+        additional_metadata: vec![],
+    };
+    let mint_info = ctx.accounts.reit_mint.to_account_info();
+    let new_len = mint_info.data_len() + token_metadata.tlv_size_of()?;
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    // Store metadata on the mint itself via the Token-2022 metadata extension
+    token_interface::token_metadata_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: mint_info.clone(),
+                update_authority: ctx.accounts.admin.to_account_info(),
+                mint_authority: ctx.accounts.admin.to_account_info(),
+                mint: mint_info,
+            },
+        ),
+        name.clone(),
+        symbol.clone(),
+        metadata_uri.clone(),
+    )?;
+
+    debug_msg!("Token metadata created successfully");
+
+    // Token-2022 fails every transfer until the hook's extra account list exists, so create it
+    // while the admin is still the mint authority the hook program checks
+    invoke(
+        &Instruction {
+            program_id: ctx.accounts.transfer_hook_program.key(),
+            accounts: vec![
+                AccountMeta::new(ctx.accounts.admin.key(), true),
+                AccountMeta::new(ctx.accounts.extra_account_meta_list.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.reit_mint.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+            ],
+            data: INITIALIZE_EXTRA_ACCOUNT_META_LIST.to_vec(),
+        },
+        &[
+            ctx.accounts.admin.to_account_info(),
+            ctx.accounts.extra_account_meta_list.to_account_info(),
+            ctx.accounts.reit_mint.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.transfer_hook_program.to_account_info(),
+        ],
+    )?;
+    debug_msg!("Transfer hook account list created: {}", ctx.accounts.extra_account_meta_list.key());
+    debug_msg!("REIT Token-2022 mint created successfully: {}", ctx.accounts.reit_mint.key());
+
+    emit!(ReitMintCreated {
+        fundraiser: ctx.accounts.fundraiser.key(),
+        reit_mint: ctx.accounts.reit_mint.key(),
+        token_program: ctx.accounts.token_program.key(),
+        name,
+        symbol,
+        uri: metadata_uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    debug_msg!("Create Token-2022 mint handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String)]
+pub struct CreateReitMint2022<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(
+        init,
+        payer = admin,
+        mint::decimals = 0,
+        mint::authority = admin,
+        mint::freeze_authority = fundraiser,
+        mint::token_program = token_program,
+        extensions::transfer_hook::authority = fundraiser,
+        extensions::transfer_hook::program_id = transfer_hook_program.key(),
+        extensions::metadata_pointer::authority = admin,
+        extensions::metadata_pointer::metadata_address = reit_mint,
+        extensions::permanent_delegate::delegate = fundraiser,
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    pub transfer_hook_program: Program<'info, ReitTransferHook>,
+
+    /// CHECK: The hook's ExtraAccountMetaList for reit_mint, created by the handler's CPI
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", reit_mint.key().as_ref()],
+        bump,
+        seeds::program = transfer_hook_program.key(),
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
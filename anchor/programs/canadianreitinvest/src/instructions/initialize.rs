@@ -1,54 +1,98 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::state;
+use crate::state::{self, Versioned};
 
-pub fn handler(ctx: Context<InitializeFundraiser>, _reit_id: String, _reit_id_hash: [u8; 16]) -> Result<()> {
+pub fn handler(ctx: Context<InitializeFundraiser>, reit_id: String, reit_id_hash: [u8; 16], reit_accepted_currency: [u8; 3]) -> Result<()> {
     // Log entry so we can see the instruction hit in transaction logs
-    msg!("InitializeFundraiser handler start");
-    msg!("reit_id: {}", _reit_id);
-    msg!("reit_id_hash: {:?}", _reit_id_hash);
-
-    // Note: No validation needed here. The PDA is derived from reit_id_hash,
-    // so if the hash doesn't match what was used to compute the PDA,
-    // the account won't be found or will be incorrect, causing the transaction to fail.
-
-    // Inspect the raw usdc_mint account info (we intentionally use UncheckedAccount
-    // in the accounts struct so Anchor does not attempt to deserialize a Mint
-    // before we can log and provide a helpful error message).
-    let usdc_ai = &ctx.accounts.usdc_mint.to_account_info();
-    msg!("usdc_mint key: {}", usdc_ai.key);
-    msg!("usdc_mint owner: {}", usdc_ai.owner);
-    // lamports is a RefCell; borrow to log
-    msg!("usdc_mint lamports: {}", usdc_ai.lamports.borrow());
-    match usdc_ai.try_borrow_data() {
-        Ok(data) => {
-            msg!("usdc_mint data len: {}", data.len());
-            // Log first 16 bytes in hex to help identify account type in logs
-            let sample_len = core::cmp::min(16usize, data.len());
-            let mut hex_buf = String::new();
-            for b in &data[0..sample_len] {
-                use core::fmt::Write;
-                write!(hex_buf, "{:02x}", b).ok();
+    debug_msg!("InitializeFundraiser handler start");
+    debug_msg!("reit_id: {}", reit_id);
+    debug_msg!("reit_id_hash: {:?}", reit_id_hash);
+    debug_msg!("reit_accepted_currency: {:?}", reit_accepted_currency);
+
+    state::require_accepted_currency(&reit_accepted_currency)?;
+
+    // The PDA is derived from reit_id_hash, so it must be the hash of reit_id;
+    // otherwise the same REIT could be registered under arbitrary PDAs.
+    if state::reit_id_hash(&reit_id)? != reit_id_hash {
+        debug_msg!("ERROR: reit_id_hash does not match reit_id {}", reit_id);
+        return Err(error!(crate::errors::CustomError::InvalidReitIdHash));
+    }
+
+    // Hex-dump the usdc_mint account only in debug builds; it is the most expensive log here
+    if cfg!(feature = "debug-logs") {
+        // Inspect the raw usdc_mint account info (we intentionally use UncheckedAccount
+        // in the accounts struct so Anchor does not attempt to deserialize a Mint
+        // before we can log and provide a helpful error message).
+        let usdc_ai = &ctx.accounts.usdc_mint.to_account_info();
+        debug_msg!("usdc_mint key: {}", usdc_ai.key);
+        debug_msg!("usdc_mint owner: {}", usdc_ai.owner);
+        // lamports is a RefCell; borrow to log
+        debug_msg!("usdc_mint lamports: {}", usdc_ai.lamports.borrow());
+        match usdc_ai.try_borrow_data() {
+            Ok(data) => {
+                debug_msg!("usdc_mint data len: {}", data.len());
+                // Log first 16 bytes in hex to help identify account type in logs
+                let sample_len = core::cmp::min(16usize, data.len());
+                let mut hex_buf = String::new();
+                for b in &data[0..sample_len] {
+                    use core::fmt::Write;
+                    write!(hex_buf, "{:02x}", b).ok();
+                }
+                debug_msg!("usdc_mint data sample (hex): {}", hex_buf);
+            }
+            Err(_) => {
+                debug_msg!("unable to borrow usdc_mint data");
             }
-            msg!("usdc_mint data sample (hex): {}", hex_buf);
-        }
-        Err(_) => {
-            msg!("unable to borrow usdc_mint data");
-        }
+        };
     }
 
     let fundraiser = &mut ctx.accounts.fundraiser;
     fundraiser.admin = ctx.accounts.admin.key();
     fundraiser.usdc_mint = ctx.accounts.usdc_mint.key();
     fundraiser.reit_mint = Pubkey::default();
+    fundraiser.reit_token_program = ctx.accounts.token_program.key();
     fundraiser.escrow_vault = ctx.accounts.escrow_vault.key();
     fundraiser.total_raised = 0;
     fundraiser.released_amount = 0;
+    fundraiser.version = state::Fundraiser::VERSION;
     fundraiser.bump = ctx.bumps.fundraiser;
-    fundraiser.reit_accepted_currency = *b"CAD";
+    fundraiser.reit_accepted_currency = reit_accepted_currency;
+    // Uncapped until the admin configures an exemption via set_offering_exemption
+    fundraiser.exemption = state::OfferingExemption::Prospectus;
+    fundraiser.investment_limits = state::InvestmentLimits { non_eligible: 0, eligible: 0 };
+    // An empty denylist admits every jurisdiction until the admin restricts it
+    fundraiser.jurisdiction_mode = state::JurisdictionMode::Denylist;
+    fundraiser.jurisdiction_rules = Vec::new();
+    fundraiser.hold_period_seconds = state::DEFAULT_HOLD_PERIOD_SECONDS;
+    // usdc_mint is the first accepted stablecoin; more are added via add_accepted_mint
+    fundraiser.accepted_mints = vec![state::AcceptedMint {
+        mint: ctx.accounts.usdc_mint.key(),
+        escrow_vault: ctx.accounts.escrow_vault.key(),
+        currency: *b"USD",
+    }];
+    fundraiser.reit_id = reit_id.clone();
+    // Drafts accept no money until the admin opens them via set_fundraiser_status
+    fundraiser.status = state::FundraiserStatus::Draft;
+    fundraiser.min_raise = 0;
+    fundraiser.max_raise = 0;
+    fundraiser.close_at = 0;
+    fundraiser.investment_count = 0;
+    fundraiser.open_investment_count = 0;
 
-    msg!("InitializeFundraiser handler complete");
+    emit!(FundraiserInitialized {
+        fundraiser: fundraiser.key(),
+        admin: fundraiser.admin,
+        reit_id,
+        reit_id_hash,
+        usdc_mint: fundraiser.usdc_mint,
+        escrow_vault: fundraiser.escrow_vault,
+        currency: reit_accepted_currency,
+        new_status: fundraiser.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    debug_msg!("InitializeFundraiser handler complete");
 
     Ok(())
 }
@@ -85,4 +129,17 @@ pub struct InitializeFundraiser<'info> {
     pub system_program: Program<'info, System>,
 
     pub rent: Sysvar<'info, Rent>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct FundraiserInitialized {
+    pub fundraiser: Pubkey,
+    pub admin: Pubkey,
+    pub reit_id: String,
+    pub reit_id_hash: [u8; 16],
+    pub usdc_mint: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub currency: [u8; 3],
+    pub new_status: state::FundraiserStatus,
+    pub timestamp: i64,
+}
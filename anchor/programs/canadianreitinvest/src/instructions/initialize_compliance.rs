@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::program::Canadianreitinvest;
+use crate::state::{self, Versioned};
+
+pub fn handler(ctx: Context<InitializeCompliance>, authority: Pubkey) -> Result<()> {
+    debug_msg!("InitializeCompliance handler start");
+    debug_msg!("Compliance authority: {}", authority);
+
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    compliance_config.authority = authority;
+    compliance_config.version = state::ComplianceConfig::VERSION;
+    compliance_config.bump = ctx.bumps.compliance_config;
+
+    debug_msg!("InitializeCompliance handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeCompliance<'info> {
+    /// Must be the program upgrade authority so the compliance role can't be claimed by anyone else
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + state::ComplianceConfig::INIT_SPACE,
+        seeds = [b"compliance_config"],
+        bump
+    )]
+    pub compliance_config: Account<'info, state::ComplianceConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Canadianreitinvest>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
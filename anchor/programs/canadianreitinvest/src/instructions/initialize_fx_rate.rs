@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::program::Canadianreitinvest;
+use crate::state::{self, Versioned};
+
+pub fn handler(
+    ctx: Context<InitializeFxRate>,
+    base_currency: [u8; 3],
+    quote_currency: [u8; 3],
+    publisher: Pubkey,
+    max_staleness_seconds: i64,
+) -> Result<()> {
+    debug_msg!("InitializeFxRate handler start");
+    debug_msg!("Pair: {:?}/{:?}, Publisher: {}", base_currency, quote_currency, publisher);
+
+    state::require_accepted_currency(&base_currency)?;
+    state::require_accepted_currency(&quote_currency)?;
+
+    if base_currency == quote_currency || max_staleness_seconds <= 0 {
+        return Err(error!(crate::errors::CustomError::InvalidFxRate));
+    }
+
+    let fx_rate = &mut ctx.accounts.fx_rate;
+    fx_rate.publisher = publisher;
+    fx_rate.base_currency = base_currency;
+    fx_rate.quote_currency = quote_currency;
+    fx_rate.rate = 0;
+    fx_rate.confidence = 0;
+    fx_rate.published_at = 0;
+    fx_rate.max_staleness_seconds = max_staleness_seconds;
+    fx_rate.version = state::FxRate::VERSION;
+    fx_rate.bump = ctx.bumps.fx_rate;
+
+    debug_msg!("InitializeFxRate handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(base_currency: [u8; 3], quote_currency: [u8; 3])]
+pub struct InitializeFxRate<'info> {
+    /// Must be the program upgrade authority, which decides who may publish rates
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + state::FxRate::INIT_SPACE,
+        seeds = [b"fx_rate", base_currency.as_ref(), quote_currency.as_ref()],
+        bump
+    )]
+    pub fx_rate: Account<'info, state::FxRate>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Canadianreitinvest>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
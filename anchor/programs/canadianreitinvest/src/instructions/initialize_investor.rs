@@ -1,15 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::state;
+use crate::state::{self, Versioned};
 
 pub fn handler(ctx: Context<InitializeInvestor>) -> Result<()> {
-    msg!("InitializeInvestor handler start");
+    debug_msg!("InitializeInvestor handler start");
 
     let investor = &mut ctx.accounts.investor;
     investor.investor_pubkey = ctx.accounts.signer.key();
+    investor.version = state::Investor::VERSION;
     investor.bump = ctx.bumps.investor;
+    investor.rolling_invested = state::RollingTotal::default();
+    investor.open_investment_count = 0;
 
-    msg!("InitializeInvestor handler complete");
+    debug_msg!("InitializeInvestor handler complete");
 
     Ok(())
 }
@@ -2,35 +2,57 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer, Mint};
 use anchor_spl::associated_token::AssociatedToken;
 
-use crate::state;
+use crate::state::{self, Versioned};
 
 pub fn handler(ctx: Context<Invest>, amount: u64, _reit_id_hash: [u8; 16], counter: u64) -> Result<()> {
-    msg!("Invest handler start");
+    debug_msg!("Invest handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Open])?;
 
     if amount == 0 {
         return Err(error!(crate::errors::CustomError::InvalidAmount));
     }
 
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+
+    // The raise stops accepting money at its deadline and cap
+    let fundraiser = &ctx.accounts.fundraiser;
+    if fundraiser.close_at != 0 && now >= fundraiser.close_at {
+        debug_msg!("ERROR: Fundraiser closed for investment at {}", fundraiser.close_at);
+        return Err(error!(crate::errors::CustomError::FundraiserDeadlinePassed));
+    }
+    if fundraiser.max_raise != 0 {
+        let new_raised = fundraiser
+            .total_raised
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        if new_raised > fundraiser.max_raise {
+            debug_msg!("ERROR: Investment of {} exceeds remaining cap {}", amount, fundraiser.max_raise - fundraiser.total_raised);
+            return Err(error!(crate::errors::CustomError::FundraiserCapExceeded));
+        }
+    }
+
+    // Only investors with a valid, unexpired KYC attestation may invest
+    ctx.accounts.kyc_attestation.require_valid(now)?;
+
+    // The investor's attested jurisdiction must be permitted by the fundraiser's rules
+    ctx.accounts.fundraiser.require_jurisdiction_allowed(&ctx.accounts.kyc_attestation.jurisdiction)?;
+
     let fundraiser = &ctx.accounts.fundraiser;
 
     // Debug logs for PDA addresses and seeds
-    msg!("Fundraiser PDA: {} (seeds: [b\"fundraiser\", reit_id_hash: {:?}])", fundraiser.key(), _reit_id_hash);
-    msg!("Investor PDA: {} (seeds: [b\"investor\", investor_signer: {}])", ctx.accounts.investor.key(), ctx.accounts.investor_signer.key());
-    msg!("InvestorFundraiser PDA: {} (seeds: [b\"investor_fundraiser\", investor_signer: {}, fundraiser: {}])", 
+    debug_msg!("Fundraiser PDA: {} (seeds: [b\"fundraiser\", reit_id_hash: {:?}])", fundraiser.key(), _reit_id_hash);
+    debug_msg!("Investor PDA: {} (seeds: [b\"investor\", investor_signer: {}])", ctx.accounts.investor.key(), ctx.accounts.investor_signer.key());
+    debug_msg!("InvestorFundraiser PDA: {} (seeds: [b\"investor_fundraiser\", investor_signer: {}, fundraiser: {}])", 
           ctx.accounts.investor_fundraiser.key(),
           ctx.accounts.investor_signer.key(),
           fundraiser.key());
-    msg!("Investment PDA: {} (seeds: [b\"investment\", investor_signer: {}, fundraiser: {}, counter: {}])",
+    debug_msg!("Investment PDA: {} (seeds: [b\"investment\", investor_signer: {}, fundraiser: {}, counter: {}])",
           ctx.accounts.investment.key(),
           ctx.accounts.investor_signer.key(),
           fundraiser.key(),
           counter);
 
-    // Verify escrow vault matches fundraiser. This will be enforced by constraints as well.
-    if ctx.accounts.escrow_vault.key() != fundraiser.escrow_vault {
-        return Err(error!(crate::errors::CustomError::InvalidAuthority));
-    }
-
     // Transfer USDC from investor's ATA to escrow (investor_signer must sign)
     let cpi_accounts = Transfer {
         from: ctx.accounts.investor_usdc_ata.to_account_info(),
@@ -41,12 +63,7 @@ pub fn handler(ctx: Context<Invest>, amount: u64, _reit_id_hash: [u8; 16], count
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     token::transfer(cpi_ctx, amount)?;
 
-    // Initialize investor account if newly created
     let investor = &mut ctx.accounts.investor;
-    if investor.investor_pubkey == Pubkey::default() {
-        investor.investor_pubkey = ctx.accounts.investor_signer.key();
-        investor.bump = ctx.bumps.investor;
-    }
 
     // Initialize InvestorFundraiser account if newly created
     let investor_fundraiser = &mut ctx.accounts.investor_fundraiser;
@@ -54,14 +71,44 @@ pub fn handler(ctx: Context<Invest>, amount: u64, _reit_id_hash: [u8; 16], count
         investor_fundraiser.investor = ctx.accounts.investor_signer.key();
         investor_fundraiser.fundraiser = fundraiser.key();
         investor_fundraiser.investment_counter = 0;
+        investor_fundraiser.investment_account_count = 0;
+        investor_fundraiser.rolling_invested = state::RollingTotal::default();
+        investor_fundraiser.version = state::InvestorFundraiser::VERSION;
         investor_fundraiser.bump = ctx.bumps.investor_fundraiser;
     }
 
+    // Enforce the exemption's rolling 12-month limit for the investor's attested category.
+    // The limit applies to the investor's total across all fundraisers under the same
+    // exemption, so only those are recorded on the Investor.
+    let accreditation = ctx.accounts.kyc_attestation.accreditation;
+    if let Some(limit) = fundraiser.investment_limit(accreditation)? {
+        let invested = investor.rolling_invested.total(now)?;
+        let new_total = invested
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        if new_total > limit {
+            debug_msg!("ERROR: Investment of {} exceeds limit {} for {:?} investor (already invested: {})", amount, limit, accreditation, invested);
+            return Err(error!(crate::errors::CustomError::InvestmentLimitExceeded));
+        }
+    }
+    if fundraiser.exemption == state::OfferingExemption::OfferingMemorandum {
+        investor.rolling_invested.record(now, amount)?;
+    }
+    investor_fundraiser.rolling_invested.record(now, amount)?;
+    investor.open_investment_count = investor
+        .open_investment_count
+        .checked_add(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
     // Update per-fundraiser counter
     investor_fundraiser.investment_counter = investor_fundraiser
         .investment_counter
         .checked_add(1)
         .ok_or(error!(crate::errors::CustomError::InvestmentCounterOverflow))?;
+    investor_fundraiser.investment_account_count = investor_fundraiser
+        .investment_account_count
+        .checked_add(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
 
     // Initialize investment account
     let investment = &mut ctx.accounts.investment;
@@ -69,9 +116,17 @@ pub fn handler(ctx: Context<Invest>, amount: u64, _reit_id_hash: [u8; 16], count
     investment.fundraiser = fundraiser.key();
     investment.usdc_amount = amount;
     investment.reit_amount = 0;
-    // set status to Pending
-    investment.status = state::InvestmentStatus::Pending;
+    investment.start(&clock, ctx.accounts.investor_signer.key());
+    investment.version = state::Investment::VERSION;
     investment.bump = ctx.bumps.investment;
+    investment.lockup_until = 0;
+    investment.wired_amount = 0;
+    investment.fx_rate = 0;
+    investment.wire_date = 0;
+    investment.wire_reference_hash = [0u8; 32];
+    investment.currency = fundraiser.reit_accepted_currency;
+    investment.share_price = 0;
+    investment.deposit_mint = ctx.accounts.usdc_mint.key();
 
     // Update fundraiser total raised
     let fundraiser = &mut ctx.accounts.fundraiser;
@@ -79,8 +134,28 @@ pub fn handler(ctx: Context<Invest>, amount: u64, _reit_id_hash: [u8; 16], count
         .total_raised
         .checked_add(amount)
         .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+    fundraiser.investment_count = fundraiser
+        .investment_count
+        .checked_add(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+    fundraiser.open_investment_count = fundraiser
+        .open_investment_count
+        .checked_add(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
 
-    msg!("Invest handler complete");
+    emit!(InvestmentCreated {
+        investment: ctx.accounts.investment.key(),
+        investor: ctx.accounts.investor_signer.key(),
+        fundraiser: fundraiser.key(),
+        new_status: state::InvestmentStatus::Pending,
+        deposit_mint: ctx.accounts.usdc_mint.key(),
+        amount,
+        counter,
+        total_raised: fundraiser.total_raised,
+        timestamp: now,
+    });
+
+    debug_msg!("Invest handler complete");
 
     Ok(())
 }
@@ -91,16 +166,21 @@ pub struct Invest<'info> {
     #[account(mut)]
     pub investor_signer: Signer<'info>,
 
-    /// Investor PDA: init if needed so users don't have to pre-create it
+    /// Investor PDA, created beforehand with initialize_investor
     #[account(
-        init_if_needed,
-        payer = investor_signer,
-        space = 8 + state::Investor::INIT_SPACE,
+        mut,
         seeds = [b"investor", investor_signer.key().as_ref()],
-        bump
+        bump = investor.bump,
     )]
     pub investor: Account<'info, state::Investor>,
 
+    /// KYC attestation written by the compliance authority for this investor
+    #[account(
+        seeds = [b"kyc_attestation", investor.key().as_ref()],
+        bump = kyc_attestation.bump,
+    )]
+    pub kyc_attestation: Account<'info, state::KycAttestation>,
+
     /// NEW: InvestorFundraiser PDA for per-fundraiser tracking
     #[account(
         init_if_needed,
@@ -128,9 +208,8 @@ pub struct Invest<'info> {
     )]
     pub investment: Account<'info, state::Investment>,
 
-    /// Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA.
-    // USDC mint (must match fundraiser.usdc_mint)
-    #[account(constraint = usdc_mint.key() == fundraiser.usdc_mint)]
+    /// Stablecoin being deposited (USDC or any other mint in fundraiser.accepted_mints)
+    #[account(constraint = fundraiser.escrow_vault_for(&usdc_mint.key()).is_some() @ crate::errors::CustomError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
 
     /// Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA.
@@ -142,7 +221,10 @@ pub struct Invest<'info> {
     )]
     pub investor_usdc_ata: Account<'info, TokenAccount>,
 
-    #[account(mut, constraint = escrow_vault.key() == fundraiser.escrow_vault)]
+    #[account(
+        mut,
+        constraint = fundraiser.escrow_vault_for(&usdc_mint.key()) == Some(escrow_vault.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
     pub escrow_vault: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
@@ -150,3 +232,16 @@ pub struct Invest<'info> {
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
+
+#[event]
+pub struct InvestmentCreated {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub new_status: state::InvestmentStatus,
+    pub deposit_mint: Pubkey,
+    pub amount: u64,
+    pub counter: u64,
+    pub total_raised: u64, // Fundraiser total raised after this investment
+    pub timestamp: i64,
+}
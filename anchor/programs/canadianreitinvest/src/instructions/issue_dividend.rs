@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, TransferChecked};
 
-use crate::state::{Investment, InvestmentStatus, Fundraiser};
+use crate::state::{Investment, InvestmentStatus, Fundraiser, FundraiserStatus, KycAttestation};
 use crate::errors::CustomError;
 
 /// Simple V1 dividend distribution instruction
@@ -18,7 +18,6 @@ pub struct IssueDividend<'info> {
     /// Investment PDA to validate investor eligibility
     /// Must have ShareIssued status
     #[account(
-        constraint = investment.status == InvestmentStatus::ShareIssued @ CustomError::InvalidInvestmentStatus,
         constraint = fundraiser.key() == investment.fundraiser @ CustomError::InvalidFundraiserMismatch
     )]
     pub investment: Account<'info, Investment>,
@@ -27,6 +26,21 @@ pub struct IssueDividend<'info> {
     /// CHECK: Validated via investment.investor field
     pub investor: UncheckedAccount<'info>,
 
+    /// Investor PDA address for the wallet, which keys its KYC attestation
+    /// CHECK: only used as a seed, address enforced by the seeds constraint
+    #[account(
+        seeds = [b"investor", investor.key().as_ref()],
+        bump
+    )]
+    pub investor_profile: UncheckedAccount<'info>,
+
+    /// KYC attestation for the investor; dividends are only paid to verified investors
+    #[account(
+        seeds = [b"kyc_attestation", investor_profile.key().as_ref()],
+        bump = kyc_attestation.bump,
+    )]
+    pub kyc_attestation: Account<'info, KycAttestation>,
+
     /// Fundraiser PDA for admin authorization check
     #[account(
         constraint = admin.key() == fundraiser.admin @ CustomError::InvalidAuthority
@@ -60,18 +74,24 @@ pub struct IssueDividend<'info> {
 }
 
 pub fn handler(ctx: Context<IssueDividend>, amount: u64) -> Result<()> {
-    // Validate investment has ShareIssued status (redundant with constraint but explicit)
-    require!(
-        ctx.accounts.investment.status == InvestmentStatus::ShareIssued,
-        CustomError::InvalidInvestmentStatus
-    );
+    // Dividends are paid while the REIT operates and through its wind-down
+    ctx.accounts
+        .fundraiser
+        .require_status(&[FundraiserStatus::Operating, FundraiserStatus::WindingDown])?;
+
+    // Dividends are paid on issued shares only
+    ctx.accounts.investment.require_status(InvestmentStatus::ShareIssued)?;
 
     // Validate investment investor matches the investor account
-    require!(
-        ctx.accounts.investment.investor == ctx.accounts.investor.key(),
-        CustomError::InvalidInvestmentStatus
+    require_keys_eq!(
+        ctx.accounts.investment.investor,
+        ctx.accounts.investor.key(),
+        CustomError::InvalidAuthority
     );
 
+    // Validate investor still holds a valid, unexpired KYC attestation
+    ctx.accounts.kyc_attestation.require_valid(Clock::get()?.unix_timestamp)?;
+
     // Transfer USDC from admin to investor using token::transfer_checked
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.admin_usdc_ata.to_account_info(),
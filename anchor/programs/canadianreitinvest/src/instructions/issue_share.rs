@@ -1,12 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, MintTo};
 use anchor_spl::associated_token::AssociatedToken;
 
-use crate::state;
+use crate::state::{self, Versioned};
 
 pub fn handler(ctx: Context<IssueShare>, investor_pubkey: Pubkey, _reit_id_hash: [u8; 16], share_price: u64) -> Result<()> {
-    msg!("Issue share handler start");
+    debug_msg!("Issue share handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Funded, state::FundraiserStatus::Operating])?;
     
+    // Shares go to the investment's own investor, whose KYC is the one checked below
+    require_keys_eq!(
+        ctx.accounts.investment.investor,
+        investor_pubkey,
+        crate::errors::CustomError::InvalidAuthority
+    );
+
     // Validate that the investor_wallet account matches the investor_pubkey parameter
     require_keys_eq!(
         ctx.accounts.investor_wallet.key(),
@@ -14,37 +22,88 @@ pub fn handler(ctx: Context<IssueShare>, investor_pubkey: Pubkey, _reit_id_hash:
         crate::errors::CustomError::InvalidAuthority
     );
     
-    // Validate reit_mint is owned by Token Program
-    let token_program_id = anchor_spl::token::ID;
+    // Validate reit_mint is owned by the token program recorded on the fundraiser
+    let token_program_id = ctx.accounts.fundraiser.reit_token_program;
     
-    if ctx.accounts.reit_mint.to_account_info().owner != &token_program_id {
-        msg!("ERROR: REIT mint not owned by Token Program!");
-        msg!("Expected: {}", token_program_id);
-        msg!("Got: {}", ctx.accounts.reit_mint.to_account_info().owner);
-        return Err(error!(crate::errors::CustomError::InvalidInvestmentStatus));
-    }
+    require_keys_eq!(
+        *ctx.accounts.reit_mint.to_account_info().owner,
+        token_program_id,
+        crate::errors::CustomError::InvalidMint
+    );
     
+    // Shares may only be issued to investors with a valid, unexpired KYC attestation
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.kyc_attestation.require_valid(now)?;
+
     let investment = &mut ctx.accounts.investment;
-    // Verify investment is in wired status
-    if investment.status != state::InvestmentStatus::Wired {
-        msg!("ERROR: Investment status is not wired. Current status: {:?}", investment.status);
-        return Err(error!(crate::errors::CustomError::InvalidInvestmentStatus));
+    investment.status.require_transition_to(state::InvestmentStatus::ShareIssued)?;
+
+    // The deposit is priced in the currency its stablecoin is pegged to
+    let deposit_currency = ctx
+        .accounts
+        .fundraiser
+        .accepted_mint(&investment.deposit_mint)
+        .ok_or(error!(crate::errors::CustomError::InvalidMint))?
+        .currency;
+
+    // share_price is always in cents of the fundraiser currency. It is converted to the deposit
+    // stablecoin's 6-decimal units at the published FX rate when one is passed, 1:1 when the
+    // stablecoin is pegged to the fundraiser currency, and otherwise at the rate the wire settled at.
+    let fundraiser_currency = ctx.accounts.fundraiser.reit_accepted_currency;
+    let rate = match ctx.accounts.fx_rate.as_ref() {
+        None if deposit_currency == fundraiser_currency => state::FX_RATE_SCALE,
+        Some(fx_rate) => {
+            fx_rate.require_usable(now)?;
+            if fx_rate.base_currency != deposit_currency || fx_rate.quote_currency != fundraiser_currency {
+                debug_msg!("ERROR: FX rate pair {:?}/{:?} does not price {:?} in the fundraiser currency", fx_rate.base_currency, fx_rate.quote_currency, deposit_currency);
+                return Err(error!(crate::errors::CustomError::InvalidFxRate));
+            }
+            fx_rate.rate
+        }
+        None => investment.fx_rate,
+    };
+    if rate == 0 {
+        return Err(error!(crate::errors::CustomError::InvalidFxRate));
     }
-
-    // Calculate REIT amount: usdc_amount (in lamports) / (share_price * 1_000_000)
-    // Since usdc_amount is in lamports (6 decimals) and share_price is in whole USDC
-    let share_price_lamports = share_price.checked_mul(1_000_000).ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
-    let reit_amount = (investment.usdc_amount / share_price_lamports) as u32;
-
-    msg!("Calculated REIT amount: {} (usdc: {}, price: {})", reit_amount, investment.usdc_amount, share_price);
-
-    // Mint tokens to investor's ATA
-    token::mint_to(
+    // cents -> 6-decimal units is x10_000, then divide by the fundraiser-currency-per-deposit-currency rate
+    let share_price_lamports = (share_price as u128) * 10_000 * (state::FX_RATE_SCALE as u128) / (rate as u128);
+    let share_price_lamports = u64::try_from(share_price_lamports).map_err(|_| error!(crate::errors::CustomError::ArithmeticOverflow))?;
+    debug_msg!("Share price {} {:?} cents at rate {} = {} {:?} lamports", share_price, fundraiser_currency, rate, share_price_lamports, deposit_currency);
+    if share_price_lamports == 0 {
+        return Err(error!(crate::errors::CustomError::InvalidAmount));
+    }
+    let reit_amount = u32::try_from(investment.usdc_amount / share_price_lamports)
+        .map_err(|_| error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
+    debug_msg!("Calculated REIT amount: {} (usdc: {}, price: {} lamports)", reit_amount, investment.usdc_amount, share_price_lamports);
+
+    let lockup_until = now
+        .checked_add(ctx.accounts.fundraiser.hold_period_seconds)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
+    // Classic SPL mints can't restrict transfers, so locked shares are minted into a
+    // per-investment lock vault and released by unlock_shares after the hold period.
+    // Token-2022 mints go straight to the investor; the transfer hook enforces the lockup.
+    let uses_lock_vault = ctx.accounts.fundraiser.reit_token_program == anchor_spl::token::ID
+        && ctx.accounts.fundraiser.hold_period_seconds > 0;
+    let destination = if uses_lock_vault {
+        let lock_vault = ctx
+            .accounts
+            .lock_vault
+            .as_ref()
+            .ok_or(error!(crate::errors::CustomError::LockVaultRequired))?;
+        lock_vault.to_account_info()
+    } else {
+        ctx.accounts.investor_ata.to_account_info()
+    };
+
+    // Mint tokens to investor's ATA, or to the lock vault while the hold period applies
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
                 mint: ctx.accounts.reit_mint.to_account_info(),
-                to: ctx.accounts.investor_ata.to_account_info(),
+                to: destination.clone(),
                 authority: ctx.accounts.admin.to_account_info(),
             },
             &[],
@@ -54,10 +113,39 @@ pub fn handler(ctx: Context<IssueShare>, investor_pubkey: Pubkey, _reit_id_hash:
 
     // Update investment
     investment.reit_amount = reit_amount;
-    investment.status = state::InvestmentStatus::ShareIssued;
-
-    msg!("Minted {} REIT tokens to {}", reit_amount, ctx.accounts.investor_ata.key());
-    msg!("Issue share handler complete");
+    let old_status = investment.transition(state::InvestmentStatus::ShareIssued, now, ctx.accounts.admin.key())?;
+    investment.lockup_until = lockup_until;
+    investment.share_price = share_price;
+    ctx.accounts.fundraiser.settle_investment()?;
+    ctx.accounts.investor.settle_investment()?;
+
+    // Extend the holder's resale lockup by the fundraiser's hold period
+    let holder_lockup = &mut ctx.accounts.holder_lockup;
+    holder_lockup.holder = investor_pubkey;
+    holder_lockup.mint = ctx.accounts.reit_mint.key();
+    holder_lockup.locked_until = holder_lockup.locked_until.max(lockup_until);
+    holder_lockup.version = state::HolderLockup::VERSION;
+    holder_lockup.bump = ctx.bumps.holder_lockup;
+    debug_msg!("Holder lockup until: {}", holder_lockup.locked_until);
+
+    debug_msg!("Minted {} REIT tokens to {} (locked until {})", reit_amount, destination.key(), lockup_until);
+
+    emit!(SharesIssued {
+        investment: investment.key(),
+        investor: investor_pubkey,
+        fundraiser: ctx.accounts.fundraiser.key(),
+        old_status,
+        new_status: investment.status,
+        reit_mint: ctx.accounts.reit_mint.key(),
+        destination: destination.key(),
+        usdc_amount: investment.usdc_amount,
+        reit_amount,
+        share_price,
+        currency: investment.currency,
+        lockup_until,
+        timestamp: now,
+    });
+    debug_msg!("Issue share handler complete");
 
     Ok(())
 }
@@ -79,17 +167,27 @@ pub struct IssueShare<'info> {
     pub investment: Account<'info, state::Investment>,
 
     #[account(
+        mut,
         seeds = [b"investor", investor_pubkey.as_ref()],
         bump = investor.bump,
     )]
     pub investor: Account<'info, state::Investor>,
 
+    #[account(
+        seeds = [b"kyc_attestation", investor.key().as_ref()],
+        bump = kyc_attestation.bump,
+    )]
+    pub kyc_attestation: Account<'info, state::KycAttestation>,
+
     /// Investor wallet - needed as the ATA authority (not a signer for this instruction)
     /// CHECK: investor_pubkey parameter validates this
     pub investor_wallet: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    pub reit_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = reit_mint.key() == fundraiser.reit_mint @ crate::errors::CustomError::InvalidMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
@@ -98,10 +196,52 @@ pub struct IssueShare<'info> {
         associated_token::authority = investor_wallet,
         associated_token::token_program = token_program,
     )]
-    pub investor_ata: Account<'info, TokenAccount>,
+    pub investor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Per-investment vault holding shares of classic SPL mints until the hold period ends
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [b"lock_vault", investment.key().as_ref()],
+        bump,
+        token::mint = reit_mint,
+        token::authority = fundraiser,
+        token::token_program = token_program,
+    )]
+    pub lock_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + state::HolderLockup::INIT_SPACE,
+        seeds = [b"holder_lockup", reit_mint.key().as_ref(), investor_pubkey.as_ref()],
+        bump
+    )]
+    pub holder_lockup: Account<'info, state::HolderLockup>,
+
+    /// Rate of the deposit mint's currency in the fundraiser currency, converting share_price at the published rate instead of the wire's
+    pub fx_rate: Option<Account<'info, state::FxRate>>,
+
+    #[account(constraint = token_program.key() == fundraiser.reit_token_program @ crate::errors::CustomError::InvalidMint)]
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct SharesIssued {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub old_status: state::InvestmentStatus,
+    pub new_status: state::InvestmentStatus,
+    pub reit_mint: Pubkey,
+    pub destination: Pubkey, // Investor ATA, or the lock vault while the hold period runs
+    pub usdc_amount: u64,
+    pub reit_amount: u32,
+    pub share_price: u64, // Cents of currency per share
+    pub currency: [u8; 3],
+    pub lockup_until: i64,
+    pub timestamp: i64,
+}
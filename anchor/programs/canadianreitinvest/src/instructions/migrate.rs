@@ -0,0 +1,368 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, Space};
+
+use crate::state::{self, Versioned, RESERVED_SPACE};
+
+/// Rewrites a program account of type `T` in its current layout, growing it and topping up its
+/// rent from the payer. Migration only re-encodes the account's own data, so anyone may run it.
+///
+/// `older` decodes an earlier layout of T from the account's data, discriminator included,
+/// identifying it by its length.
+pub fn handler<T>(ctx: Context<Migrate>, older: impl FnOnce(&[u8]) -> Result<Option<T>>) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Space + Versioned,
+{
+    rewrite(&ctx.accounts.payer, &ctx.accounts.account, &ctx.accounts.system_program, older)
+}
+
+/// Rewrites a baseline Fundraiser with `reit_id`, the REIT id text the baseline never stored.
+/// It must be the id the fundraiser's PDA was derived from.
+pub fn fundraiser_handler(ctx: Context<Migrate>, reit_id: String) -> Result<()> {
+    let Migrate { payer, account, system_program } = &*ctx.accounts;
+    let program_id = ctx.program_id;
+    rewrite(payer, account, system_program, |data| {
+        let Some(baseline) = decode::<FundraiserV0>(data)? else {
+            return Ok(None);
+        };
+        let reit_id_hash = state::reit_id_hash(&reit_id)?;
+        let address = Pubkey::create_program_address(&[b"fundraiser", reit_id_hash.as_slice(), &[baseline.bump]], program_id)
+            .map_err(|_| error!(crate::errors::CustomError::InvalidReitIdHash))?;
+        if address != account.key() {
+            debug_msg!("ERROR: REIT id {} derives fundraiser {}", reit_id, address);
+            return Err(error!(crate::errors::CustomError::InvalidReitIdHash));
+        }
+        Ok(Some(baseline.into_current(reit_id)))
+    })
+}
+
+/// Rewrites a baseline Investment and counts it on its fundraiser, investor and per-fundraiser
+/// record, as `invest` does for new ones. Those accounts must be migrated first.
+pub fn investment_handler(ctx: Context<MigrateInvestment>) -> Result<()> {
+    let MigrateInvestment { payer, account, fundraiser, investor, investor_fundraiser, system_program } = &mut *ctx.accounts;
+    rewrite(payer, account, system_program, |data| {
+        let Some(baseline) = decode::<InvestmentV0>(data)? else {
+            return Ok(None);
+        };
+        require_keys_eq!(baseline.fundraiser, fundraiser.key(), crate::errors::CustomError::InvalidAuthority);
+        require_keys_eq!(baseline.investor, investor.investor_pubkey, crate::errors::CustomError::InvalidAuthority);
+        require_keys_eq!(investor_fundraiser.investor, baseline.investor, crate::errors::CustomError::InvalidAuthority);
+        require_keys_eq!(investor_fundraiser.fundraiser, baseline.fundraiser, crate::errors::CustomError::InvalidAuthority);
+
+        let increment = |count: &mut u64| -> Result<()> {
+            *count = count.checked_add(1).ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+            Ok(())
+        };
+        increment(&mut fundraiser.investment_count)?;
+        increment(&mut investor_fundraiser.investment_account_count)?;
+        if baseline.status.is_open() {
+            increment(&mut fundraiser.open_investment_count)?;
+            increment(&mut investor.open_investment_count)?;
+        }
+        Ok(Some(baseline.into_current(fundraiser)))
+    })
+}
+
+fn rewrite<'info, T>(
+    payer: &Signer<'info>,
+    account: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    older: impl FnOnce(&[u8]) -> Result<Option<T>>,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Space + Versioned,
+{
+    debug_msg!("Migrate handler start");
+    debug_msg!("Account: {}", account.key());
+
+    let account_info = account.to_account_info();
+    let new_len = 8 + T::INIT_SPACE;
+
+    let (previous_len, migrated) = {
+        let data = account_info.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != *T::DISCRIMINATOR {
+            return Err(error!(ErrorCode::AccountDiscriminatorMismatch));
+        }
+        debug_msg!("Account is {} bytes, current layout is {} (version {})", data.len(), new_len, T::VERSION);
+
+        let mut migrated = Vec::with_capacity(new_len);
+        if data.len() == new_len && data[8] == T::VERSION {
+            return Err(error!(crate::errors::CustomError::AccountAlreadyMigrated));
+        }
+        let account = older(&data)?.ok_or(error!(ErrorCode::AccountDidNotDeserialize))?;
+        account.try_serialize(&mut migrated)?;
+        migrated.resize(new_len, 0);
+        // Refuse to write anything the current program couldn't read back
+        T::try_deserialize(&mut &migrated[..])?;
+        (data.len(), migrated)
+    };
+
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(account_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account_info.realloc(new_len, false)?;
+    account_info.try_borrow_mut_data()?.copy_from_slice(&migrated);
+
+    emit!(AccountMigrated {
+        account: account_info.key(),
+        previous_len: previous_len as u64,
+        version: T::VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    debug_msg!("Migrate handler complete");
+
+    Ok(())
+}
+
+/// For account types created after the deployed baseline, which have no earlier layout.
+pub fn no_older_layouts<T>(_data: &[u8]) -> Result<Option<T>> {
+    Ok(None)
+}
+
+/// Decodes an Investor in its baseline layout.
+pub fn older_investor(data: &[u8]) -> Result<Option<state::Investor>> {
+    Ok(decode::<InvestorV0>(data)?.map(InvestorV0::into_current))
+}
+
+/// Decodes an InvestorFundraiser in its baseline or version 1 layout.
+pub fn older_investor_fundraiser(data: &[u8]) -> Result<Option<state::InvestorFundraiser>> {
+    if let Some(baseline) = decode::<InvestorFundraiserV0>(data)? {
+        return Ok(Some(baseline.into_current()));
+    }
+    Ok(decode::<InvestorFundraiserV1>(data)?.map(InvestorFundraiserV1::into_current))
+}
+
+/// Decodes `data` as the layout `V` if it is exactly that layout's size, `None` otherwise.
+fn decode<V: AnchorDeserialize + Space>(data: &[u8]) -> Result<Option<V>> {
+    if data.len() != 8 + V::INIT_SPACE {
+        return Ok(None);
+    }
+    Ok(Some(V::deserialize(&mut &data[8..])?))
+}
+
+/// Fundraiser as deployed before this program had versions, statuses, offering terms or more
+/// than one accepted mint.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct FundraiserV0 {
+    pub admin: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub reit_mint: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub total_raised: u64,
+    pub released_amount: u64,
+    pub bump: u8,
+    pub reit_accepted_currency: [u8; 3],
+}
+
+impl FundraiserV0 {
+    /// The baseline raise was always taking investments in USDC under no exemption limits, and
+    /// minted classic SPL shares, so it becomes an Open fundraiser with the defaults `initialize`
+    /// sets. Its REIT id text was never stored and is passed to `migrate_fundraiser`. The counts
+    /// start at zero and go up as `migrate_investment` rewrites each of its investments.
+    fn into_current(self, reit_id: String) -> state::Fundraiser {
+        state::Fundraiser {
+            version: state::Fundraiser::VERSION,
+            admin: self.admin,
+            usdc_mint: self.usdc_mint,
+            reit_mint: self.reit_mint,
+            reit_token_program: anchor_spl::token::ID,
+            escrow_vault: self.escrow_vault,
+            total_raised: self.total_raised,
+            released_amount: self.released_amount,
+            bump: self.bump,
+            reit_accepted_currency: self.reit_accepted_currency,
+            exemption: state::OfferingExemption::Prospectus,
+            investment_limits: state::InvestmentLimits { non_eligible: 0, eligible: 0 },
+            jurisdiction_mode: state::JurisdictionMode::Denylist,
+            jurisdiction_rules: Vec::new(),
+            hold_period_seconds: state::DEFAULT_HOLD_PERIOD_SECONDS,
+            accepted_mints: vec![state::AcceptedMint {
+                mint: self.usdc_mint,
+                escrow_vault: self.escrow_vault,
+                currency: *b"USD",
+            }],
+            reit_id,
+            status: state::FundraiserStatus::Open,
+            min_raise: 0,
+            max_raise: 0,
+            close_at: 0,
+            investment_count: 0,
+            open_investment_count: 0,
+            reserved: [0u8; RESERVED_SPACE],
+        }
+    }
+}
+
+/// Investor as deployed before this program tracked rolling limits or open investments.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct InvestorV0 {
+    pub investor_pubkey: Pubkey,
+    pub bump: u8,
+}
+
+impl InvestorV0 {
+    /// Nothing invested before migration counts towards rolling limits; the open count goes up
+    /// as `migrate_investment` rewrites the investor's investments.
+    fn into_current(self) -> state::Investor {
+        state::Investor {
+            version: state::Investor::VERSION,
+            investor_pubkey: self.investor_pubkey,
+            bump: self.bump,
+            rolling_invested: state::RollingTotal::default(),
+            open_investment_count: 0,
+            reserved: [0u8; RESERVED_SPACE],
+        }
+    }
+}
+
+/// InvestorFundraiser as deployed before this program counted live investment accounts.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct InvestorFundraiserV0 {
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub investment_counter: u64,
+    pub bump: u8,
+}
+
+impl InvestorFundraiserV0 {
+    /// The account count goes up as `migrate_investment` rewrites the pair's investments.
+    fn into_current(self) -> state::InvestorFundraiser {
+        state::InvestorFundraiser {
+            version: state::InvestorFundraiser::VERSION,
+            investor: self.investor,
+            fundraiser: self.fundraiser,
+            investment_counter: self.investment_counter,
+            bump: self.bump,
+            investment_account_count: 0,
+            rolling_invested: state::RollingTotal::default(),
+            reserved: [0u8; RESERVED_SPACE],
+        }
+    }
+}
+
+/// InvestorFundraiser in version 1, before it kept a rolling total of its own.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct InvestorFundraiserV1 {
+    pub version: u8,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub investment_counter: u64,
+    pub bump: u8,
+    pub investment_account_count: u64,
+    pub reserved: [u8; RESERVED_SPACE],
+}
+
+impl InvestorFundraiserV1 {
+    /// Investments made before migration don't count towards the pair's rolling total.
+    fn into_current(self) -> state::InvestorFundraiser {
+        state::InvestorFundraiser {
+            version: state::InvestorFundraiser::VERSION,
+            investor: self.investor,
+            fundraiser: self.fundraiser,
+            investment_counter: self.investment_counter,
+            bump: self.bump,
+            investment_account_count: self.investment_account_count,
+            rolling_invested: state::RollingTotal::default(),
+            reserved: self.reserved,
+        }
+    }
+}
+
+/// Investment as deployed before this program tracked deposit mints, wires or status history.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct InvestmentV0 {
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub usdc_amount: u64,
+    pub reit_amount: u32,
+    pub status: state::InvestmentStatus,
+    pub bump: u8,
+}
+
+impl InvestmentV0 {
+    /// The baseline only accepted the fundraiser's usdc_mint and recorded no wire or timeline,
+    /// so those fields start from the fundraiser or zeroed.
+    fn into_current(self, fundraiser: &state::Fundraiser) -> state::Investment {
+        state::Investment {
+            version: state::Investment::VERSION,
+            investor: self.investor,
+            fundraiser: self.fundraiser,
+            usdc_amount: self.usdc_amount,
+            reit_amount: self.reit_amount,
+            status: self.status,
+            bump: self.bump,
+            lockup_until: 0,
+            wired_amount: 0,
+            fx_rate: 0,
+            wire_date: 0,
+            wire_reference_hash: [0u8; 32],
+            currency: fundraiser.reit_accepted_currency,
+            share_price: 0,
+            deposit_mint: fundraiser.usdc_mint,
+            created_at: 0,
+            created_slot: 0,
+            last_status_change: 0,
+            status_history: Vec::new(),
+            reserved: [0u8; RESERVED_SPACE],
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Program account in a layout `Account` may not deserialize; the handler checks its
+    /// discriminator and that the rewritten data deserializes
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateInvestment<'info> {
+    /// Pays the rent for the larger account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Investment in a layout `Account` may not deserialize; the handler checks its
+    /// discriminator and that the rewritten data deserializes
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Fundraiser of the investment, already in its current layout
+    #[account(mut)]
+    pub fundraiser: Box<Account<'info, state::Fundraiser>>,
+
+    /// Investor PDA of the investment's investor, already in its current layout
+    #[account(mut)]
+    pub investor: Box<Account<'info, state::Investor>>,
+
+    /// Per-fundraiser record of the investment's investor, already in its current layout
+    #[account(mut)]
+    pub investor_fundraiser: Box<Account<'info, state::InvestorFundraiser>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub previous_len: u64,
+    pub version: u8,
+    pub timestamp: i64,
+}
@@ -8,4 +8,28 @@ pub mod wire;
 pub mod create_reit_mint;
 pub mod update_reit_mint;
 pub mod issue_share;
-pub mod issue_dividend;
\ No newline at end of file
+pub mod issue_dividend;
+pub mod initialize_compliance;
+pub mod set_compliance_authority;
+pub mod attest_investor;
+pub mod set_offering_exemption;
+pub mod set_jurisdiction_rules;
+pub mod add_jurisdiction_rule;
+pub mod remove_jurisdiction_rule;
+pub mod create_reit_mint_2022;
+pub mod unlock_shares;
+pub mod set_hold_period;
+pub mod freeze_holder;
+pub mod thaw_holder;
+pub mod recover_position;
+pub mod record_share_sale;
+pub mod wire_batch;
+pub mod initialize_fx_rate;
+pub mod publish_fx_rate;
+pub mod set_fx_rate_publisher;
+pub mod update_fundraiser_terms;
+pub mod add_accepted_mint;
+pub mod set_fundraiser_status;
+pub mod close_fundraiser;
+pub mod close_investment;
+pub mod migrate;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(
+    ctx: Context<PublishFxRate>,
+    _base_currency: [u8; 3],
+    _quote_currency: [u8; 3],
+    rate: u64,
+    confidence: u64,
+    published_at: i64,
+) -> Result<()> {
+    debug_msg!("PublishFxRate handler start");
+    debug_msg!("Rate: {}, Confidence: {}, Published at: {}", rate, confidence, published_at);
+
+    let now = Clock::get()?.unix_timestamp;
+    let fx_rate = &mut ctx.accounts.fx_rate;
+
+    // Observations must be real, not from the future, and never older than what is stored
+    if rate == 0 || published_at > now || published_at < fx_rate.published_at {
+        debug_msg!("ERROR: Invalid FX observation (rate: {}, published at: {}, now: {}, last: {})", rate, published_at, now, fx_rate.published_at);
+        return Err(error!(crate::errors::CustomError::InvalidFxRate));
+    }
+
+    fx_rate.rate = rate;
+    fx_rate.confidence = confidence;
+    fx_rate.published_at = published_at;
+
+    debug_msg!("PublishFxRate handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(base_currency: [u8; 3], quote_currency: [u8; 3])]
+pub struct PublishFxRate<'info> {
+    pub publisher: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fx_rate", base_currency.as_ref(), quote_currency.as_ref()],
+        bump = fx_rate.bump,
+        constraint = fx_rate.publisher == publisher.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fx_rate: Account<'info, state::FxRate>,
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+/// Records that the shares issued to an investment have been sold or redeemed, which settles it
+/// so the investor can close it and reclaim its rent.
+pub fn handler(ctx: Context<RecordShareSale>, _reit_id_hash: [u8; 16]) -> Result<()> {
+    debug_msg!("RecordShareSale handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Operating,
+        state::FundraiserStatus::WindingDown,
+        state::FundraiserStatus::Terminated,
+    ])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+
+    let investment = &mut ctx.accounts.investment;
+    investment.status.require_transition_to(state::InvestmentStatus::ShareSold)?;
+
+    // Shares can't change hands during the resale hold period
+    let now = Clock::get()?.unix_timestamp;
+    if now < investment.lockup_until {
+        debug_msg!("ERROR: Shares are locked until {} (now: {})", investment.lockup_until, now);
+        return Err(error!(crate::errors::CustomError::HoldPeriodActive));
+    }
+
+    let old_status = investment.transition(state::InvestmentStatus::ShareSold, now, ctx.accounts.admin.key())?;
+    debug_msg!("Status: {:?} -> {:?}", old_status, investment.status);
+
+    emit!(SharesSold {
+        investment: investment.key(),
+        investor: investment.investor,
+        fundraiser: ctx.accounts.fundraiser.key(),
+        old_status,
+        new_status: investment.status,
+        reit_amount: investment.reit_amount,
+        timestamp: now,
+    });
+
+    debug_msg!("RecordShareSale handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct RecordShareSale<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(
+        mut,
+        constraint = investment.fundraiser == fundraiser.key() @ crate::errors::CustomError::InvalidFundraiserMismatch
+    )]
+    pub investment: Account<'info, state::Investment>,
+}
+
+#[event]
+pub struct SharesSold {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub old_status: state::InvestmentStatus,
+    pub new_status: state::InvestmentStatus,
+    pub reit_amount: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,297 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Burn, FreezeAccount, Mint, MintTo, ThawAccount, TokenAccount, TokenInterface};
+
+use crate::state::{self, Versioned};
+
+pub fn handler(ctx: Context<RecoverPosition>, reit_id_hash: [u8; 16], new_owner: Pubkey, legal_reference_hash: [u8; 32]) -> Result<()> {
+    debug_msg!("RecoverPosition handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Operating,
+        state::FundraiserStatus::WindingDown,
+    ])?;
+    debug_msg!("Compliance authority: {}", ctx.accounts.authority.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+    debug_msg!("Old holder: {}, New owner: {}", ctx.accounts.investment.investor, new_owner);
+    ctx.accounts.investment.require_status(state::InvestmentStatus::ShareIssued)?;
+
+    require_keys_eq!(
+        ctx.accounts.new_owner.key(),
+        new_owner,
+        crate::errors::CustomError::InvalidAuthority
+    );
+    require_keys_neq!(
+        ctx.accounts.investment.investor,
+        new_owner,
+        crate::errors::CustomError::InvalidAuthority
+    );
+
+    // The rightful owner must be able to hold REIT units in their own right
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.new_owner_kyc_attestation.require_valid(now)?;
+
+    let fundraiser_key = ctx.accounts.fundraiser.key();
+    let seeds = &[
+        b"fundraiser",
+        reit_id_hash.as_slice(),
+        &[ctx.accounts.fundraiser.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let old_investor = ctx.accounts.investment.investor;
+    let amount = ctx.accounts.investment.reit_amount as u64;
+    let (lock_vault, _) = Pubkey::find_program_address(
+        &[b"lock_vault", ctx.accounts.investment.key().as_ref()],
+        ctx.program_id,
+    );
+    let old_token_account_key = ctx.accounts.old_token_account.key();
+
+    if old_token_account_key == lock_vault {
+        // Shares are still in the investment's lock vault; re-pointing the investment
+        // is enough for the new owner to unlock them
+        debug_msg!("Shares held in lock vault {}, re-pointing investment only", lock_vault);
+    } else {
+        if ctx.accounts.old_token_account.owner != old_investor {
+            debug_msg!("ERROR: Token account owner {} is not the investment holder {}", ctx.accounts.old_token_account.owner, old_investor);
+            return Err(error!(crate::errors::CustomError::InvalidAuthority));
+        }
+
+        if ctx.accounts.fundraiser.reit_token_program == anchor_spl::token_2022::ID {
+            // Frozen accounts can't be burned from, so thaw first
+            if ctx.accounts.old_token_account.is_frozen() {
+                token_interface::thaw_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    ThawAccount {
+                        account: ctx.accounts.old_token_account.to_account_info(),
+                        mint: ctx.accounts.reit_mint.to_account_info(),
+                        authority: ctx.accounts.fundraiser.to_account_info(),
+                    },
+                    signer_seeds,
+                ))?;
+            }
+
+            // The fundraiser PDA is the permanent delegate of Token-2022 REIT mints
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.reit_mint.to_account_info(),
+                        from: ctx.accounts.old_token_account.to_account_info(),
+                        authority: ctx.accounts.fundraiser.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+            debug_msg!("Burned {} REIT tokens from {}", amount, old_token_account_key);
+        } else if !ctx.accounts.old_token_account.is_frozen() {
+            // Classic SPL mints have no permanent delegate, so the lost units can't be burned.
+            // Freezing the old account through the mint's freeze authority takes them out of
+            // circulation instead, and the replacement units are minted below.
+            token_interface::freeze_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                FreezeAccount {
+                    account: ctx.accounts.old_token_account.to_account_info(),
+                    mint: ctx.accounts.reit_mint.to_account_info(),
+                    authority: ctx.accounts.fundraiser.to_account_info(),
+                },
+                signer_seeds,
+            ))?;
+            debug_msg!("Froze {} holding {} REIT tokens", old_token_account_key, ctx.accounts.old_token_account.amount);
+        }
+
+        token_interface::mint_to(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.reit_mint.to_account_info(),
+                    to: ctx.accounts.new_owner_ata.to_account_info(),
+                    authority: ctx.accounts.admin.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        debug_msg!("Minted {} REIT tokens to {}", amount, ctx.accounts.new_owner_ata.key());
+    }
+
+    // Recovered units keep the original resale hold period
+    let lockup_until = ctx.accounts.investment.lockup_until;
+    let holder_lockup = &mut ctx.accounts.new_owner_lockup;
+    holder_lockup.holder = new_owner;
+    holder_lockup.mint = ctx.accounts.reit_mint.key();
+    holder_lockup.locked_until = holder_lockup.locked_until.max(lockup_until);
+    holder_lockup.version = state::HolderLockup::VERSION;
+    holder_lockup.bump = ctx.bumps.new_owner_lockup;
+
+    // The per-fundraiser record follows the position
+    let new_investor_fundraiser = &mut ctx.accounts.new_investor_fundraiser;
+    if new_investor_fundraiser.investor == Pubkey::default() {
+        new_investor_fundraiser.investor = new_owner;
+        new_investor_fundraiser.fundraiser = fundraiser_key;
+        new_investor_fundraiser.investment_counter = 0;
+        new_investor_fundraiser.investment_account_count = 0;
+        new_investor_fundraiser.rolling_invested = state::RollingTotal::default();
+        new_investor_fundraiser.version = state::InvestorFundraiser::VERSION;
+        new_investor_fundraiser.bump = ctx.bumps.new_investor_fundraiser;
+    }
+    new_investor_fundraiser.investment_account_count = new_investor_fundraiser
+        .investment_account_count
+        .checked_add(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
+    let old_investor_fundraiser = &mut ctx.accounts.old_investor_fundraiser;
+    old_investor_fundraiser.investment_account_count = old_investor_fundraiser
+        .investment_account_count
+        .checked_sub(1)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+    // As in close_investment, an empty record goes back to the investor who paid for it
+    if old_investor_fundraiser.investment_account_count == 0 {
+        debug_msg!("Closing InvestorFundraiser {}", old_investor_fundraiser.key());
+        old_investor_fundraiser.close(ctx.accounts.old_owner.to_account_info())?;
+    }
+
+    let investment = &mut ctx.accounts.investment;
+    investment.investor = new_owner;
+
+    emit!(PositionRecovered {
+        fundraiser: fundraiser_key,
+        investment: investment.key(),
+        reit_mint: ctx.accounts.reit_mint.key(),
+        old_holder: old_investor,
+        new_holder: new_owner,
+        old_token_account: old_token_account_key,
+        new_token_account: ctx.accounts.new_owner_ata.key(),
+        amount,
+        authority: ctx.accounts.authority.key(),
+        legal_reference_hash,
+        timestamp: now,
+    });
+
+    debug_msg!("RecoverPosition handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16], new_owner: Pubkey)]
+pub struct RecoverPosition<'info> {
+    /// Compliance authority ordering the recovery
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Fundraiser admin, required as the REIT mint authority
+    #[account(mut, address = fundraiser.admin @ crate::errors::CustomError::InvalidAuthority)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        constraint = compliance_config.authority == authority.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub compliance_config: Account<'info, state::ComplianceConfig>,
+
+    #[account(
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(
+        mut,
+        constraint = investment.fundraiser == fundraiser.key() @ crate::errors::CustomError::InvalidFundraiserMismatch
+    )]
+    pub investment: Account<'info, state::Investment>,
+
+    #[account(
+        mut,
+        constraint = reit_mint.key() == fundraiser.reit_mint @ crate::errors::CustomError::InvalidMint
+    )]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holder's REIT token account, or the investment's lock vault
+    #[account(
+        mut,
+        token::mint = reit_mint,
+        token::token_program = token_program,
+    )]
+    pub old_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Current holder, refunded the rent of their InvestorFundraiser record once it is empty
+    /// CHECK: must be the investment's investor
+    #[account(mut, address = investment.investor @ crate::errors::CustomError::InvalidAuthority)]
+    pub old_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"investor_fundraiser", investment.investor.as_ref(), fundraiser.key().as_ref()],
+        bump = old_investor_fundraiser.bump,
+    )]
+    pub old_investor_fundraiser: Box<Account<'info, state::InvestorFundraiser>>,
+
+    /// Rightful owner receiving the position
+    /// CHECK: new_owner parameter validates this
+    pub new_owner: UncheckedAccount<'info>,
+
+    /// New owner's Investor PDA, created beforehand with initialize_investor. The accounts added
+    /// for the recovery are boxed to keep account validation within the SBF stack frame.
+    #[account(
+        seeds = [b"investor", new_owner.key().as_ref()],
+        bump = new_investor.bump,
+    )]
+    pub new_investor: Box<Account<'info, state::Investor>>,
+
+    #[account(
+        seeds = [b"kyc_attestation", new_investor.key().as_ref()],
+        bump = new_owner_kyc_attestation.bump,
+    )]
+    pub new_owner_kyc_attestation: Box<Account<'info, state::KycAttestation>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + state::InvestorFundraiser::INIT_SPACE,
+        seeds = [b"investor_fundraiser", new_owner.key().as_ref(), fundraiser.key().as_ref()],
+        bump
+    )]
+    pub new_investor_fundraiser: Box<Account<'info, state::InvestorFundraiser>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = reit_mint,
+        associated_token::authority = new_owner,
+        associated_token::token_program = token_program,
+    )]
+    pub new_owner_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + state::HolderLockup::INIT_SPACE,
+        seeds = [b"holder_lockup", reit_mint.key().as_ref(), new_owner.key().as_ref()],
+        bump
+    )]
+    pub new_owner_lockup: Account<'info, state::HolderLockup>,
+
+    #[account(constraint = token_program.key() == fundraiser.reit_token_program @ crate::errors::CustomError::InvalidMint)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a position is re-issued to its rightful owner
+#[event]
+pub struct PositionRecovered {
+    pub fundraiser: Pubkey,
+    pub investment: Pubkey,
+    pub reit_mint: Pubkey,
+    pub old_holder: Pubkey,
+    pub new_holder: Pubkey,
+    pub old_token_account: Pubkey,
+    pub new_token_account: Pubkey,
+    pub amount: u64,
+    pub authority: Pubkey,
+    pub legal_reference_hash: [u8; 32],
+    pub timestamp: i64,
+}
@@ -4,56 +4,94 @@ use anchor_spl::token::{self, Token, TokenAccount, Mint, TransferChecked};
 use crate::state;
 
 pub fn handler(ctx: Context<Refund>, reit_id_hash: [u8; 16]) -> Result<()> {
-    msg!("Refund handler start");
-    msg!("Admin: {}", ctx.accounts.admin.key());
-    msg!("Investment: {}", ctx.accounts.investment.key());
-    msg!("REIT ID hash: {:?}", reit_id_hash);
+    debug_msg!("Refund handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Failed,
+        state::FundraiserStatus::Operating,
+    ])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+    debug_msg!("REIT ID hash: {:?}", reit_id_hash);
 
     let investment = &mut ctx.accounts.investment;
-    msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
+    debug_msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
 
-    // Verify investment is in released status
-    if investment.status != state::InvestmentStatus::Released {
-        msg!("ERROR: Investment status is not released. Current status: {:?}", investment.status);
-        return Err(error!(crate::errors::CustomError::InvalidInvestmentStatus));
-    }
+    // Only Released investments can be refunded
+    let now = Clock::get()?.unix_timestamp;
+    let old_status = investment.transition(state::InvestmentStatus::Refunded, now, ctx.accounts.admin.key())?;
 
     // Verify admin is the signer and matches fundraiser admin
     if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
-        msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
+        debug_msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
         return Err(error!(crate::errors::CustomError::InvalidAuthority));
     }
 
     // Validate investor matches investment
     if ctx.accounts.investor.key() != investment.investor {
-        msg!("ERROR: Investor account {} does not match investment investor {}", ctx.accounts.investor.key(), investment.investor);
+        debug_msg!("ERROR: Investor account {} does not match investment investor {}", ctx.accounts.investor.key(), investment.investor);
         return Err(error!(crate::errors::CustomError::InvalidAuthority));
     }
 
-    // Transfer USDC from admin to investor using token::transfer_checked
+    // The admin returns the released deposit to the escrow vault of its mint, which pays the
+    // investor, so every stablecoin movement goes through the matching vault
     let transfer_amount = investment.usdc_amount;
-    msg!("Transferring {} USDC from admin to investor", transfer_amount);
+    debug_msg!("Returning {} from admin to escrow vault {}", transfer_amount, ctx.accounts.escrow_vault.key());
 
     let cpi_accounts = TransferChecked {
         from: ctx.accounts.admin_usdc_ata.to_account_info(),
-        to: ctx.accounts.investor_usdc_ata.to_account_info(),
+        to: ctx.accounts.escrow_vault.to_account_info(),
         authority: ctx.accounts.admin.to_account_info(),
         mint: ctx.accounts.usdc_mint.to_account_info(),
     };
+    let cpi_context = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer_checked(cpi_context, transfer_amount, ctx.accounts.usdc_mint.decimals)?;
 
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
-
-    // USDC has 6 decimals
+    debug_msg!("Refunding {} from escrow vault to investor", transfer_amount);
+    let seeds = &[
+        b"fundraiser",
+        reit_id_hash.as_slice(),
+        &[ctx.accounts.fundraiser.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_vault.to_account_info(),
+        to: ctx.accounts.investor_usdc_ata.to_account_info(),
+        authority: ctx.accounts.fundraiser.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_context = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds);
     token::transfer_checked(cpi_context, transfer_amount, ctx.accounts.usdc_mint.decimals)?;
 
-    msg!("USDC transfer completed successfully");
+    debug_msg!("USDC transfer completed successfully");
+
+    // The refunded deposit is no longer held by the admin
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    fundraiser.released_amount = fundraiser
+        .released_amount
+        .checked_sub(transfer_amount)
+        .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+    fundraiser.settle_investment()?;
+    ctx.accounts.investor_pda.settle_investment()?;
+
+    // A refunded deposit no longer counts towards the investor's rolling totals
+    if fundraiser.exemption == state::OfferingExemption::OfferingMemorandum {
+        ctx.accounts.investor_pda.rolling_invested.release(now, investment.created_at, transfer_amount);
+    }
+    ctx.accounts.investor_fundraiser.rolling_invested.release(now, investment.created_at, transfer_amount);
 
-    // Update investment status to Refunded
-    investment.status = state::InvestmentStatus::Refunded;
-    msg!("Investment status updated to Refunded (status: {:?})", investment.status);
+    emit!(InvestmentRefunded {
+        investment: investment.key(),
+        investor: investment.investor,
+        fundraiser: ctx.accounts.fundraiser.key(),
+        old_status,
+        new_status: investment.status,
+        deposit_mint: investment.deposit_mint,
+        amount: transfer_amount,
+        timestamp: now,
+    });
 
-    msg!("Refund handler complete - transaction successful");
+    debug_msg!("Refund handler complete - transaction successful");
 
     Ok(())
 }
@@ -66,6 +104,7 @@ pub struct Refund<'info> {
 
     /// CHECK: derived in constraint
     #[account(
+        mut,
         seeds = [b"fundraiser", reit_id_hash.as_slice()],
         bump = fundraiser.bump,
     )]
@@ -78,7 +117,23 @@ pub struct Refund<'info> {
     /// CHECK: Validated via investment.investor field
     pub investor: UncheckedAccount<'info>,
 
-    /// Admin's USDC token account (source of refund)
+    /// Investor PDA tracking the investor's open positions
+    #[account(
+        mut,
+        seeds = [b"investor", investment.investor.as_ref()],
+        bump = investor_pda.bump,
+    )]
+    pub investor_pda: Account<'info, state::Investor>,
+
+    /// Investor's record for this fundraiser, tracking what they invested in it
+    #[account(
+        mut,
+        seeds = [b"investor_fundraiser", investment.investor.as_ref(), fundraiser.key().as_ref()],
+        bump = investor_fundraiser.bump,
+    )]
+    pub investor_fundraiser: Box<Account<'info, state::InvestorFundraiser>>,
+
+    /// Admin's USDC token account, returning the released deposit
     #[account(
         mut,
         token::mint = usdc_mint,
@@ -94,12 +149,31 @@ pub struct Refund<'info> {
     )]
     pub investor_usdc_ata: Account<'info, TokenAccount>,
 
-    /// USDC mint for validation
+    /// Stablecoin the investment was deposited in, refunded in kind
     #[account(
-        constraint = usdc_mint.key() == fundraiser.usdc_mint
+        constraint = usdc_mint.key() == investment.deposit_mint @ crate::errors::CustomError::InvalidMint
     )]
     pub usdc_mint: Account<'info, Mint>,
 
+    /// Escrow vault of usdc_mint, paying the refund
+    #[account(
+        mut,
+        constraint = fundraiser.escrow_vault_for(&usdc_mint.key()) == Some(escrow_vault.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
     /// Token program for transfer
     pub token_program: Program<'info, Token>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct InvestmentRefunded {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub old_status: state::InvestmentStatus,
+    pub new_status: state::InvestmentStatus,
+    pub deposit_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
@@ -5,33 +5,32 @@ use anchor_spl::associated_token::AssociatedToken;
 use crate::state;
 
 pub fn handler(ctx: Context<Release>, reit_id_hash: [u8; 16]) -> Result<()> {
-    msg!("Release handler start");
-    msg!("Admin: {}", ctx.accounts.admin.key());
-    msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
-    msg!("Investment: {}", ctx.accounts.investment.key());
-    msg!("Admin USDC ATA: {}", ctx.accounts.admin_usdc_ata.key());
-    msg!("Escrow vault: {}", ctx.accounts.escrow_vault.key());
-    msg!("USDC mint: {}", ctx.accounts.usdc_mint.key());
-    msg!("REIT ID hash: {:?}", reit_id_hash);
+    debug_msg!("Release handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Funded, state::FundraiserStatus::Failed])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+    debug_msg!("Admin USDC ATA: {}", ctx.accounts.admin_usdc_ata.key());
+    debug_msg!("Escrow vault: {}", ctx.accounts.escrow_vault.key());
+    debug_msg!("USDC mint: {}", ctx.accounts.usdc_mint.key());
+    debug_msg!("REIT ID hash: {:?}", reit_id_hash);
 
     let investment = &mut ctx.accounts.investment;
-    msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
+    debug_msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
 
-    // Verify investment is in pending status
-    if investment.status != state::InvestmentStatus::Pending {
-        msg!("ERROR: Investment status is not pending. Current status: {:?}", investment.status);
-        return Err(error!(crate::errors::CustomError::InvalidInvestmentStatus));
-    }
+    // Only Pending investments can be released
+    let now = Clock::get()?.unix_timestamp;
+    let old_status = investment.transition(state::InvestmentStatus::Released, now, ctx.accounts.admin.key())?;
 
     // Verify admin is the signer
     if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
-        msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
+        debug_msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
         return Err(error!(crate::errors::CustomError::InvalidAuthority));
     }
 
-    msg!("Transferring {} USDC from escrow vault to admin ATA", investment.usdc_amount);
-    msg!("From (escrow): {}", ctx.accounts.escrow_vault.key());
-    msg!("To (admin ATA): {}", ctx.accounts.admin_usdc_ata.key());
+    debug_msg!("Transferring {} USDC from escrow vault to admin ATA", investment.usdc_amount);
+    debug_msg!("From (escrow): {}", ctx.accounts.escrow_vault.key());
+    debug_msg!("To (admin ATA): {}", ctx.accounts.admin_usdc_ata.key());
 
     // Transfer USDC from escrow vault to admin's ATA
     let cpi_accounts = Transfer {
@@ -51,11 +50,7 @@ pub fn handler(ctx: Context<Release>, reit_id_hash: [u8; 16]) -> Result<()> {
 
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
     token::transfer(cpi_ctx, investment.usdc_amount)?;
-    msg!("Token transfer completed successfully");
-
-    // Update investment status to Released
-    investment.status = state::InvestmentStatus::Released;
-    msg!("Investment status updated to Released (status: {:?})", investment.status);
+    debug_msg!("Token transfer completed successfully");
 
     // Update fundraiser released amount
     let fundraiser = &mut ctx.accounts.fundraiser;
@@ -64,9 +59,21 @@ pub fn handler(ctx: Context<Release>, reit_id_hash: [u8; 16]) -> Result<()> {
         .released_amount
         .checked_add(investment.usdc_amount)
         .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
-    msg!("Fundraiser released amount updated: {} -> {}", old_released, fundraiser.released_amount);
-
-    msg!("Release handler complete - transaction successful");
+    debug_msg!("Fundraiser released amount updated: {} -> {}", old_released, fundraiser.released_amount);
+
+    emit!(InvestmentReleased {
+        investment: investment.key(),
+        investor: investment.investor,
+        fundraiser: fundraiser.key(),
+        old_status,
+        new_status: investment.status,
+        deposit_mint: investment.deposit_mint,
+        amount: investment.usdc_amount,
+        released_amount: fundraiser.released_amount,
+        timestamp: now,
+    });
+
+    debug_msg!("Release handler complete - transaction successful");
 
     Ok(())
 }
@@ -97,15 +104,32 @@ pub struct Release<'info> {
     )]
     pub admin_usdc_ata: Account<'info, TokenAccount>,
 
-    /// USDC mint (must match fundraiser.usdc_mint)
-    #[account(constraint = usdc_mint.key() == fundraiser.usdc_mint)]
+    /// Stablecoin the investment was deposited in
+    #[account(constraint = usdc_mint.key() == investment.deposit_mint @ crate::errors::CustomError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
 
-    #[account(mut, constraint = escrow_vault.key() == fundraiser.escrow_vault)]
+    /// Escrow vault holding deposits of usdc_mint
+    #[account(
+        mut,
+        constraint = fundraiser.escrow_vault_for(&usdc_mint.key()) == Some(escrow_vault.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
     pub escrow_vault: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct InvestmentReleased {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub old_status: state::InvestmentStatus,
+    pub new_status: state::InvestmentStatus,
+    pub deposit_mint: Pubkey,
+    pub amount: u64,
+    pub released_amount: u64, // Fundraiser total released after this investment
+    pub timestamp: i64,
+}
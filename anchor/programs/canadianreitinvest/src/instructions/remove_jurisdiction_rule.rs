@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(ctx: Context<RemoveJurisdictionRule>, _reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Result<()> {
+    debug_msg!("RemoveJurisdictionRule handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Draft, state::FundraiserStatus::Open])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Jurisdiction: {:?}", jurisdiction);
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    let position = fundraiser
+        .jurisdiction_rules
+        .iter()
+        .position(|rule| *rule == jurisdiction)
+        .ok_or(error!(crate::errors::CustomError::JurisdictionRuleNotFound))?;
+    fundraiser.jurisdiction_rules.remove(position);
+
+    debug_msg!("RemoveJurisdictionRule handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct RemoveJurisdictionRule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
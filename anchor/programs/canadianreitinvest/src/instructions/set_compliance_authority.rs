@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(ctx: Context<SetComplianceAuthority>, new_authority: Pubkey) -> Result<()> {
+    debug_msg!("SetComplianceAuthority handler start");
+
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    debug_msg!("Compliance authority: {} -> {}", compliance_config.authority, new_authority);
+    compliance_config.authority = new_authority;
+
+    debug_msg!("SetComplianceAuthority handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        constraint = compliance_config.authority == authority.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub compliance_config: Account<'info, state::ComplianceConfig>,
+}
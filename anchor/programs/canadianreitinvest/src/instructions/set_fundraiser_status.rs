@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(ctx: Context<SetFundraiserStatus>, _reit_id_hash: [u8; 16], status: state::FundraiserStatus) -> Result<()> {
+    debug_msg!("SetFundraiserStatus handler start");
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+
+    let now = Clock::get()?.unix_timestamp;
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    fundraiser.require_transition(status, now)?;
+
+    let old_status = fundraiser.status;
+    fundraiser.status = status;
+    debug_msg!("Status: {:?} -> {:?}", old_status, status);
+
+    emit!(FundraiserStatusChanged {
+        fundraiser: fundraiser.key(),
+        old_status,
+        new_status: status,
+        total_raised: fundraiser.total_raised,
+        timestamp: now,
+    });
+
+    debug_msg!("SetFundraiserStatus handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct SetFundraiserStatus<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
+
+#[event]
+pub struct FundraiserStatusChanged {
+    pub fundraiser: Pubkey,
+    pub old_status: state::FundraiserStatus,
+    pub new_status: state::FundraiserStatus,
+    pub total_raised: u64,
+    pub timestamp: i64,
+}
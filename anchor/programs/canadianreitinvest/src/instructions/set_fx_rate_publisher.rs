@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::program::Canadianreitinvest;
+use crate::state;
+
+pub fn handler(
+    ctx: Context<SetFxRatePublisher>,
+    _base_currency: [u8; 3],
+    _quote_currency: [u8; 3],
+    new_publisher: Pubkey,
+) -> Result<()> {
+    debug_msg!("SetFxRatePublisher handler start");
+
+    let fx_rate = &mut ctx.accounts.fx_rate;
+    debug_msg!("FX rate publisher: {} -> {}", fx_rate.publisher, new_publisher);
+    fx_rate.publisher = new_publisher;
+
+    debug_msg!("SetFxRatePublisher handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(base_currency: [u8; 3], quote_currency: [u8; 3])]
+pub struct SetFxRatePublisher<'info> {
+    /// Must be the program upgrade authority, which appointed the current publisher
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fx_rate", base_currency.as_ref(), quote_currency.as_ref()],
+        bump = fx_rate.bump
+    )]
+    pub fx_rate: Account<'info, state::FxRate>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Canadianreitinvest>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
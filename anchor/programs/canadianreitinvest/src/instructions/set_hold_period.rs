@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(ctx: Context<SetHoldPeriod>, _reit_id_hash: [u8; 16], hold_period_seconds: i64) -> Result<()> {
+    debug_msg!("SetHoldPeriod handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Draft,
+        state::FundraiserStatus::Open,
+        state::FundraiserStatus::Closed,
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Operating,
+    ])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+
+    if hold_period_seconds < 0 {
+        return Err(error!(crate::errors::CustomError::InvalidAmount));
+    }
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    debug_msg!("Hold period: {} -> {} seconds", fundraiser.hold_period_seconds, hold_period_seconds);
+    fundraiser.hold_period_seconds = hold_period_seconds;
+
+    debug_msg!("SetHoldPeriod handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct SetHoldPeriod<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
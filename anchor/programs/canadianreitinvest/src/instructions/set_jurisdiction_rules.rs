@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(
+    ctx: Context<SetJurisdictionRules>,
+    _reit_id_hash: [u8; 16],
+    mode: state::JurisdictionMode,
+    rules: Vec<state::Jurisdiction>,
+) -> Result<()> {
+    debug_msg!("SetJurisdictionRules handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Draft, state::FundraiserStatus::Open])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Mode: {:?}, Rules: {}", mode, rules.len());
+
+    if rules.len() > state::MAX_JURISDICTION_RULES {
+        debug_msg!("ERROR: {} rules exceeds maximum of {}", rules.len(), state::MAX_JURISDICTION_RULES);
+        return Err(error!(crate::errors::CustomError::TooManyJurisdictionRules));
+    }
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    fundraiser.jurisdiction_mode = mode;
+    fundraiser.jurisdiction_rules = rules;
+
+    debug_msg!("SetJurisdictionRules handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct SetJurisdictionRules<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(
+    ctx: Context<SetOfferingExemption>,
+    _reit_id_hash: [u8; 16],
+    exemption: state::OfferingExemption,
+    investment_limits: state::InvestmentLimits,
+) -> Result<()> {
+    debug_msg!("SetOfferingExemption handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Draft, state::FundraiserStatus::Open])?;
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Exemption: {:?}, Limits: {:?}", exemption, investment_limits);
+
+    // Investments are counted towards the investor's limits under the exemption they were
+    // made under, and released from them under the same one on refund
+    let fundraiser = &mut ctx.accounts.fundraiser;
+    if fundraiser.investment_count > 0 {
+        debug_msg!("ERROR: Fundraiser already has {} investments", fundraiser.investment_count);
+        return Err(error!(crate::errors::CustomError::FundraiserTermsLocked));
+    }
+    fundraiser.exemption = exemption;
+    fundraiser.investment_limits = investment_limits;
+
+    debug_msg!("SetOfferingExemption handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct SetOfferingExemption<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
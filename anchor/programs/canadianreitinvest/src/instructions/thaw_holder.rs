@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, ThawAccount, Mint, TokenAccount, TokenInterface};
+
+use crate::state;
+
+pub fn handler(ctx: Context<ThawHolder>, reit_id_hash: [u8; 16], legal_reference_hash: [u8; 32]) -> Result<()> {
+    debug_msg!("ThawHolder handler start");
+    debug_msg!("Compliance authority: {}", ctx.accounts.authority.key());
+    debug_msg!("Holder token account: {}", ctx.accounts.holder_token_account.key());
+
+    // Not gated on fundraiser status: regulatory orders apply in every lifecycle state.
+    // The fundraiser PDA is the REIT mint's freeze authority
+    let seeds = &[
+        b"fundraiser",
+        reit_id_hash.as_slice(),
+        &[ctx.accounts.fundraiser.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.holder_token_account.to_account_info(),
+            mint: ctx.accounts.reit_mint.to_account_info(),
+            authority: ctx.accounts.fundraiser.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    emit!(HolderThawed {
+        fundraiser: ctx.accounts.fundraiser.key(),
+        reit_mint: ctx.accounts.reit_mint.key(),
+        token_account: ctx.accounts.holder_token_account.key(),
+        holder: ctx.accounts.holder_token_account.owner,
+        balance: ctx.accounts.holder_token_account.amount,
+        authority: ctx.accounts.authority.key(),
+        legal_reference_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    debug_msg!("ThawHolder handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct ThawHolder<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        constraint = compliance_config.authority == authority.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub compliance_config: Account<'info, state::ComplianceConfig>,
+
+    #[account(
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(constraint = reit_mint.key() == fundraiser.reit_mint @ crate::errors::CustomError::InvalidMint)]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = reit_mint,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = token_program.key() == fundraiser.reit_token_program @ crate::errors::CustomError::InvalidMint)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Event emitted when a holder's REIT token account is thawed after a regulatory freeze
+#[event]
+pub struct HolderThawed {
+    pub fundraiser: Pubkey,
+    pub reit_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub holder: Pubkey,
+    pub balance: u64,
+    pub authority: Pubkey,
+    pub legal_reference_hash: [u8; 32],
+    pub timestamp: i64,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::state;
+
+pub fn handler(ctx: Context<UnlockShares>, reit_id_hash: [u8; 16]) -> Result<()> {
+    debug_msg!("UnlockShares handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Operating,
+        state::FundraiserStatus::WindingDown,
+        state::FundraiserStatus::Terminated,
+    ])?;
+    debug_msg!("Investor: {}", ctx.accounts.investor_signer.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+    debug_msg!("Lock vault: {}", ctx.accounts.lock_vault.key());
+
+    let investment = &ctx.accounts.investment;
+    investment.require_status(state::InvestmentStatus::ShareIssued)?;
+
+    // Shares can only leave the lock vault once the hold period has passed
+    let now = Clock::get()?.unix_timestamp;
+    if now < investment.lockup_until {
+        debug_msg!("ERROR: Shares are locked until {} (now: {})", investment.lockup_until, now);
+        return Err(error!(crate::errors::CustomError::HoldPeriodActive));
+    }
+
+    let seeds = &[
+        b"fundraiser",
+        reit_id_hash.as_slice(),
+        &[ctx.accounts.fundraiser.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // Move every locked share to the investor's ATA
+    let amount = ctx.accounts.lock_vault.amount;
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.lock_vault.to_account_info(),
+                mint: ctx.accounts.reit_mint.to_account_info(),
+                to: ctx.accounts.investor_ata.to_account_info(),
+                authority: ctx.accounts.fundraiser.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        ctx.accounts.reit_mint.decimals,
+    )?;
+    debug_msg!("Unlocked {} REIT tokens to {}", amount, ctx.accounts.investor_ata.key());
+
+    // Return the vault rent to the admin who paid for it
+    token_interface::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.lock_vault.to_account_info(),
+            destination: ctx.accounts.admin.to_account_info(),
+            authority: ctx.accounts.fundraiser.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    debug_msg!("UnlockShares handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct UnlockShares<'info> {
+    #[account(mut)]
+    pub investor_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    #[account(
+        constraint = investment.fundraiser == fundraiser.key() @ crate::errors::CustomError::InvalidFundraiserMismatch,
+        constraint = investment.investor == investor_signer.key() @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub investment: Account<'info, state::Investment>,
+
+    #[account(
+        mut,
+        seeds = [b"lock_vault", investment.key().as_ref()],
+        bump,
+        token::mint = reit_mint,
+        token::authority = fundraiser,
+        token::token_program = token_program,
+    )]
+    pub lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(constraint = reit_mint.key() == fundraiser.reit_mint @ crate::errors::CustomError::InvalidMint)]
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = investor_signer,
+        associated_token::mint = reit_mint,
+        associated_token::authority = investor_signer,
+        associated_token::token_program = token_program,
+    )]
+    pub investor_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Fundraiser admin, receives the lock vault rent
+    /// CHECK: address checked against fundraiser.admin
+    #[account(mut, address = fundraiser.admin @ crate::errors::CustomError::InvalidAuthority)]
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(constraint = token_program.key() == fundraiser.reit_token_program @ crate::errors::CustomError::InvalidMint)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state;
+
+pub fn handler(
+    ctx: Context<UpdateFundraiserTerms>,
+    _reit_id_hash: [u8; 16],
+    reit_accepted_currency: [u8; 3],
+    min_raise: u64,
+    max_raise: u64,
+    close_at: i64,
+) -> Result<()> {
+    debug_msg!("UpdateFundraiserTerms handler start");
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+
+    state::require_accepted_currency(&reit_accepted_currency)?;
+
+    // A zero cap means uncapped; otherwise it must leave room for the minimum
+    if close_at < 0 || (max_raise != 0 && max_raise < min_raise) {
+        debug_msg!("ERROR: Invalid raise terms (min {}, max {}, close_at {})", min_raise, max_raise, close_at);
+        return Err(error!(crate::errors::CustomError::InvalidAmount));
+    }
+
+    let fundraiser = &mut ctx.accounts.fundraiser;
+
+    // Terms are fixed once the fundraiser leaves Draft
+    if fundraiser.status != state::FundraiserStatus::Draft {
+        debug_msg!("ERROR: Fundraiser is {:?}", fundraiser.status);
+        return Err(error!(crate::errors::CustomError::FundraiserTermsLocked));
+    }
+
+    debug_msg!("Currency: {:?} -> {:?}", fundraiser.reit_accepted_currency, reit_accepted_currency);
+    debug_msg!("Raise: min {} max {} close_at {}", min_raise, max_raise, close_at);
+    fundraiser.reit_accepted_currency = reit_accepted_currency;
+    fundraiser.min_raise = min_raise;
+    fundraiser.max_raise = max_raise;
+    fundraiser.close_at = close_at;
+
+    debug_msg!("UpdateFundraiserTerms handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16])]
+pub struct UpdateFundraiserTerms<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+}
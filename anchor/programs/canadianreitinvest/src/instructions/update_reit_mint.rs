@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token_interface::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_2022::state::Mint as SplMint;
+use anchor_spl::token_interface::spl_token_metadata_interface::state::{Field, TokenMetadata};
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenMetadataUpdateField};
 use mpl_token_metadata::instructions::UpdateV1CpiBuilder;
 
 #[derive(Clone)]
@@ -20,16 +24,24 @@ pub fn handler(
     symbol: String,
     metadata_uri: String,
 ) -> Result<()> {
-    msg!("Update mint handler start");
-    msg!("Admin: {}", ctx.accounts.admin.key());
-    msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
-    msg!("Mint: {}", ctx.accounts.reit_mint.key());
-    msg!("Name: {}, Symbol: {}", name, symbol);
-    msg!("Metadata URI: {}", metadata_uri);
+    debug_msg!("Update mint handler start");
+    ctx.accounts.fundraiser.require_status(&[
+        state::FundraiserStatus::Draft,
+        state::FundraiserStatus::Open,
+        state::FundraiserStatus::Closed,
+        state::FundraiserStatus::Funded,
+        state::FundraiserStatus::Operating,
+        state::FundraiserStatus::WindingDown,
+    ])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Mint: {}", ctx.accounts.reit_mint.key());
+    debug_msg!("Name: {}, Symbol: {}", name, symbol);
+    debug_msg!("Metadata URI: {}", metadata_uri);
 
     // Verify admin is the signer and matches fundraiser admin
     if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
-        msg!(
+        debug_msg!(
             "ERROR: Admin {} is not the fundraiser admin {}",
             ctx.accounts.admin.key(),
             ctx.accounts.fundraiser.admin
@@ -39,7 +51,7 @@ pub fn handler(
 
     // Verify the mint address matches what's stored in the fundraiser
     if ctx.accounts.reit_mint.key() != ctx.accounts.fundraiser.reit_mint {
-        msg!(
+        debug_msg!(
             "ERROR: Mint {} does not match fundraiser mint {}",
             ctx.accounts.reit_mint.key(),
             ctx.accounts.fundraiser.reit_mint
@@ -47,31 +59,109 @@ pub fn handler(
         return Err(error!(crate::errors::CustomError::InvalidMint));
     }
 
-    // Update metadata using Metaplex Token Metadata program
-    // Note: UpdateV1 uses the Data struct to update fields like name, symbol, and uri
-    let data = mpl_token_metadata::types::Data {
+    if ctx.accounts.fundraiser.reit_token_program == anchor_spl::token_2022::ID {
+        update_token_2022_metadata(&ctx, &name, &symbol, &metadata_uri)?;
+    } else {
+        update_metaplex_metadata(&ctx, &name, &symbol, &metadata_uri)?;
+    }
+
+    debug_msg!("Token metadata updated successfully");
+    debug_msg!("REIT mint updated: {}", ctx.accounts.reit_mint.key());
+
+    emit!(ReitMetadataUpdated {
+        fundraiser: ctx.accounts.fundraiser.key(),
+        reit_mint: ctx.accounts.reit_mint.key(),
         name,
         symbol,
         uri: metadata_uri,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    debug_msg!("Update mint handler complete");
+
+    Ok(())
+}
+
+/// Classic SPL mints keep their metadata in a Metaplex metadata account.
+fn update_metaplex_metadata(ctx: &Context<UpdateReitMint>, name: &str, symbol: &str, metadata_uri: &str) -> Result<()> {
+    let (Some(metadata), Some(instructions_sysvar), Some(token_metadata_program)) = (
+        ctx.accounts.metadata.as_ref(),
+        ctx.accounts.instructions_sysvar.as_ref(),
+        ctx.accounts.token_metadata_program.as_ref(),
+    ) else {
+        debug_msg!("ERROR: Metaplex accounts are required to update a classic SPL mint");
+        return Err(error!(ErrorCode::AccountNotEnoughKeys));
+    };
+
+    // Update metadata using Metaplex Token Metadata program
+    // Note: UpdateV1 uses the Data struct to update fields like name, symbol, and uri
+    let data = mpl_token_metadata::types::Data {
+        name: name.to_string(),
+        symbol: symbol.to_string(),
+        uri: metadata_uri.to_string(),
         seller_fee_basis_points: 0,
         creators: None,
     };
 
-    UpdateV1CpiBuilder::new(&ctx.accounts.token_metadata_program)
-        .metadata(&ctx.accounts.metadata.to_account_info())
+    UpdateV1CpiBuilder::new(token_metadata_program)
+        .metadata(&metadata.to_account_info())
         .mint(&ctx.accounts.reit_mint.to_account_info())
         .authority(&ctx.accounts.admin.to_account_info())
         .new_update_authority(ctx.accounts.admin.key())
         .payer(&ctx.accounts.admin.to_account_info())
         .system_program(&ctx.accounts.system_program.to_account_info())
-        .sysvar_instructions(&ctx.accounts.instructions_sysvar.to_account_info())
+        .sysvar_instructions(&instructions_sysvar.to_account_info())
         .data(data)
         .invoke()?;
+    Ok(())
+}
 
-    msg!("Token metadata updated successfully");
-    msg!("REIT mint updated: {}", ctx.accounts.reit_mint.key());
-    msg!("Update mint handler complete");
+/// Token-2022 mints keep their metadata in the mint's own metadata extension.
+fn update_token_2022_metadata(ctx: &Context<UpdateReitMint>, name: &str, symbol: &str, metadata_uri: &str) -> Result<()> {
+    let mint_info = ctx.accounts.reit_mint.to_account_info();
+
+    // Longer fields realloc the mint, so fund the extra rent up front
+    let new_len = {
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<SplMint>::unpack(&data)?;
+        let current = mint.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = current.tlv_size_of()?;
+        let updated = TokenMetadata {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: metadata_uri.to_string(),
+            ..current
+        };
+        data.len() - current_size + updated.tlv_size_of()?
+    };
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(mint_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: mint_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
 
+    for (field, value) in [(Field::Name, name), (Field::Symbol, symbol), (Field::Uri, metadata_uri)] {
+        token_interface::token_metadata_update_field(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: ctx.accounts.admin.to_account_info(),
+                },
+            ),
+            field,
+            value.to_string(),
+        )?;
+    }
     Ok(())
 }
 
@@ -90,16 +180,29 @@ pub struct UpdateReitMint<'info> {
 
     /// CHECK: Verified that this is the fundraiser's REIT mint
     #[account(mut)]
-    pub reit_mint: Account<'info, Mint>,
+    pub reit_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(constraint = token_program.key() == fundraiser.reit_token_program @ crate::errors::CustomError::InvalidMint)]
+    pub token_program: Interface<'info, TokenInterface>,
 
     pub system_program: Program<'info, System>,
 
-    /// CHECK: Sysvar instructions account required by Metaplex UpdateV1
-    pub instructions_sysvar: UncheckedAccount<'info>,
+    /// CHECK: Sysvar instructions account required by Metaplex UpdateV1; classic SPL mints only
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
 
-    /// CHECK: Metaplex Token Metadata program will validate this
+    /// CHECK: Metaplex Token Metadata program will validate this; classic SPL mints only
     #[account(mut)]
-    pub metadata: UncheckedAccount<'info>,
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    pub token_metadata_program: Option<Program<'info, MetaplexTokenMetadata>>,
+}
 
-    pub token_metadata_program: Program<'info, MetaplexTokenMetadata>,
+#[event]
+pub struct ReitMetadataUpdated {
+    pub fundraiser: Pubkey,
+    pub reit_mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
 }
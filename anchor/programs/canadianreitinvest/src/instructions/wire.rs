@@ -2,33 +2,70 @@ use anchor_lang::prelude::*;
 
 use crate::state;
 
-pub fn handler(ctx: Context<Wire>, reit_id_hash: [u8; 16]) -> Result<()> {
-    msg!("Wire handler start");
-    msg!("Admin: {}", ctx.accounts.admin.key());
-    msg!("Investment: {}", ctx.accounts.investment.key());
-    msg!("REIT ID hash: {:?}", reit_id_hash);
+pub fn handler(
+    ctx: Context<Wire>,
+    reit_id_hash: [u8; 16],
+    wired_amount: u64,
+    fx_rate: u64,
+    wire_date: i64,
+    wire_reference_hash: [u8; 32],
+) -> Result<()> {
+    debug_msg!("Wire handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Funded, state::FundraiserStatus::Operating])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Investment: {}", ctx.accounts.investment.key());
+    debug_msg!("REIT ID hash: {:?}", reit_id_hash);
+    debug_msg!("Wired amount: {}, FX rate: {}, Wire date: {}", wired_amount, fx_rate, wire_date);
 
     let investment = &mut ctx.accounts.investment;
-    msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
+    debug_msg!("Investment data - Investor: {}, Amount: {}, Status: {:?}", investment.investor, investment.usdc_amount, investment.status);
 
-    // Verify investment is in released status
-    if investment.status != state::InvestmentStatus::Released {
-        msg!("ERROR: Investment status is not released. Current status: {:?}", investment.status);
-        return Err(error!(crate::errors::CustomError::InvalidInvestmentStatus));
-    }
+    investment.status.require_transition_to(state::InvestmentStatus::Wired)?;
 
     // Verify admin is the signer and matches fundraiser admin
     if ctx.accounts.admin.key() != ctx.accounts.fundraiser.admin {
-        msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
+        debug_msg!("ERROR: Admin {} is not the fundraiser admin {}", ctx.accounts.admin.key(), ctx.accounts.fundraiser.admin);
         return Err(error!(crate::errors::CustomError::InvalidAuthority));
     }
 
-    // Update investment status to Wired
-    investment.status = state::InvestmentStatus::Wired;
-    msg!("Investment status updated to Wired (status: {:?})", investment.status);
+    let now = Clock::get()?.unix_timestamp;
+    validate_wire_details(wired_amount, fx_rate, wire_date, now)?;
+
+    // Record the bank wire and update investment status to Wired
+    investment.wired_amount = wired_amount;
+    investment.fx_rate = fx_rate;
+    investment.wire_date = wire_date;
+    investment.wire_reference_hash = wire_reference_hash;
+    investment.currency = ctx.accounts.fundraiser.reit_accepted_currency;
+    let old_status = investment.transition(state::InvestmentStatus::Wired, now, ctx.accounts.admin.key())?;
+    debug_msg!("Investment status updated to Wired (status: {:?})", investment.status);
+
+    emit!(InvestmentWired {
+        investment: investment.key(),
+        investor: investment.investor,
+        fundraiser: ctx.accounts.fundraiser.key(),
+        old_status,
+        new_status: investment.status,
+        usdc_amount: investment.usdc_amount,
+        wired_amount,
+        currency: ctx.accounts.fundraiser.reit_accepted_currency,
+        fx_rate,
+        wire_date,
+        wire_reference_hash,
+        timestamp: now,
+    });
 
-    msg!("Wire handler complete - transaction successful");
+    debug_msg!("Wire handler complete - transaction successful");
+
+    Ok(())
+}
 
+/// The wire must have actually moved money, at a real rate, and already settled.
+pub fn validate_wire_details(wired_amount: u64, fx_rate: u64, wire_date: i64, now: i64) -> Result<()> {
+    if wired_amount == 0 || fx_rate == 0 || wire_date > now {
+        debug_msg!("ERROR: Invalid wire details (amount: {}, rate: {}, date: {}, now: {})", wired_amount, fx_rate, wire_date, now);
+        return Err(error!(crate::errors::CustomError::InvalidWireDetails));
+    }
     Ok(())
 }
 
@@ -47,4 +84,22 @@ pub struct Wire<'info> {
 
     #[account(mut, constraint = investment.fundraiser == fundraiser.key())]
     pub investment: Account<'info, state::Investment>,
-}
\ No newline at end of file
+}
+
+/// Event emitted when the bank wire for an investment is recorded
+/// Makes the stablecoin to fiat conversion auditable on-chain
+#[event]
+pub struct InvestmentWired {
+    pub investment: Pubkey,
+    pub investor: Pubkey,
+    pub fundraiser: Pubkey,
+    pub old_status: state::InvestmentStatus,
+    pub new_status: state::InvestmentStatus,
+    pub usdc_amount: u64,
+    pub wired_amount: u64,
+    pub currency: [u8; 3],
+    pub fx_rate: u64,
+    pub wire_date: i64,
+    pub wire_reference_hash: [u8; 32],
+    pub timestamp: i64,
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::wire::{validate_wire_details, InvestmentWired};
+use crate::state::{self, Versioned};
+
+/// Records one bank wire covering every Released investment passed as a remaining account.
+/// The wired fiat is allocated pro-rata to each investment's usdc_amount; rounding dust goes
+/// to the last investment so the allocations always sum to `wired_amount`. Every investment
+/// must have been deposited in the same stablecoin, the one the wire converted.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WireBatch<'info>>,
+    _reit_id_hash: [u8; 16],
+    wired_amount: u64,
+    fx_rate: u64,
+    wire_date: i64,
+    wire_reference_hash: [u8; 32],
+) -> Result<()> {
+    debug_msg!("WireBatch handler start");
+    ctx.accounts.fundraiser.require_status(&[state::FundraiserStatus::Funded, state::FundraiserStatus::Operating])?;
+    debug_msg!("Admin: {}", ctx.accounts.admin.key());
+    debug_msg!("Fundraiser: {}", ctx.accounts.fundraiser.key());
+    debug_msg!("Wired amount: {}, FX rate: {}, Wire date: {}, Investments: {}", wired_amount, fx_rate, wire_date, ctx.remaining_accounts.len());
+
+    let now = Clock::get()?.unix_timestamp;
+    validate_wire_details(wired_amount, fx_rate, wire_date, now)?;
+
+    let count = ctx.remaining_accounts.len();
+    if count == 0 || count > state::MAX_WIRE_BATCH_INVESTMENTS {
+        debug_msg!("ERROR: Batch must cover 1 to {} investments, got {}", state::MAX_WIRE_BATCH_INVESTMENTS, count);
+        return Err(error!(crate::errors::CustomError::InvalidWireBatch));
+    }
+
+    // Load and validate every investment before touching any of them
+    let fundraiser_key = ctx.accounts.fundraiser.key();
+    let mut investments: Vec<Account<'info, state::Investment>> = Vec::with_capacity(count);
+    let mut usdc_total: u64 = 0;
+    for account_info in ctx.remaining_accounts.iter() {
+        if !account_info.is_writable {
+            debug_msg!("ERROR: Investment {} must be writable", account_info.key());
+            return Err(error!(crate::errors::CustomError::InvalidWireBatch));
+        }
+        if investments.iter().any(|investment| investment.key() == account_info.key()) {
+            debug_msg!("ERROR: Investment {} listed more than once", account_info.key());
+            return Err(error!(crate::errors::CustomError::InvalidWireBatch));
+        }
+        let investment = Account::<state::Investment>::try_from(account_info)?;
+        if investment.fundraiser != fundraiser_key {
+            debug_msg!("ERROR: Investment {} belongs to fundraiser {}", account_info.key(), investment.fundraiser);
+            return Err(error!(crate::errors::CustomError::InvalidFundraiserMismatch));
+        }
+        if let Some(first) = investments.first() {
+            if investment.deposit_mint != first.deposit_mint {
+                debug_msg!("ERROR: Investment {} was deposited in {}, batch is in {}", account_info.key(), investment.deposit_mint, first.deposit_mint);
+                return Err(error!(crate::errors::CustomError::InvalidWireBatch));
+            }
+        }
+        investment.status.require_transition_to(state::InvestmentStatus::Wired)?;
+        usdc_total = usdc_total
+            .checked_add(investment.usdc_amount)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        investments.push(investment);
+    }
+    if usdc_total == 0 {
+        return Err(error!(crate::errors::CustomError::InvalidWireBatch));
+    }
+    let deposit_mint = investments[0].deposit_mint;
+
+    // Allocate the wired fiat pro-rata and mark every investment Wired
+    let mut allocated: u64 = 0;
+    for (index, investment) in investments.iter_mut().enumerate() {
+        let share = if index == count - 1 {
+            wired_amount - allocated
+        } else {
+            ((wired_amount as u128) * (investment.usdc_amount as u128) / (usdc_total as u128)) as u64
+        };
+        allocated = allocated
+            .checked_add(share)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+
+        investment.wired_amount = share;
+        investment.fx_rate = fx_rate;
+        investment.wire_date = wire_date;
+        investment.wire_reference_hash = wire_reference_hash;
+        investment.currency = ctx.accounts.fundraiser.reit_accepted_currency;
+        let old_status = investment.transition(state::InvestmentStatus::Wired, now, ctx.accounts.admin.key())?;
+        investment.exit(ctx.program_id)?;
+        debug_msg!("Investment {} wired: {} (usdc: {})", investment.key(), share, investment.usdc_amount);
+
+        emit!(InvestmentWired {
+            investment: investment.key(),
+            investor: investment.investor,
+            fundraiser: fundraiser_key,
+            old_status,
+            new_status: investment.status,
+            usdc_amount: investment.usdc_amount,
+            wired_amount: share,
+            currency: ctx.accounts.fundraiser.reit_accepted_currency,
+            fx_rate,
+            wire_date,
+            wire_reference_hash,
+            timestamp: now,
+        });
+    }
+
+    let wire_batch = &mut ctx.accounts.wire_batch;
+    wire_batch.fundraiser = fundraiser_key;
+    wire_batch.wired_amount = wired_amount;
+    wire_batch.usdc_amount = usdc_total;
+    wire_batch.fx_rate = fx_rate;
+    wire_batch.wire_date = wire_date;
+    wire_batch.wire_reference_hash = wire_reference_hash;
+    wire_batch.investments = investments.iter().map(|investment| investment.key()).collect();
+    wire_batch.version = state::WireBatchRecord::VERSION;
+    wire_batch.bump = ctx.bumps.wire_batch;
+
+    emit!(WireBatchRecorded {
+        wire_batch: wire_batch.key(),
+        fundraiser: fundraiser_key,
+        investment_count: count as u32,
+        deposit_mint,
+        usdc_amount: usdc_total,
+        wired_amount,
+        currency: ctx.accounts.fundraiser.reit_accepted_currency,
+        fx_rate,
+        wire_date,
+        wire_reference_hash,
+        timestamp: now,
+    });
+
+    debug_msg!("WireBatch handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(reit_id_hash: [u8; 16], wired_amount: u64, fx_rate: u64, wire_date: i64, wire_reference_hash: [u8; 32])]
+pub struct WireBatch<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"fundraiser", reit_id_hash.as_slice()],
+        bump = fundraiser.bump,
+        constraint = admin.key() == fundraiser.admin @ crate::errors::CustomError::InvalidAuthority
+    )]
+    pub fundraiser: Account<'info, state::Fundraiser>,
+
+    /// One record per bank wire; reusing a wire reference fails on init
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + state::WireBatchRecord::INIT_SPACE,
+        seeds = [b"wire_batch", fundraiser.key().as_ref(), wire_reference_hash.as_ref()],
+        bump
+    )]
+    pub wire_batch: Account<'info, state::WireBatchRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Event emitted when a batch wire settles several investments at once
+#[event]
+pub struct WireBatchRecorded {
+    pub wire_batch: Pubkey,
+    pub fundraiser: Pubkey,
+    pub investment_count: u32,
+    pub deposit_mint: Pubkey, // Stablecoin every investment in the batch was deposited in
+    pub usdc_amount: u64,
+    pub wired_amount: u64,
+    pub currency: [u8; 3],
+    pub fx_rate: u64,
+    pub wire_date: i64,
+    pub wire_reference_hash: [u8; 32],
+    pub timestamp: i64,
+}
@@ -2,10 +2,46 @@ use anchor_lang::prelude::*;
 
 declare_id!("FuEhMFWU9Ui35a9mpavfy7AYGqEX8diUSk1CZonEUivH");
 
-mod state;
-mod errors;
+/// `msg!` that only logs when built with the `debug-logs` feature. Without it the call is
+/// dead code and optimized out, so release builds log nothing but Anchor events and errors.
+macro_rules! debug_msg {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "debug-logs") {
+            anchor_lang::prelude::msg!($($arg)*);
+        }
+    };
+}
+
+pub mod state;
+pub mod errors;
 mod instructions;
 
+/// Earlier account layouts the `migrate_*` instructions read, re-exported for off-chain tools
+pub mod legacy {
+    pub use crate::instructions::migrate::{FundraiserV0, InvestmentV0, InvestorFundraiserV0, InvestorFundraiserV1, InvestorV0};
+}
+
+/// Every event the program emits, re-exported for off-chain decoders
+pub mod events {
+    pub use crate::instructions::close_investment::InvestmentReceipt;
+    pub use crate::instructions::create_reit_mint::ReitMintCreated;
+    pub use crate::instructions::freeze_holder::HolderFrozen;
+    pub use crate::instructions::initialize::FundraiserInitialized;
+    pub use crate::instructions::invest::InvestmentCreated;
+    pub use crate::instructions::migrate::AccountMigrated;
+    pub use crate::instructions::issue_dividend::DividendIssued;
+    pub use crate::instructions::issue_share::SharesIssued;
+    pub use crate::instructions::recover_position::PositionRecovered;
+    pub use crate::instructions::record_share_sale::SharesSold;
+    pub use crate::instructions::refund::InvestmentRefunded;
+    pub use crate::instructions::release::InvestmentReleased;
+    pub use crate::instructions::set_fundraiser_status::FundraiserStatusChanged;
+    pub use crate::instructions::thaw_holder::HolderThawed;
+    pub use crate::instructions::update_reit_mint::ReitMetadataUpdated;
+    pub use crate::instructions::wire::InvestmentWired;
+    pub use crate::instructions::wire_batch::WireBatchRecorded;
+}
+
 use instructions::initialize::*;
 use instructions::invest::*;
 use instructions::initialize_investor::*;
@@ -17,13 +53,93 @@ use instructions::create_reit_mint::*;
 use instructions::update_reit_mint::*;
 use instructions::issue_share::*;
 use instructions::issue_dividend::*;
+use instructions::initialize_compliance::*;
+use instructions::set_compliance_authority::*;
+use instructions::attest_investor::*;
+use instructions::set_offering_exemption::*;
+use instructions::set_jurisdiction_rules::*;
+use instructions::add_jurisdiction_rule::*;
+use instructions::remove_jurisdiction_rule::*;
+use instructions::create_reit_mint_2022::*;
+use instructions::unlock_shares::*;
+use instructions::set_hold_period::*;
+use instructions::freeze_holder::*;
+use instructions::thaw_holder::*;
+use instructions::recover_position::*;
+use instructions::record_share_sale::*;
+use instructions::wire_batch::*;
+use instructions::initialize_fx_rate::*;
+use instructions::publish_fx_rate::*;
+use instructions::set_fx_rate_publisher::*;
+use instructions::update_fundraiser_terms::*;
+use instructions::add_accepted_mint::*;
+use instructions::set_fundraiser_status::*;
+use instructions::close_fundraiser::*;
+use instructions::close_investment::*;
+use instructions::migrate::*;
 
 #[program]
 pub mod canadianreitinvest {
     use super::*;
 
-    pub fn initialize_fundraiser(ctx: Context<InitializeFundraiser>, reit_id: String, reit_id_hash: [u8; 16]) -> Result<()> {
-        instructions::initialize::handler(ctx, reit_id, reit_id_hash)
+    pub fn initialize_fundraiser(ctx: Context<InitializeFundraiser>, reit_id: String, reit_id_hash: [u8; 16], reit_accepted_currency: [u8; 3]) -> Result<()> {
+        instructions::initialize::handler(ctx, reit_id, reit_id_hash, reit_accepted_currency)
+    }
+
+    pub fn update_fundraiser_terms(ctx: Context<UpdateFundraiserTerms>, reit_id_hash: [u8; 16], reit_accepted_currency: [u8; 3], min_raise: u64, max_raise: u64, close_at: i64) -> Result<()> {
+        instructions::update_fundraiser_terms::handler(ctx, reit_id_hash, reit_accepted_currency, min_raise, max_raise, close_at)
+    }
+
+    pub fn set_fundraiser_status(ctx: Context<SetFundraiserStatus>, reit_id_hash: [u8; 16], status: state::FundraiserStatus) -> Result<()> {
+        instructions::set_fundraiser_status::handler(ctx, reit_id_hash, status)
+    }
+
+    pub fn close_fundraiser<'info>(ctx: Context<'_, '_, 'info, 'info, CloseFundraiser<'info>>, reit_id_hash: [u8; 16]) -> Result<()> {
+        instructions::close_fundraiser::handler(ctx, reit_id_hash)
+    }
+
+    pub fn close_investment(ctx: Context<CloseInvestment>, emit_receipt: bool) -> Result<()> {
+        instructions::close_investment::handler(ctx, emit_receipt)
+    }
+
+    pub fn migrate_fundraiser(ctx: Context<Migrate>, reit_id: String) -> Result<()> {
+        instructions::migrate::fundraiser_handler(ctx, reit_id)
+    }
+
+    pub fn migrate_investor(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::Investor>(ctx, instructions::migrate::older_investor)
+    }
+
+    pub fn migrate_investor_fundraiser(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::InvestorFundraiser>(ctx, instructions::migrate::older_investor_fundraiser)
+    }
+
+    pub fn migrate_investment(ctx: Context<MigrateInvestment>) -> Result<()> {
+        instructions::migrate::investment_handler(ctx)
+    }
+
+    pub fn migrate_compliance_config(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::ComplianceConfig>(ctx, instructions::migrate::no_older_layouts)
+    }
+
+    pub fn migrate_kyc_attestation(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::KycAttestation>(ctx, instructions::migrate::no_older_layouts)
+    }
+
+    pub fn migrate_holder_lockup(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::HolderLockup>(ctx, instructions::migrate::no_older_layouts)
+    }
+
+    pub fn migrate_wire_batch_record(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::WireBatchRecord>(ctx, instructions::migrate::no_older_layouts)
+    }
+
+    pub fn migrate_fx_rate(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate::handler::<state::FxRate>(ctx, instructions::migrate::no_older_layouts)
+    }
+
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>, reit_id_hash: [u8; 16], currency: [u8; 3]) -> Result<()> {
+        instructions::add_accepted_mint::handler(ctx, reit_id_hash, currency)
     }
 
     pub fn initialize_investor(ctx: Context<InitializeInvestor>) -> Result<()> {
@@ -46,14 +162,36 @@ pub mod canadianreitinvest {
         instructions::refund::handler(ctx, reit_id_hash)
     }
 
-    pub fn wire(ctx: Context<Wire>, reit_id_hash: [u8; 16]) -> Result<()> {
-        instructions::wire::handler(ctx, reit_id_hash)
+    pub fn wire(
+        ctx: Context<Wire>,
+        reit_id_hash: [u8; 16],
+        wired_amount: u64,
+        fx_rate: u64,
+        wire_date: i64,
+        wire_reference_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::wire::handler(ctx, reit_id_hash, wired_amount, fx_rate, wire_date, wire_reference_hash)
+    }
+
+    pub fn wire_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WireBatch<'info>>,
+        reit_id_hash: [u8; 16],
+        wired_amount: u64,
+        fx_rate: u64,
+        wire_date: i64,
+        wire_reference_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::wire_batch::handler(ctx, reit_id_hash, wired_amount, fx_rate, wire_date, wire_reference_hash)
     }
 
     pub fn create_reit_mint(ctx: Context<CreateReitMint>, reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
         instructions::create_reit_mint::handler(ctx, reit_id_hash, name, symbol, metadata_uri)
     }
 
+    pub fn create_reit_mint_2022(ctx: Context<CreateReitMint2022>, reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
+        instructions::create_reit_mint_2022::handler(ctx, reit_id_hash, name, symbol, metadata_uri)
+    }
+
     pub fn update_reit_mint(ctx: Context<UpdateReitMint>, reit_id_hash: [u8; 16], name: String, symbol: String, metadata_uri: String) -> Result<()> {
         instructions::update_reit_mint::handler(ctx, reit_id_hash, name, symbol, metadata_uri)
     }
@@ -62,7 +200,107 @@ pub mod canadianreitinvest {
         instructions::issue_share::handler(ctx, investor_pubkey, reit_id_hash, share_price)
     }
 
+    pub fn unlock_shares(ctx: Context<UnlockShares>, reit_id_hash: [u8; 16]) -> Result<()> {
+        instructions::unlock_shares::handler(ctx, reit_id_hash)
+    }
+
+    pub fn set_hold_period(ctx: Context<SetHoldPeriod>, reit_id_hash: [u8; 16], hold_period_seconds: i64) -> Result<()> {
+        instructions::set_hold_period::handler(ctx, reit_id_hash, hold_period_seconds)
+    }
+
     pub fn issue_dividend(ctx: Context<IssueDividend>, amount: u64) -> Result<()> {
         instructions::issue_dividend::handler(ctx, amount)
     }
+
+    pub fn initialize_compliance(ctx: Context<InitializeCompliance>, authority: Pubkey) -> Result<()> {
+        instructions::initialize_compliance::handler(ctx, authority)
+    }
+
+    pub fn set_compliance_authority(ctx: Context<SetComplianceAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::set_compliance_authority::handler(ctx, new_authority)
+    }
+
+    pub fn attest_investor(
+        ctx: Context<AttestInvestor>,
+        investor_wallet: Pubkey,
+        status: state::KycStatus,
+        jurisdiction: state::Jurisdiction,
+        expires_at: i64,
+        accreditation: state::AccreditationCategory,
+        kyc_record_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::attest_investor::handler(ctx, investor_wallet, status, jurisdiction, expires_at, accreditation, kyc_record_hash)
+    }
+
+    pub fn freeze_holder(ctx: Context<FreezeHolder>, reit_id_hash: [u8; 16], legal_reference_hash: [u8; 32]) -> Result<()> {
+        instructions::freeze_holder::handler(ctx, reit_id_hash, legal_reference_hash)
+    }
+
+    pub fn thaw_holder(ctx: Context<ThawHolder>, reit_id_hash: [u8; 16], legal_reference_hash: [u8; 32]) -> Result<()> {
+        instructions::thaw_holder::handler(ctx, reit_id_hash, legal_reference_hash)
+    }
+
+    pub fn recover_position(ctx: Context<RecoverPosition>, reit_id_hash: [u8; 16], new_owner: Pubkey, legal_reference_hash: [u8; 32]) -> Result<()> {
+        instructions::recover_position::handler(ctx, reit_id_hash, new_owner, legal_reference_hash)
+    }
+
+    pub fn record_share_sale(ctx: Context<RecordShareSale>, reit_id_hash: [u8; 16]) -> Result<()> {
+        instructions::record_share_sale::handler(ctx, reit_id_hash)
+    }
+
+    pub fn initialize_fx_rate(
+        ctx: Context<InitializeFxRate>,
+        base_currency: [u8; 3],
+        quote_currency: [u8; 3],
+        publisher: Pubkey,
+        max_staleness_seconds: i64,
+    ) -> Result<()> {
+        instructions::initialize_fx_rate::handler(ctx, base_currency, quote_currency, publisher, max_staleness_seconds)
+    }
+
+    pub fn publish_fx_rate(
+        ctx: Context<PublishFxRate>,
+        base_currency: [u8; 3],
+        quote_currency: [u8; 3],
+        rate: u64,
+        confidence: u64,
+        published_at: i64,
+    ) -> Result<()> {
+        instructions::publish_fx_rate::handler(ctx, base_currency, quote_currency, rate, confidence, published_at)
+    }
+
+    pub fn set_fx_rate_publisher(
+        ctx: Context<SetFxRatePublisher>,
+        base_currency: [u8; 3],
+        quote_currency: [u8; 3],
+        new_publisher: Pubkey,
+    ) -> Result<()> {
+        instructions::set_fx_rate_publisher::handler(ctx, base_currency, quote_currency, new_publisher)
+    }
+
+    pub fn set_offering_exemption(
+        ctx: Context<SetOfferingExemption>,
+        reit_id_hash: [u8; 16],
+        exemption: state::OfferingExemption,
+        investment_limits: state::InvestmentLimits,
+    ) -> Result<()> {
+        instructions::set_offering_exemption::handler(ctx, reit_id_hash, exemption, investment_limits)
+    }
+
+    pub fn set_jurisdiction_rules(
+        ctx: Context<SetJurisdictionRules>,
+        reit_id_hash: [u8; 16],
+        mode: state::JurisdictionMode,
+        rules: Vec<state::Jurisdiction>,
+    ) -> Result<()> {
+        instructions::set_jurisdiction_rules::handler(ctx, reit_id_hash, mode, rules)
+    }
+
+    pub fn add_jurisdiction_rule(ctx: Context<AddJurisdictionRule>, reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Result<()> {
+        instructions::add_jurisdiction_rule::handler(ctx, reit_id_hash, jurisdiction)
+    }
+
+    pub fn remove_jurisdiction_rule(ctx: Context<RemoveJurisdictionRule>, reit_id_hash: [u8; 16], jurisdiction: state::Jurisdiction) -> Result<()> {
+        instructions::remove_jurisdiction_rule::handler(ctx, reit_id_hash, jurisdiction)
+    }
 }
@@ -1,16 +1,358 @@
 use anchor_lang::prelude::*;
 
+/// Layout version of a program account, stored as its first field. Bump it whenever the
+/// layout changes and teach the account's `migrate_*` instruction to read the old one.
+pub trait Versioned {
+    const VERSION: u8;
+}
+
+/// Bytes reserved at the end of every account, so new fields can be added without a realloc.
+pub const RESERVED_SPACE: usize = 64;
+
+impl Versioned for Fundraiser {
+    // The deployed baseline (`migrate::FundraiserV0`) is unversioned; 1 adds the version byte,
+    // token program, offering terms, accepted mints, REIT id, status, counts and reserved space
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for Investor {
+    // The deployed baseline (`migrate::InvestorV0`) is unversioned; 1 adds the version byte,
+    // rolling total, open count and reserved space
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for InvestorFundraiser {
+    // The deployed baseline (`migrate::InvestorFundraiserV0`) is unversioned; 1 adds the version
+    // byte, account count and reserved space; 2 (`migrate::InvestorFundraiserV1` before) adds the
+    // rolling total
+    const VERSION: u8 = 2;
+}
+
+impl Versioned for Investment {
+    // The deployed baseline (`migrate::InvestmentV0`) is unversioned; 1 adds the version byte,
+    // wire and share details, deposit mint, timeline and reserved space
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for ComplianceConfig {
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for KycAttestation {
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for HolderLockup {
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for WireBatchRecord {
+    const VERSION: u8 = 1;
+}
+
+impl Versioned for FxRate {
+    const VERSION: u8 = 1;
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Fundraiser {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
     pub admin: Pubkey, // The admin public key who initializes and manages the fundraiser
     pub usdc_mint: Pubkey, // The USDC mint public key for validation of the escrow vault
     pub reit_mint: Pubkey, // The REIT token mint public key (set when REIT tokens are created)
+    pub reit_token_program: Pubkey, // Token program owning reit_mint (SPL Token or Token-2022)
     pub escrow_vault: Pubkey, // The escrow token account that holds USDC investments
     pub total_raised: u64, // Total USDC raised from all investments (aggregate for onchain efficiency)
-    pub released_amount: u64, // Total USDC released to the admin for CAD conversion (aggregate for onchain efficiency)
+    pub released_amount: u64, // Total USDC released to the admin for CAD conversion, net of refunds (aggregate for onchain efficiency)
     pub bump: u8, // PDA bump seed for the fundraiser account
     pub reit_accepted_currency: [u8; 3], // Currency code for the REIT (e.g., "CAD") - changed from String
+    pub exemption: OfferingExemption, // Prospectus exemption the offering is distributed under
+    pub investment_limits: InvestmentLimits, // Per-category rolling 12-month limits (OfferingMemorandum only)
+    pub jurisdiction_mode: JurisdictionMode, // Whether jurisdiction_rules is an allowlist or a denylist
+    #[max_len(MAX_JURISDICTION_RULES)]
+    pub jurisdiction_rules: Vec<Jurisdiction>, // Jurisdictions checked against the investor's attested jurisdiction
+    pub hold_period_seconds: i64, // Resale hold period applied to each share issuance
+    #[max_len(MAX_ACCEPTED_MINTS)]
+    pub accepted_mints: Vec<AcceptedMint>, // Stablecoins accepted for investment, each with its own escrow vault
+    #[max_len(REIT_ID_LEN)]
+    pub reit_id: String, // REIT UUID in canonical text form; reit_id_hash is its 16 raw bytes
+    pub status: FundraiserStatus, // Lifecycle state gating which instructions may run
+    pub min_raise: u64, // Minimum total_raised for the raise to be Funded (0 = no minimum)
+    pub max_raise: u64, // Cap on total_raised while Open (0 = uncapped)
+    pub close_at: i64, // Deadline after which invest is rejected (0 = no deadline)
+    pub investment_count: u64, // Investments ever created against this fundraiser
+    pub open_investment_count: u64, // Investments in an open status (InvestmentStatus::is_open)
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+impl Fundraiser {
+    /// Returns the rolling 12-month investment limit for an investor of `category`,
+    /// `None` when uncapped. Fails if the category may not invest under this exemption.
+    pub fn investment_limit(&self, category: AccreditationCategory) -> Result<Option<u64>> {
+        match self.exemption {
+            OfferingExemption::Prospectus => Ok(None),
+            OfferingExemption::AccreditedInvestor => {
+                if category != AccreditationCategory::Accredited {
+                    debug_msg!("ERROR: Offering is limited to accredited investors (investor category: {:?})", category);
+                    return Err(error!(crate::errors::CustomError::InvestorNotEligible));
+                }
+                Ok(None)
+            }
+            OfferingExemption::OfferingMemorandum => Ok(match category {
+                AccreditationCategory::NonEligible => Some(self.investment_limits.non_eligible),
+                AccreditationCategory::Eligible => Some(self.investment_limits.eligible),
+                AccreditationCategory::Accredited => None,
+            }),
+        }
+    }
+
+    /// Fails unless the fundraiser is in one of the `allowed` states.
+    pub fn require_status(&self, allowed: &[FundraiserStatus]) -> Result<()> {
+        if !allowed.contains(&self.status) {
+            debug_msg!("ERROR: Fundraiser is {:?}, expected one of {:?}", self.status, allowed);
+            return Err(error!(crate::errors::CustomError::InvalidFundraiserStatus));
+        }
+        Ok(())
+    }
+
+    /// Fails unless the fundraiser may still get its REIT mint: in a status before Operating and
+    /// with no mint yet. Shares are only ever minted from `reit_mint`, so while it is unset
+    /// nothing has been issued and no holder can be stranded on a replaced mint.
+    pub fn require_reit_mint_creatable(&self) -> Result<()> {
+        self.require_status(&[FundraiserStatus::Draft, FundraiserStatus::Open, FundraiserStatus::Closed, FundraiserStatus::Funded])?;
+        if self.reit_mint != Pubkey::default() {
+            debug_msg!("ERROR: Fundraiser already has REIT mint {}", self.reit_mint);
+            return Err(error!(crate::errors::CustomError::ReitMintAlreadyCreated));
+        }
+        Ok(())
+    }
+
+    /// Fails unless the admin may move the fundraiser from its current state to `next` at `now`.
+    pub fn require_transition(&self, next: FundraiserStatus, now: i64) -> Result<()> {
+        use FundraiserStatus::*;
+        let allowed = match (self.status, next) {
+            // Cannot open a raise whose deadline has already passed
+            (Draft, Open) => self.close_at == 0 || now < self.close_at,
+            (Draft, Terminated) => true,
+            (Open, Closed) => true,
+            (Closed, Funded) => self.total_raised >= self.min_raise,
+            (Closed, Failed) => self.total_raised < self.min_raise,
+            (Funded, Operating) => self.reit_mint != Pubkey::default(),
+            (Operating, WindingDown) => true,
+            (WindingDown, Terminated) | (Failed, Terminated) => true,
+            _ => false,
+        };
+        if !allowed {
+            debug_msg!("ERROR: Fundraiser cannot move from {:?} to {:?} (raised {}, min {}, close_at {})",
+                 self.status, next, self.total_raised, self.min_raise, self.close_at);
+            return Err(error!(crate::errors::CustomError::InvalidFundraiserTransition));
+        }
+        Ok(())
+    }
+
+    /// Records that one investment left the open statuses (see `InvestmentStatus::is_open`).
+    pub fn settle_investment(&mut self) -> Result<()> {
+        self.open_investment_count = self
+            .open_investment_count
+            .checked_sub(1)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        Ok(())
+    }
+
+    /// Returns the accepted stablecoin entry for `mint`, `None` if the mint is not accepted.
+    pub fn accepted_mint(&self, mint: &Pubkey) -> Option<&AcceptedMint> {
+        self.accepted_mints.iter().find(|accepted| accepted.mint == *mint)
+    }
+
+    /// Currency every accepted stablecoin is pegged to, that of the primary `usdc_mint`.
+    /// Deposit amounts in totals, caps and investment limits are base units of it.
+    pub fn deposit_currency(&self) -> [u8; 3] {
+        self.accepted_mints.first().map_or(*b"USD", |primary| primary.currency)
+    }
+
+    /// Returns the escrow vault holding deposits of `mint`, `None` if the mint is not accepted.
+    pub fn escrow_vault_for(&self, mint: &Pubkey) -> Option<Pubkey> {
+        self.accepted_mint(mint).map(|accepted| accepted.escrow_vault)
+    }
+
+    /// Fails if an investor from `jurisdiction` may not invest in this offering.
+    pub fn require_jurisdiction_allowed(&self, jurisdiction: &Jurisdiction) -> Result<()> {
+        let listed = self.jurisdiction_rules.iter().any(|rule| rule.covers(jurisdiction));
+        let allowed = match self.jurisdiction_mode {
+            JurisdictionMode::Allowlist => listed,
+            JurisdictionMode::Denylist => !listed,
+        };
+        if !allowed {
+            debug_msg!("ERROR: Investor jurisdiction {:?} is blocked by {:?} rules", jurisdiction, self.jurisdiction_mode);
+            return Err(error!(crate::errors::CustomError::JurisdictionBlocked));
+        }
+        Ok(())
+    }
+}
+
+/// Fundraiser lifecycle. Explicit discriminants for deterministic storage.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum FundraiserStatus {
+    Draft = 0, // Terms being configured, not accepting money
+    Open = 1, // Accepting investments until the cap or deadline
+    Closed = 2, // No longer accepting investments, outcome not yet decided
+    Funded = 3, // Minimum raise met; funds are released, wired and shares issued
+    Failed = 4, // Minimum raise missed; investments are refunded
+    Operating = 5, // Shares issued, REIT paying dividends
+    WindingDown = 6, // REIT being liquidated
+    Terminated = 7, // Final state
+}
+
+/// Length of a REIT id: a UUID in canonical hyphenated form.
+pub const REIT_ID_LEN: usize = 36;
+
+/// Computes the `reit_id_hash` PDA seed of `reit_id`: the 16 bytes of the UUID, as produced
+/// by `uuid.parse` in the client. Fails with `InvalidReitIdHash` if `reit_id` is not a
+/// canonical hyphenated UUID.
+pub fn reit_id_hash(reit_id: &str) -> Result<[u8; 16]> {
+    let invalid = || error!(crate::errors::CustomError::InvalidReitIdHash);
+    let text = reit_id.as_bytes();
+    if text.len() != REIT_ID_LEN {
+        return Err(invalid());
+    }
+
+    let mut nibbles = [0u8; 32];
+    let mut count = 0usize;
+    for (position, &c) in text.iter().enumerate() {
+        if matches!(position, 8 | 13 | 18 | 23) {
+            if c != b'-' {
+                return Err(invalid());
+            }
+            continue;
+        }
+        nibbles[count] = (c as char).to_digit(16).ok_or_else(invalid)? as u8;
+        count += 1;
+    }
+
+    let mut hash = [0u8; 16];
+    for (byte, pair) in hash.iter_mut().zip(nibbles.chunks_exact(2)) {
+        *byte = (pair[0] << 4) | pair[1];
+    }
+    Ok(hash)
+}
+
+/// Maximum number of stablecoin mints a fundraiser accepts, including `usdc_mint`.
+pub const MAX_ACCEPTED_MINTS: usize = 4;
+
+/// A stablecoin accepted by a fundraiser and the escrow vault its deposits are held in.
+/// `usdc_mint` is always the first entry, pegged to USD, and keeps its original vault.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct AcceptedMint {
+    pub mint: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub currency: [u8; 3], // ISO 4217 currency the stablecoin is pegged to; shares are priced in it
+}
+
+/// ISO 4217 currencies a REIT may be denominated in. Only currencies with two minor units
+/// are listed, since fiat amounts are stored in cents.
+pub const ACCEPTED_CURRENCIES: [[u8; 3]; 6] = [*b"CAD", *b"USD", *b"EUR", *b"GBP", *b"CHF", *b"AUD"];
+
+/// Fails unless `currency` is in `ACCEPTED_CURRENCIES`.
+pub fn require_accepted_currency(currency: &[u8; 3]) -> Result<()> {
+    if !ACCEPTED_CURRENCIES.contains(currency) {
+        debug_msg!("ERROR: Currency {:?} is not an accepted ISO 4217 code", currency);
+        return Err(error!(crate::errors::CustomError::UnsupportedCurrency));
+    }
+    Ok(())
+}
+
+/// Default resale hold period: four months and a day, rounded up to 123 days.
+pub const DEFAULT_HOLD_PERIOD_SECONDS: i64 = 123 * 24 * 60 * 60;
+
+/// Maximum number of jurisdiction rules stored on a fundraiser.
+pub const MAX_JURISDICTION_RULES: usize = 32;
+
+/// How a fundraiser's jurisdiction rules are applied.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum JurisdictionMode {
+    Allowlist = 0, // Only listed jurisdictions may invest
+    Denylist = 1, // Every jurisdiction except the listed ones may invest
+}
+
+/// Prospectus exemption an offering is distributed under (NI 45-106 style).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum OfferingExemption {
+    Prospectus = 0, // No exemption relied on, no investor limits
+    AccreditedInvestor = 1, // Only accredited investors may invest, uncapped
+    OfferingMemorandum = 2, // Non-eligible and eligible investors capped per rolling 12 months
+}
+
+/// Rolling 12-month caps per investor category, in escrow token base units.
+/// Accredited investors are never capped.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct InvestmentLimits {
+    pub non_eligible: u64,
+    pub eligible: u64,
+}
+
+/// Length of one bucket of a `RollingTotal`; twelve buckets span a year.
+pub const ROLLING_BUCKET_SECONDS: i64 = 365 * 24 * 60 * 60 / 12;
+
+/// Buckets kept by a `RollingTotal`: the current, partly elapsed one and the twelve before it,
+/// so the window always covers at least the trailing twelve months.
+pub const ROLLING_BUCKETS: usize = 13;
+
+/// Amount invested over the trailing twelve months, kept as monthly buckets in a ring indexed
+/// by `timestamp / ROLLING_BUCKET_SECONDS`. Totals may include up to one month more than a
+/// year, never less, so a limit checked against them holds over any twelve-month period.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct RollingTotal {
+    pub current_bucket: i64, // Bucket index of the most recent write
+    pub buckets: [u64; ROLLING_BUCKETS],
+}
+
+impl RollingTotal {
+    /// Drops buckets that have fallen out of the window ending at `now`.
+    fn advance(&mut self, now: i64) {
+        let bucket = now.div_euclid(ROLLING_BUCKET_SECONDS);
+        if bucket <= self.current_bucket {
+            return;
+        }
+        let stale = (bucket - self.current_bucket).min(ROLLING_BUCKETS as i64);
+        for offset in 1..=stale {
+            let index = (self.current_bucket + offset).rem_euclid(ROLLING_BUCKETS as i64) as usize;
+            self.buckets[index] = 0;
+        }
+        self.current_bucket = bucket;
+    }
+
+    /// Total invested in the window ending at `now`.
+    pub fn total(&mut self, now: i64) -> Result<u64> {
+        self.advance(now);
+        self.buckets
+            .iter()
+            .try_fold(0u64, |acc, amount| acc.checked_add(*amount))
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))
+    }
+
+    /// Records `amount` as invested at `now`.
+    pub fn record(&mut self, now: i64, amount: u64) -> Result<()> {
+        self.advance(now);
+        let index = self.current_bucket.rem_euclid(ROLLING_BUCKETS as i64) as usize;
+        self.buckets[index] = self.buckets[index]
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        Ok(())
+    }
+
+    /// Takes `amount`, recorded at `recorded_at`, back out of the total, as of `now`. Amounts
+    /// whose bucket has left the window no longer count and are ignored. Saturates, since
+    /// investments migrated from the baseline were never recorded.
+    pub fn release(&mut self, now: i64, recorded_at: i64, amount: u64) {
+        self.advance(now);
+        let bucket = recorded_at.div_euclid(ROLLING_BUCKET_SECONDS);
+        if bucket > self.current_bucket || self.current_bucket - bucket >= ROLLING_BUCKETS as i64 {
+            return;
+        }
+        let index = bucket.rem_euclid(ROLLING_BUCKETS as i64) as usize;
+        self.buckets[index] = self.buckets[index].saturating_sub(amount);
+    }
 }
 
 /// Represents an investor's profile on-chain
@@ -18,8 +360,23 @@ pub struct Fundraiser {
 #[account]
 #[derive(InitSpace)]
 pub struct Investor {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
     pub investor_pubkey: Pubkey, // The public key of the investor
     pub bump: u8, // PDA bump seed for the investor account
+    pub rolling_invested: RollingTotal, // Amount invested across OfferingMemorandum fundraisers over the trailing 12 months, net of refunds
+    pub open_investment_count: u64, // Investments in an open status (InvestmentStatus::is_open); close_investor requires zero
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+impl Investor {
+    /// Records that one of the investor's investments left the open statuses (see `InvestmentStatus::is_open`).
+    pub fn settle_investment(&mut self) -> Result<()> {
+        self.open_investment_count = self
+            .open_investment_count
+            .checked_sub(1)
+            .ok_or(error!(crate::errors::CustomError::ArithmeticOverflow))?;
+        Ok(())
+    }
 }
 
 /// Tracks investment activity for a specific investor-fundraiser pair
@@ -27,10 +384,14 @@ pub struct Investor {
 #[account]
 #[derive(InitSpace)]
 pub struct InvestorFundraiser {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
     pub investor: Pubkey,              // The investor's public key
     pub fundraiser: Pubkey,            // The fundraiser's public key
     pub investment_counter: u64,       // Counter for investments in THIS fundraiser by THIS investor
     pub bump: u8,                      // PDA bump seed
+    pub investment_account_count: u64, // Investment PDAs created here and not yet closed
+    pub rolling_invested: RollingTotal, // Amount invested in THIS fundraiser over the trailing 12 months, net of refunds
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
 }
 
 /// Represents an individual investment in a fundraiser
@@ -39,14 +400,89 @@ pub struct InvestorFundraiser {
 #[account]
 #[derive(InitSpace)]
 pub struct Investment {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
     pub investor: Pubkey, // The public key of the investor
     pub fundraiser: Pubkey, // The public key of the fundraiser PDA
     pub usdc_amount: u64, // The amount of USDC invested
     pub reit_amount: u32, // The amount of REIT tokens to be minted for this investment - changed from u64
     pub status: InvestmentStatus,
     pub bump: u8, // PDA bump seed for the investment account
+    pub lockup_until: i64, // End of the resale hold period for the shares issued to this investment
+    pub wired_amount: u64, // Fiat received by the REIT for this investment, in cents of reit_accepted_currency
+    pub fx_rate: u64, // Rate applied, in reit_accepted_currency per unit of the deposit mint's currency, scaled by FX_RATE_SCALE
+    pub wire_date: i64, // Unix timestamp the bank wire settled
+    pub wire_reference_hash: [u8; 32], // Hash of the bank confirmation / wire reference number
+    pub currency: [u8; 3], // Fundraiser currency at wire time; wired_amount and share_price are in its cents
+    pub share_price: u64, // Price per REIT share applied by issue_share, in cents of currency
+    pub deposit_mint: Pubkey, // Stablecoin mint the investor deposited; release and refund use its vault
+    pub created_at: i64, // Unix timestamp of the invest (0 for accounts migrated from before it was recorded)
+    pub created_slot: u64, // Slot of the invest (0 for accounts migrated from before it was recorded)
+    pub last_status_change: i64, // Unix timestamp status was last set
+    #[max_len(MAX_STATUS_HISTORY)]
+    pub status_history: Vec<StatusChange>, // Every status the investment has held, oldest first
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+impl Investment {
+    /// Starts a new investment as Pending, created by `investor` at `clock`.
+    pub fn start(&mut self, clock: &Clock, investor: Pubkey) {
+        self.status = InvestmentStatus::Pending;
+        self.created_at = clock.unix_timestamp;
+        self.created_slot = clock.slot;
+        self.last_status_change = clock.unix_timestamp;
+        self.status_history = vec![StatusChange {
+            status: InvestmentStatus::Pending,
+            changed_at: clock.unix_timestamp,
+            signer: investor,
+        }];
+    }
+
+    /// Fails unless the investment is in `expected`, for instructions that act on it without
+    /// changing its status.
+    pub fn require_status(&self, expected: InvestmentStatus) -> Result<()> {
+        if self.status != expected {
+            debug_msg!("ERROR: Investment is {:?}, expected {:?}", self.status, expected);
+            return Err(invalid_investment_status(self.status, &[expected]));
+        }
+        Ok(())
+    }
+
+    /// Fails unless the investment has reached a terminal status and may be closed.
+    pub fn require_terminal(&self) -> Result<()> {
+        if !self.status.is_terminal() {
+            debug_msg!("ERROR: Investment status {:?} is not terminal", self.status);
+            return Err(invalid_investment_status(self.status, &[InvestmentStatus::Refunded, InvestmentStatus::ShareSold]));
+        }
+        Ok(())
+    }
+
+    /// Moves the investment to `next` at `now` on behalf of `signer` and records the change in
+    /// its history. Returns the previous status.
+    pub fn transition(&mut self, next: InvestmentStatus, now: i64, signer: Pubkey) -> Result<InvestmentStatus> {
+        let previous = self.status;
+        previous.require_transition_to(next)?;
+        self.status = next;
+        self.last_status_change = now;
+        // The longest path through INVESTMENT_TRANSITIONS has MAX_STATUS_HISTORY statuses
+        self.status_history.push(StatusChange { status: next, changed_at: now, signer });
+        Ok(previous)
+    }
+}
+
+/// One entry of `Investment.status_history`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct StatusChange {
+    pub status: InvestmentStatus, // Status entered
+    pub changed_at: i64, // Unix timestamp it was entered
+    pub signer: Pubkey, // Wallet that signed the change (default for changes migrated without one)
 }
 
+/// Longest investment lifecycle: Pending, Released, Wired, ShareIssued, ShareSold.
+pub const MAX_STATUS_HISTORY: usize = 5;
+
+/// Fixed-point scale of `Investment.fx_rate` (1.3625 CAD per USD is stored as 1_362_500).
+pub const FX_RATE_SCALE: u64 = 1_000_000;
+
 /// Investment lifecycle status stored on-chain as a small enum.
 /// We keep explicit discriminants for deterministic storage.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
@@ -57,4 +493,202 @@ pub enum InvestmentStatus {
     Wired = 3,
     ShareIssued = 4,
     ShareSold = 5,
-}
\ No newline at end of file
+}
+
+/// Every status change an investment may make. `invest` creates investments as Pending;
+/// any pair not listed here is rejected by `Investment::transition`.
+pub const INVESTMENT_TRANSITIONS: [(InvestmentStatus, InvestmentStatus); 5] = [
+    (InvestmentStatus::Pending, InvestmentStatus::Released), // release
+    (InvestmentStatus::Released, InvestmentStatus::Refunded), // refund
+    (InvestmentStatus::Released, InvestmentStatus::Wired), // wire, wire_batch
+    (InvestmentStatus::Wired, InvestmentStatus::ShareIssued), // issue_share
+    (InvestmentStatus::ShareIssued, InvestmentStatus::ShareSold), // record_share_sale
+];
+
+impl InvestmentStatus {
+    /// Statuses after which nothing more happens to the investment, so its account may be closed.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, InvestmentStatus::Refunded | InvestmentStatus::ShareSold)
+    }
+
+    /// Statuses in which funds or shares are still owed to the investor. `Fundraiser` and
+    /// `Investor` both count these in `open_investment_count`.
+    pub fn is_open(&self) -> bool {
+        matches!(self, InvestmentStatus::Pending | InvestmentStatus::Released | InvestmentStatus::Wired)
+    }
+
+    pub fn can_transition_to(&self, next: InvestmentStatus) -> bool {
+        INVESTMENT_TRANSITIONS.contains(&(*self, next))
+    }
+
+    /// Fails unless INVESTMENT_TRANSITIONS allows moving from this status to `next`.
+    pub fn require_transition_to(&self, next: InvestmentStatus) -> Result<()> {
+        if !self.can_transition_to(next) {
+            let expected: Vec<InvestmentStatus> = INVESTMENT_TRANSITIONS
+                .iter()
+                .filter(|(_, to)| *to == next)
+                .map(|(from, _)| *from)
+                .collect();
+            debug_msg!("ERROR: Investment cannot move from {:?} to {:?} (expected {:?})", self, next, expected);
+            return Err(invalid_investment_status(*self, &expected));
+        }
+        Ok(())
+    }
+}
+
+/// `InvalidInvestmentStatus` with the current status as the left value and the status(es) the
+/// instruction needs as the right, so the program log shows both.
+fn invalid_investment_status(current: InvestmentStatus, expected: &[InvestmentStatus]) -> Error {
+    error!(crate::errors::CustomError::InvalidInvestmentStatus).with_values((format!("{:?}", current), format!("{:?}", expected)))
+}
+
+/// Program-wide compliance configuration
+/// Seeds: [b"compliance_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceConfig {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
+    pub authority: Pubkey, // The compliance authority allowed to write investor attestations
+    pub bump: u8, // PDA bump seed for the compliance config account
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+/// KYC/AML attestation written by the compliance authority for an investor wallet
+/// Seeds: [b"kyc_attestation", investor_pda]
+///         Derived from the Investor PDA address so clients holding the Investor PDA can find it
+#[account]
+#[derive(InitSpace)]
+pub struct KycAttestation {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
+    pub investor: Pubkey, // The investor wallet this attestation covers
+    pub status: KycStatus,
+    pub jurisdiction: Jurisdiction, // Jurisdiction the investor was verified in
+    pub expires_at: i64, // Unix timestamp after which the attestation is no longer valid
+    pub accreditation: AccreditationCategory,
+    pub kyc_record_hash: [u8; 32], // Hash of the off-chain KYC record held by the compliance provider
+    pub updated_at: i64, // Unix timestamp of the last write by the compliance authority
+    pub bump: u8, // PDA bump seed for the attestation account
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+impl KycAttestation {
+    /// Fails unless the attestation is verified and not yet expired at `now`.
+    pub fn require_valid(&self, now: i64) -> Result<()> {
+        if self.status != KycStatus::Verified {
+            debug_msg!("ERROR: KYC attestation for {} is not verified (status: {:?})", self.investor, self.status);
+            return Err(error!(crate::errors::CustomError::KycNotVerified));
+        }
+        if now >= self.expires_at {
+            debug_msg!("ERROR: KYC attestation for {} expired at {}", self.investor, self.expires_at);
+            return Err(error!(crate::errors::CustomError::KycExpired));
+        }
+        Ok(())
+    }
+}
+
+/// KYC review outcome recorded by the compliance authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum KycStatus {
+    Pending = 0,
+    Verified = 1,
+    Rejected = 2,
+    Revoked = 3,
+}
+
+/// Investor category under the NI 45-106 prospectus exemptions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum AccreditationCategory {
+    NonEligible = 0,
+    Eligible = 1,
+    Accredited = 2,
+}
+
+/// ISO 3166 jurisdiction: alpha-2 country code plus the ISO 3166-2 subdivision suffix
+/// (e.g. "CA" + "ON"). Unused trailing subdivision bytes are zero.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct Jurisdiction {
+    pub country: [u8; 2],
+    pub subdivision: [u8; 3],
+}
+
+impl Jurisdiction {
+    /// A rule without a subdivision covers the whole country.
+    pub fn covers(&self, other: &Jurisdiction) -> bool {
+        self.country == other.country && (self.subdivision == [0u8; 3] || self.subdivision == other.subdivision)
+    }
+}
+
+/// Resale lockup for a holder of a REIT mint, extended on every share issuance.
+/// Read by the transfer hook program for Token-2022 REIT mints.
+/// Seeds: [b"holder_lockup", reit_mint, holder]
+#[account]
+#[derive(InitSpace)]
+pub struct HolderLockup {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
+    pub holder: Pubkey, // The investor wallet holding the REIT tokens
+    pub mint: Pubkey, // The REIT mint the lockup applies to
+    pub locked_until: i64, // Unix timestamp before which the holder may not transfer
+    pub bump: u8, // PDA bump seed for the lockup account
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+/// Maximum number of investments settled by a single batch wire.
+pub const MAX_WIRE_BATCH_INVESTMENTS: usize = 20;
+
+/// One bank wire covering several released investments of a fundraiser
+/// Seeds: [b"wire_batch", fundraiser_pubkey, wire_reference_hash]
+#[account]
+#[derive(InitSpace)]
+pub struct WireBatchRecord {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
+    pub fundraiser: Pubkey, // The fundraiser whose investments were wired
+    pub wired_amount: u64, // Total fiat received, in cents of reit_accepted_currency
+    pub usdc_amount: u64, // Sum of usdc_amount over the covered investments
+    pub fx_rate: u64, // Rate applied, scaled by FX_RATE_SCALE
+    pub wire_date: i64, // Unix timestamp the bank wire settled
+    pub wire_reference_hash: [u8; 32], // Hash of the bank confirmation / wire reference number
+    #[max_len(MAX_WIRE_BATCH_INVESTMENTS)]
+    pub investments: Vec<Pubkey>, // Investment accounts covered, in the order passed
+    pub bump: u8, // PDA bump seed for the batch record
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+/// Maximum accepted FX confidence interval, in basis points of the rate.
+pub const MAX_FX_CONFIDENCE_BPS: u64 = 100;
+
+/// Exchange rate published by an authorized publisher (an oracle relay, or a local keypair in tests)
+/// Seeds: [b"fx_rate", base_currency, quote_currency]
+#[account]
+#[derive(InitSpace)]
+pub struct FxRate {
+    pub version: u8, // Layout version, Versioned::VERSION when last written
+    pub publisher: Pubkey, // The only key allowed to publish this rate
+    pub base_currency: [u8; 3], // e.g. "USD"
+    pub quote_currency: [u8; 3], // e.g. "CAD"
+    pub rate: u64, // quote per base, scaled by FX_RATE_SCALE
+    pub confidence: u64, // +/- confidence interval, scaled by FX_RATE_SCALE
+    pub published_at: i64, // Unix timestamp of the observation
+    pub max_staleness_seconds: i64, // Age after which the rate may no longer be used
+    pub bump: u8, // PDA bump seed for the FX rate account
+    pub reserved: [u8; RESERVED_SPACE], // Zeroed room for future fields
+}
+
+impl FxRate {
+    /// Fails unless the rate has been published, is fresh at `now` and its confidence is tight enough.
+    pub fn require_usable(&self, now: i64) -> Result<()> {
+        if self.rate == 0 {
+            debug_msg!("ERROR: FX rate has not been published");
+            return Err(error!(crate::errors::CustomError::InvalidFxRate));
+        }
+        if now.saturating_sub(self.published_at) > self.max_staleness_seconds {
+            debug_msg!("ERROR: FX rate published at {} is stale (now: {}, max staleness: {})", self.published_at, now, self.max_staleness_seconds);
+            return Err(error!(crate::errors::CustomError::StaleFxRate));
+        }
+        let confidence_bps = (self.confidence as u128) * 10_000 / (self.rate as u128);
+        if confidence_bps > MAX_FX_CONFIDENCE_BPS as u128 {
+            debug_msg!("ERROR: FX rate confidence {} bps exceeds {} bps", confidence_bps, MAX_FX_CONFIDENCE_BPS);
+            return Err(error!(crate::errors::CustomError::FxRateConfidenceTooWide));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,587 @@
+//! In-process SVM harness shared by the integration tests.
+//!
+//! The tests load the compiled programs from `anchor/target/deploy`, so run `anchor build`
+//! first. Tests that create classic SPL REIT mints also need the Metaplex Token Metadata
+//! program, dumped from mainnet by `npm run anchor-fixtures` into `anchor/tests/fixtures`.
+//! The SBF toolchain is not available everywhere `cargo test` runs, so every test that needs a
+//! binary is `#[ignore]`d. `npm run anchor-test-svm` builds both programs and the debug-logs
+//! build, fetches the fixture if it is missing and runs them with `--include-ignored`. A missing
+//! binary fails the test with the command that produces it.
+
+#![allow(dead_code, clippy::result_large_err)]
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::{bpf_loader_upgradeable, pubkey, system_instruction};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use anchor_spl::associated_token;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use canadianreitinvest::errors::CustomError;
+use canadianreitinvest::state;
+use canadianreitinvest_client::{instructions as ix, pda};
+use litesvm::types::TransactionResult;
+use litesvm::LiteSVM;
+use solana_account::Account;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+
+/// `reit_transfer_hook` program id, as listed in Anchor.toml.
+pub const TRANSFER_HOOK_ID: Pubkey = pubkey!("98ABkLqEXy5MPkVEZv7Wz4TtSTr9be5sCrM1LLKxjj66");
+
+/// Unix time the harness clock starts at.
+pub const START_TIME: i64 = 1_760_000_000;
+
+pub const DAY: i64 = 24 * 60 * 60;
+
+pub const USDC_DECIMALS: u8 = 6;
+
+/// One whole USDC in base units.
+pub const USDC: u64 = 1_000_000;
+
+/// Share price in CAD cents just under 1 USDC at the wires' 1.3625 CAD per USD, so up to a few
+/// hundred USDC buys one share per USDC
+pub const USDC_SHARE_PRICE: u64 = 136;
+
+pub const PROGRAM: &str = "canadianreitinvest.so";
+pub const TRANSFER_HOOK_PROGRAM: &str = "reit_transfer_hook.so";
+pub const METADATA_FIXTURE: &str = "mpl_token_metadata.so";
+
+/// CA-ON, the jurisdiction `onboard_investor` attests.
+pub const ONTARIO: state::Jurisdiction = state::Jurisdiction { country: *b"CA", subdivision: *b"ON\0" };
+
+/// Path of a program binary produced by `anchor build`.
+pub fn deploy_path(file_name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../target/deploy")
+        .join(file_name)
+}
+
+/// Path of a third-party program binary checked out by `npm run anchor-fixtures`.
+pub fn fixture_path(file_name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures")
+        .join(file_name)
+}
+
+fn read_binary(path: PathBuf, hint: &str) -> Vec<u8> {
+    std::fs::read(&path).unwrap_or_else(|err| panic!("{}: {err}, run `{hint}` first", path.display()))
+}
+
+/// Reads a program binary, panicking with the build to run if it is missing.
+pub fn read_program(file_name: &str) -> Vec<u8> {
+    read_binary(deploy_path(file_name), "anchor build")
+}
+
+/// Reads a fixture binary, panicking with how to fetch it if it is missing.
+pub fn read_fixture(file_name: &str) -> Vec<u8> {
+    read_binary(fixture_path(file_name), "npm run anchor-fixtures")
+}
+
+/// The release program and the transfer hook.
+pub fn programs() -> (Vec<u8>, Vec<u8>) {
+    (read_program(PROGRAM), read_program(TRANSFER_HOOK_PROGRAM))
+}
+
+/// Program error code of a failed transaction, if it failed with a custom error.
+pub fn custom_error_code(err: &TransactionError) -> Option<u32> {
+    match err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+        _ => None,
+    }
+}
+
+/// A REIT id and its hash, as the client derives them.
+pub fn new_reit_id() -> (String, [u8; 16]) {
+    let reit_id_hash: [u8; 16] = Keypair::new().pubkey().to_bytes()[..16].try_into().unwrap();
+    let hex: String = reit_id_hash.iter().map(|byte| format!("{byte:02x}")).collect();
+    let reit_id = format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32]);
+    (reit_id, reit_id_hash)
+}
+
+/// A fundraiser created by the harness and the addresses tests keep reaching for.
+pub struct TestFundraiser {
+    pub reit_id: String,
+    pub reit_id_hash: [u8; 16],
+    pub address: Pubkey,
+    pub escrow_vault: Pubkey,
+    /// Set by `create_reit_mint_2022` / `create_reit_mint`.
+    pub reit_mint: Pubkey,
+    pub reit_token_program: Pubkey,
+}
+
+/// One investor's investments in a fundraiser, advanced through the lifecycle together.
+pub struct Raise {
+    pub fundraiser: TestFundraiser,
+    pub investor: Keypair,
+    pub investments: Vec<Pubkey>,
+}
+
+pub struct TestEnv {
+    pub svm: LiteSVM,
+    pub admin: Keypair,
+    pub compliance: Keypair,
+    pub usdc_mint: Pubkey,
+}
+
+impl TestEnv {
+    /// Deploys `program` as an upgradeable program owned by a fresh admin, plus the transfer
+    /// hook, and creates a USDC mint controlled by the admin.
+    pub fn new(program: &[u8], transfer_hook: &[u8]) -> Self {
+        let mut svm = LiteSVM::new();
+        let admin = Keypair::new();
+        let compliance = Keypair::new();
+        svm.airdrop(&admin.pubkey(), 100_000_000_000).unwrap();
+        svm.airdrop(&compliance.pubkey(), 1_000_000_000).unwrap();
+
+        deploy_upgradeable(&mut svm, canadianreitinvest::ID, program, admin.pubkey());
+        svm.add_program(TRANSFER_HOOK_ID, transfer_hook).unwrap();
+
+        let mut clock: Clock = svm.get_sysvar();
+        clock.unix_timestamp = START_TIME;
+        svm.set_sysvar(&clock);
+
+        let mut env = TestEnv { svm, admin, compliance, usdc_mint: Pubkey::default() };
+        env.usdc_mint = env.create_mint(USDC_DECIMALS);
+        env
+    }
+
+    /// Builds an environment from the release build with the compliance configuration in place.
+    pub fn load() -> Self {
+        let (program, transfer_hook) = programs();
+        let mut env = TestEnv::new(&program, &transfer_hook);
+        let (admin, compliance) = (env.admin.pubkey(), env.compliance.pubkey());
+        env.execute_admin(ix::initialize_compliance(&admin, &compliance));
+        env
+    }
+
+    /// Like `load`, with the Metaplex Token Metadata program installed for classic SPL REIT mints.
+    pub fn load_with_metadata() -> Self {
+        let metadata_program = read_fixture(METADATA_FIXTURE);
+        let mut env = TestEnv::load();
+        env.svm.add_program(mpl_token_metadata::ID, &metadata_program).unwrap();
+        env
+    }
+
+    /// Sends `instructions` in one transaction paid by the first signer.
+    pub fn send(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TransactionResult {
+        let tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&signers[0].pubkey()),
+            signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(tx);
+        self.svm.expire_blockhash();
+        result
+    }
+
+    /// Sends `instruction` signed by `signers` and returns the compute units it consumed.
+    pub fn execute(&mut self, instruction: Instruction, signers: &[&Keypair]) -> u64 {
+        match self.send(&[instruction], signers) {
+            Ok(meta) => meta.compute_units_consumed,
+            Err(failed) => panic!("transaction failed: {:?}\n{}", failed.err, failed.meta.pretty_logs()),
+        }
+    }
+
+    pub fn execute_admin(&mut self, instruction: Instruction) -> u64 {
+        let admin = self.admin.insecure_clone();
+        self.execute(instruction, &[&admin])
+    }
+
+    pub fn execute_compliance(&mut self, instruction: Instruction) -> u64 {
+        let compliance = self.compliance.insecure_clone();
+        self.execute(instruction, &[&compliance])
+    }
+
+    /// Sends `instruction` and asserts it fails with `expected`.
+    pub fn expect_error(&mut self, instruction: Instruction, signers: &[&Keypair], expected: CustomError) {
+        let code = u32::from(expected);
+        match self.send(&[instruction], signers) {
+            Ok(meta) => panic!("expected {expected:?} ({code}), transaction succeeded\n{}", meta.pretty_logs()),
+            Err(failed) => assert_eq!(
+                custom_error_code(&failed.err),
+                Some(code),
+                "expected {expected:?}, got {:?}\n{}",
+                failed.err,
+                failed.meta.pretty_logs()
+            ),
+        }
+    }
+
+    pub fn expect_admin_error(&mut self, instruction: Instruction, expected: CustomError) {
+        let admin = self.admin.insecure_clone();
+        self.expect_error(instruction, &[&admin], expected);
+    }
+
+    pub fn expect_compliance_error(&mut self, instruction: Instruction, expected: CustomError) {
+        let compliance = self.compliance.insecure_clone();
+        self.expect_error(instruction, &[&compliance], expected);
+    }
+
+    pub fn now(&self) -> i64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp
+    }
+
+    pub fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.svm.get_sysvar();
+        clock.unix_timestamp = unix_timestamp;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self.svm.get_account(address).expect("account not found");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Whether `address` holds a live account (closed accounts have no lamports left).
+    pub fn exists(&self, address: &Pubkey) -> bool {
+        self.svm.get_account(address).is_some_and(|account| account.lamports > 0)
+    }
+
+    /// Rewrites a program account in place, for states no instruction sequence can reach
+    /// within a test (counters at their limits and the like).
+    pub fn modify_account<T: AccountDeserialize + AccountSerialize>(&mut self, address: &Pubkey, modify: impl FnOnce(&mut T)) {
+        let mut account = self.svm.get_account(address).expect("account not found");
+        let mut value = T::try_deserialize(&mut account.data.as_slice()).unwrap();
+        modify(&mut value);
+        value.try_serialize(&mut account.data.as_mut_slice()).unwrap();
+        self.svm.set_account(*address, account).unwrap();
+    }
+
+    pub fn token_balance(&self, token_account: &Pubkey) -> u64 {
+        let account = self.svm.get_account(token_account).expect("token account not found");
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+
+    pub fn is_frozen(&self, token_account: &Pubkey) -> bool {
+        // spl_token::state::AccountState::Frozen, shared by both token programs
+        self.svm.get_account(token_account).expect("token account not found").data[108] == 2
+    }
+
+    pub fn mint_supply(&self, mint: &Pubkey) -> u64 {
+        let account = self.svm.get_account(mint).expect("mint not found");
+        u64::from_le_bytes(account.data[36..44].try_into().unwrap())
+    }
+
+    /// Creates a classic SPL mint with the admin as mint authority.
+    pub fn create_mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let admin = self.admin.insecure_clone();
+        let space = spl_token::state::Mint::LEN;
+        let instructions = [
+            system_instruction::create_account(
+                &admin.pubkey(),
+                &mint.pubkey(),
+                self.svm.minimum_balance_for_rent_exemption(space),
+                space as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::ID, &mint.pubkey(), &admin.pubkey(), None, decimals).unwrap(),
+        ];
+        self.send(&instructions, &[&admin, &mint]).unwrap();
+        mint.pubkey()
+    }
+
+    /// Creates `owner`'s ATA for a classic SPL `mint` and mints `amount` into it.
+    pub fn fund_ata(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let admin = self.admin.insecure_clone();
+        let ata = pda::associated_token_account(owner, mint, &spl_token::ID);
+        let mut instructions = Vec::new();
+        if self.svm.get_account(&ata).is_none() {
+            instructions.push(associated_token::spl_associated_token_account::instruction::create_associated_token_account(
+                &admin.pubkey(),
+                owner,
+                mint,
+                &spl_token::ID,
+            ));
+        }
+        if amount > 0 {
+            instructions.push(spl_token::instruction::mint_to(&spl_token::ID, mint, &ata, &admin.pubkey(), &[], amount).unwrap());
+        }
+        self.send(&instructions, &[&admin]).unwrap();
+        ata
+    }
+
+    /// Writes a KYC attestation for `wallet` as the compliance authority.
+    pub fn attest(
+        &mut self,
+        wallet: &Pubkey,
+        status: state::KycStatus,
+        jurisdiction: state::Jurisdiction,
+        expires_at: i64,
+        accreditation: state::AccreditationCategory,
+    ) {
+        let compliance = self.compliance.pubkey();
+        self.execute_compliance(ix::attest_investor(&compliance, wallet, status, jurisdiction, expires_at, accreditation, [7u8; 32]));
+    }
+
+    /// Creates and funds an investor wallet with an attested, verified KYC record and an
+    /// Investor PDA, holding `usdc` in its USDC ATA.
+    pub fn onboard_investor(&mut self, usdc: u64) -> Keypair {
+        self.onboard_investor_as(state::AccreditationCategory::Accredited, ONTARIO, usdc)
+    }
+
+    /// `onboard_investor` with a chosen accreditation category and jurisdiction.
+    pub fn onboard_investor_as(
+        &mut self,
+        accreditation: state::AccreditationCategory,
+        jurisdiction: state::Jurisdiction,
+        usdc: u64,
+    ) -> Keypair {
+        let investor = Keypair::new();
+        self.svm.airdrop(&investor.pubkey(), 10_000_000_000).unwrap();
+        self.attest(&investor.pubkey(), state::KycStatus::Verified, jurisdiction, START_TIME + 365 * DAY, accreditation);
+        self.execute(ix::initialize_investor(&investor.pubkey()), &[&investor]);
+        let usdc_mint = self.usdc_mint;
+        self.fund_ata(&usdc_mint, &investor.pubkey(), usdc);
+        investor
+    }
+
+    /// Creates a Draft CAD fundraiser with no terms set.
+    pub fn create_fundraiser(&mut self) -> TestFundraiser {
+        let (reit_id, reit_id_hash) = new_reit_id();
+        let (admin, usdc_mint) = (self.admin.pubkey(), self.usdc_mint);
+        self.execute_admin(ix::initialize_fundraiser(&admin, &usdc_mint, &reit_id, *b"CAD").unwrap());
+        let address = pda::fundraiser(&reit_id_hash);
+        TestFundraiser {
+            reit_id,
+            reit_id_hash,
+            address,
+            escrow_vault: pda::escrow_vault(&address),
+            reit_mint: Pubkey::default(),
+            reit_token_program: spl_token::ID,
+        }
+    }
+
+    /// Creates a fundraiser with the given terms and opens it for investment.
+    pub fn open_fundraiser(&mut self, min_raise: u64, max_raise: u64, close_at: i64) -> TestFundraiser {
+        let fundraiser = self.create_fundraiser();
+        let admin = self.admin.pubkey();
+        self.execute_admin(ix::update_fundraiser_terms(&admin, fundraiser.reit_id_hash, *b"CAD", min_raise, max_raise, close_at));
+        self.set_status(&fundraiser, state::FundraiserStatus::Open);
+        fundraiser
+    }
+
+    pub fn set_status(&mut self, fundraiser: &TestFundraiser, status: state::FundraiserStatus) {
+        let admin = self.admin.pubkey();
+        self.execute_admin(ix::set_fundraiser_status(&admin, fundraiser.reit_id_hash, status));
+    }
+
+    /// Counter of `wallet`'s next investment in `fundraiser`.
+    pub fn next_counter(&self, wallet: &Pubkey, fundraiser: &TestFundraiser) -> u64 {
+        let investor_fundraiser = pda::investor_fundraiser(wallet, &fundraiser.address);
+        if !self.exists(&investor_fundraiser) {
+            return 0;
+        }
+        self.account::<state::InvestorFundraiser>(&investor_fundraiser).investment_counter
+    }
+
+    /// Builds an `invest` of `amount` USDC into the fundraiser's primary escrow vault.
+    pub fn invest_ix(&self, wallet: &Pubkey, fundraiser: &TestFundraiser, amount: u64) -> Instruction {
+        let counter = self.next_counter(wallet, fundraiser);
+        ix::invest(wallet, fundraiser.reit_id_hash, &self.usdc_mint, &fundraiser.escrow_vault, amount, counter)
+    }
+
+    /// Invests `amount` USDC and returns the new investment's address.
+    pub fn invest(&mut self, investor: &Keypair, fundraiser: &TestFundraiser, amount: u64) -> Pubkey {
+        let counter = self.next_counter(&investor.pubkey(), fundraiser);
+        let instruction = self.invest_ix(&investor.pubkey(), fundraiser, amount);
+        self.execute(instruction, &[investor]);
+        pda::investment(&investor.pubkey(), &fundraiser.address, counter)
+    }
+
+    pub fn release_ix(&self, fundraiser: &TestFundraiser, investment: &Pubkey) -> Instruction {
+        ix::release(&self.admin.pubkey(), fundraiser.reit_id_hash, investment, &self.usdc_mint, &fundraiser.escrow_vault)
+    }
+
+    /// Builds a `wire` of a released investment at 1.3625 CAD per USD, settled now.
+    pub fn wire_ix(&self, fundraiser: &TestFundraiser, investment: &Pubkey, wired_amount: u64) -> Instruction {
+        ix::wire(&self.admin.pubkey(), fundraiser.reit_id_hash, investment, wired_amount, 1_362_500, self.now(), [9u8; 32])
+    }
+
+    /// Creates a Token-2022 REIT mint for `fundraiser` with the compliance transfer hook.
+    pub fn create_reit_mint_2022(&mut self, fundraiser: &mut TestFundraiser) {
+        let reit_mint = Keypair::new();
+        let admin = self.admin.insecure_clone();
+        self.execute(
+            ix::create_reit_mint_2022(
+                &admin.pubkey(),
+                fundraiser.reit_id_hash,
+                &reit_mint.pubkey(),
+                &TRANSFER_HOOK_ID,
+                "Test REIT".to_string(),
+                "TREIT".to_string(),
+                "https://example.com/reit.json".to_string(),
+            ),
+            &[&admin, &reit_mint],
+        );
+        fundraiser.reit_mint = reit_mint.pubkey();
+        fundraiser.reit_token_program = spl_token_2022::ID;
+    }
+
+    /// Creates a classic SPL REIT mint with Metaplex metadata; needs `load_with_metadata`.
+    pub fn create_reit_mint(&mut self, fundraiser: &mut TestFundraiser) {
+        let reit_mint = Keypair::new();
+        let admin = self.admin.insecure_clone();
+        self.execute(
+            ix::create_reit_mint(
+                &admin.pubkey(),
+                fundraiser.reit_id_hash,
+                &reit_mint.pubkey(),
+                "Test REIT".to_string(),
+                "TREIT".to_string(),
+                "https://example.com/reit.json".to_string(),
+            ),
+            &[&admin, &reit_mint],
+        );
+        fundraiser.reit_mint = reit_mint.pubkey();
+        fundraiser.reit_token_program = spl_token::ID;
+    }
+
+    /// Builds an `issue_share` at `share_price` cents of the fundraiser currency per share,
+    /// converted at the investment's wire rate, through the lock vault
+    /// whenever the program requires one.
+    pub fn issue_share_ix(&self, fundraiser: &TestFundraiser, investment: &Pubkey, wallet: &Pubkey, share_price: u64) -> Instruction {
+        let hold_period = self.account::<state::Fundraiser>(&fundraiser.address).hold_period_seconds;
+        let use_lock_vault = fundraiser.reit_token_program == spl_token::ID && hold_period > 0;
+        ix::issue_share(
+            &self.admin.pubkey(),
+            fundraiser.reit_id_hash,
+            investment,
+            wallet,
+            &fundraiser.reit_mint,
+            &fundraiser.reit_token_program,
+            use_lock_vault,
+            None,
+            share_price,
+        )
+    }
+
+    /// Opens a fundraiser with no minimum, has one investor put in each of `amounts`, and
+    /// closes it as Funded.
+    pub fn funded_raise(&mut self, amounts: &[u64]) -> Raise {
+        let fundraiser = self.open_fundraiser(0, 0, 0);
+        let investor = self.onboard_investor(amounts.iter().sum());
+        let investments = amounts.iter().map(|amount| self.invest(&investor, &fundraiser, *amount)).collect();
+        self.set_status(&fundraiser, state::FundraiserStatus::Closed);
+        self.set_status(&fundraiser, state::FundraiserStatus::Funded);
+        Raise { fundraiser, investor, investments }
+    }
+
+    /// `funded_raise` with every investment released and wired.
+    pub fn wired_raise(&mut self, amounts: &[u64]) -> Raise {
+        let raise = self.funded_raise(amounts);
+        for (investment, amount) in raise.investments.iter().zip(amounts) {
+            self.execute_admin(self.release_ix(&raise.fundraiser, investment));
+            // Wired CAD cents at 1.3625 CAD per USD
+            self.execute_admin(self.wire_ix(&raise.fundraiser, investment, amount * 13_625 / 100_000_000));
+        }
+        raise
+    }
+
+    /// `wired_raise` with a Token-2022 REIT mint and shares issued at `USDC_SHARE_PRICE`.
+    pub fn issued_raise(&mut self, amounts: &[u64]) -> Raise {
+        let mut raise = self.wired_raise(amounts);
+        self.create_reit_mint_2022(&mut raise.fundraiser);
+        let wallet = raise.investor.pubkey();
+        for investment in &raise.investments {
+            self.execute_admin(self.issue_share_ix(&raise.fundraiser, investment, &wallet, USDC_SHARE_PRICE));
+        }
+        raise
+    }
+
+    /// Replaces `address`'s data with `data`, rent-exempt at its size, as the deployed baseline
+    /// program left it.
+    pub fn set_baseline(&mut self, address: &Pubkey, data: Vec<u8>) {
+        let mut account = self.svm.get_account(address).unwrap();
+        account.lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        account.data = data;
+        self.svm.set_account(*address, account).unwrap();
+    }
+
+    /// Rewrites a `funded_raise`'s fundraiser, Investor, InvestorFundraiser and Pending
+    /// investments byte for byte in the layouts of the deployed baseline.
+    pub fn write_baseline_raise(&mut self, raise: &Raise) {
+        let wallet = raise.investor.pubkey();
+        let fundraiser = &raise.fundraiser;
+        let (investor, investor_fundraiser) = (pda::investor(&wallet), pda::investor_fundraiser(&wallet, &fundraiser.address));
+
+        let current: state::Fundraiser = self.account(&fundraiser.address);
+        let mut data = state::Fundraiser::DISCRIMINATOR.to_vec();
+        for key in [current.admin, current.usdc_mint, Pubkey::default(), fundraiser.escrow_vault] {
+            data.extend_from_slice(key.as_ref());
+        }
+        data.extend_from_slice(&current.total_raised.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(current.bump);
+        data.extend_from_slice(&current.reit_accepted_currency);
+        assert_eq!(data.len(), 156);
+        self.set_baseline(&fundraiser.address, data);
+
+        let current: state::Investor = self.account(&investor);
+        let mut data = state::Investor::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(wallet.as_ref());
+        data.push(current.bump);
+        assert_eq!(data.len(), 41);
+        self.set_baseline(&investor, data);
+
+        let current: state::InvestorFundraiser = self.account(&investor_fundraiser);
+        let mut data = state::InvestorFundraiser::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(wallet.as_ref());
+        data.extend_from_slice(fundraiser.address.as_ref());
+        data.extend_from_slice(&current.investment_counter.to_le_bytes());
+        data.push(current.bump);
+        assert_eq!(data.len(), 81);
+        self.set_baseline(&investor_fundraiser, data);
+
+        for investment in &raise.investments {
+            let current: state::Investment = self.account(investment);
+            let mut data = state::Investment::DISCRIMINATOR.to_vec();
+            data.extend_from_slice(wallet.as_ref());
+            data.extend_from_slice(fundraiser.address.as_ref());
+            data.extend_from_slice(&current.usdc_amount.to_le_bytes());
+            data.extend_from_slice(&0u32.to_le_bytes());
+            data.push(state::InvestmentStatus::Pending as u8);
+            data.push(current.bump);
+            assert_eq!(data.len(), 86);
+            self.set_baseline(investment, data);
+        }
+    }
+}
+
+/// Installs `elf` at `program_id` under the upgradeable loader with `authority` as upgrade
+/// authority, so instructions gated on the program data account can run.
+pub fn deploy_upgradeable(svm: &mut LiteSVM, program_id: Pubkey, elf: &[u8], authority: Pubkey) {
+    let (program_data, _) = Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::ID);
+
+    // UpgradeableLoaderState::ProgramData { slot, upgrade_authority_address } followed by the ELF
+    let mut data = Vec::with_capacity(45 + elf.len());
+    data.extend_from_slice(&3u32.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(elf);
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        program_data,
+        Account { lamports, data, owner: bpf_loader_upgradeable::ID, executable: false, rent_epoch: 0 },
+    )
+    .unwrap();
+
+    // UpgradeableLoaderState::Program { programdata_address }
+    let mut data = Vec::with_capacity(36);
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(program_data.as_ref());
+    let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+    svm.set_account(
+        program_id,
+        Account { lamports, data, owner: bpf_loader_upgradeable::ID, executable: true, rent_epoch: 0 },
+    )
+    .unwrap();
+}
@@ -0,0 +1,554 @@
+//! Compute-unit budget for the release build.
+//!
+//! Runs every program instruction, and a Token-2022 transfer through the compliance hook, twice:
+//! once against the release programs and once against builds with `--features debug-logs`.
+//! Every instruction must stay under `CU_BUDGET` in the release build and must not cost more
+//! than it does with debug logging, and neither release program may log anything beyond
+//! Anchor's instruction names, events and errors.
+//!
+//! Needs `anchor build`, `npm run anchor-build-debug-logs` and the Metaplex fixture from
+//! `npm run anchor-fixtures`; `npm run anchor-test-svm` does all three and runs this test with
+//! the rest of the ignored suite.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_spl::associated_token::spl_associated_token_account;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use canadianreitinvest::errors::CustomError;
+use canadianreitinvest::state::{self, AccreditationCategory, FundraiserStatus, KycStatus};
+use canadianreitinvest_client::{instructions as ix, pda};
+use common::{read_fixture, read_program, TestEnv, DAY, METADATA_FIXTURE, ONTARIO, PROGRAM, START_TIME, TRANSFER_HOOK_ID, TRANSFER_HOOK_PROGRAM, USDC};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+/// Per-instruction ceiling for the release build, well below the 200k default per instruction
+const CU_BUDGET: u64 = 150_000;
+
+const DEBUG_LOGS_PROGRAM: &str = "canadianreitinvest_debug_logs.so";
+const DEBUG_LOGS_TRANSFER_HOOK_PROGRAM: &str = "reit_transfer_hook_debug_logs.so";
+
+/// Every instruction in the program, each of which must be measured at least once
+const INSTRUCTIONS: [&str; 43] = [
+    "initialize_fundraiser",
+    "update_fundraiser_terms",
+    "set_fundraiser_status",
+    "close_fundraiser",
+    "close_investment",
+    "migrate_fundraiser",
+    "migrate_investor",
+    "migrate_investor_fundraiser",
+    "migrate_investment",
+    "migrate_compliance_config",
+    "migrate_kyc_attestation",
+    "migrate_holder_lockup",
+    "migrate_wire_batch_record",
+    "migrate_fx_rate",
+    "add_accepted_mint",
+    "initialize_investor",
+    "close_investor",
+    "invest",
+    "release",
+    "refund",
+    "wire",
+    "wire_batch",
+    "create_reit_mint",
+    "create_reit_mint_2022",
+    "update_reit_mint",
+    "issue_share",
+    "unlock_shares",
+    "set_hold_period",
+    "issue_dividend",
+    "initialize_compliance",
+    "set_compliance_authority",
+    "attest_investor",
+    "freeze_holder",
+    "thaw_holder",
+    "recover_position",
+    "record_share_sale",
+    "initialize_fx_rate",
+    "publish_fx_rate",
+    "set_fx_rate_publisher",
+    "set_offering_exemption",
+    "set_jurisdiction_rules",
+    "add_jurisdiction_rule",
+    "remove_jurisdiction_rule",
+];
+
+/// The program binaries one run loads
+struct Build {
+    program: Vec<u8>,
+    transfer_hook: Vec<u8>,
+    metadata: Vec<u8>,
+}
+
+/// A `migrate_*` builder for an account type without extra accounts
+type MigrateBuilder = fn(&Pubkey, &Pubkey) -> Instruction;
+
+struct Measurement {
+    instruction: &'static str,
+    compute_units: u64,
+    logs: Vec<String>,
+}
+
+#[derive(Default)]
+struct Recorder {
+    measurements: Vec<Measurement>,
+}
+
+impl Recorder {
+    fn measure(&mut self, env: &mut TestEnv, instruction: &'static str, ix: Instruction, signers: &[&Keypair]) {
+        let meta = match env.send(&[ix], signers) {
+            Ok(meta) => meta,
+            Err(failed) => panic!("{instruction} failed: {:?}\n{}", failed.err, failed.meta.pretty_logs()),
+        };
+        self.measurements.push(Measurement { instruction, compute_units: meta.compute_units_consumed, logs: meta.logs });
+    }
+
+    /// Measures an instruction whose only reachable outcome is failing with `expected`, like
+    /// the `migrate_*` handlers of account types that have no earlier layout.
+    fn measure_rejected(&mut self, env: &mut TestEnv, instruction: &'static str, ix: Instruction, signers: &[&Keypair], expected: CustomError) {
+        let failed = match env.send(&[ix], signers) {
+            Ok(meta) => panic!("{instruction} succeeded, expected {expected:?}\n{}", meta.pretty_logs()),
+            Err(failed) => failed,
+        };
+        assert_eq!(common::custom_error_code(&failed.err), Some(u32::from(expected)), "{instruction}\n{}", failed.meta.pretty_logs());
+        self.measurements.push(Measurement { instruction, compute_units: failed.meta.compute_units_consumed, logs: failed.meta.logs });
+    }
+}
+
+/// A fresh environment with the Metaplex program installed for classic SPL mints.
+fn new_env(build: &Build) -> TestEnv {
+    let mut env = TestEnv::new(&build.program, &build.transfer_hook);
+    env.svm.add_program(mpl_token_metadata::ID, &build.metadata).unwrap();
+    env
+}
+
+/// Creates the compliance configuration in scenarios that don't measure it.
+fn initialize_compliance(env: &mut TestEnv) {
+    let (admin, compliance) = (env.admin.pubkey(), env.compliance.pubkey());
+    env.execute_admin(ix::initialize_compliance(&admin, &compliance));
+}
+
+/// One Token-2022 fundraiser from configuration through dividend, then compliance actions,
+/// a transfer through the hook and the rejected migrations of accounts already current.
+fn offering(recorder: &mut Recorder, build: &Build) {
+    let mut env = new_env(build);
+    let admin = env.admin.insecure_clone();
+    let compliance = env.compliance.insecure_clone();
+    let usdc_mint = env.usdc_mint;
+
+    recorder.measure(&mut env, "initialize_compliance", ix::initialize_compliance(&admin.pubkey(), &compliance.pubkey()), &[&admin]);
+
+    // Onboarded as `TestEnv::onboard_investor` does it, measuring each step
+    let investor = Keypair::new();
+    let wallet = investor.pubkey();
+    env.svm.airdrop(&wallet, 10_000_000_000).unwrap();
+    recorder.measure(
+        &mut env,
+        "attest_investor",
+        ix::attest_investor(&compliance.pubkey(), &wallet, KycStatus::Verified, ONTARIO, START_TIME + 365 * DAY, AccreditationCategory::Accredited, [7u8; 32]),
+        &[&compliance],
+    );
+    recorder.measure(&mut env, "initialize_investor", ix::initialize_investor(&wallet), &[&investor]);
+    env.fund_ata(&usdc_mint, &wallet, 10_000 * USDC);
+
+    let (reit_id, reit_id_hash) = common::new_reit_id();
+    let fundraiser = pda::fundraiser(&reit_id_hash);
+    let escrow_vault = pda::escrow_vault(&fundraiser);
+    recorder.measure(&mut env, "initialize_fundraiser", ix::initialize_fundraiser(&admin.pubkey(), &usdc_mint, &reit_id, *b"CAD").unwrap(), &[&admin]);
+    recorder.measure(
+        &mut env,
+        "update_fundraiser_terms",
+        ix::update_fundraiser_terms(&admin.pubkey(), reit_id_hash, *b"CAD", 1_000 * USDC, 0, START_TIME + 30 * DAY),
+        &[&admin],
+    );
+    let limits = state::InvestmentLimits { non_eligible: 10_000 * USDC, eligible: 100_000 * USDC };
+    recorder.measure(
+        &mut env,
+        "set_offering_exemption",
+        ix::set_offering_exemption(&admin.pubkey(), reit_id_hash, state::OfferingExemption::OfferingMemorandum, limits),
+        &[&admin],
+    );
+    let new_york = state::Jurisdiction { country: *b"US", subdivision: *b"NY\0" };
+    let britain = state::Jurisdiction { country: *b"GB", subdivision: [0; 3] };
+    recorder.measure(
+        &mut env,
+        "set_jurisdiction_rules",
+        ix::set_jurisdiction_rules(&admin.pubkey(), reit_id_hash, state::JurisdictionMode::Denylist, vec![new_york]),
+        &[&admin],
+    );
+    recorder.measure(&mut env, "add_jurisdiction_rule", ix::add_jurisdiction_rule(&admin.pubkey(), reit_id_hash, britain), &[&admin]);
+    recorder.measure(&mut env, "remove_jurisdiction_rule", ix::remove_jurisdiction_rule(&admin.pubkey(), reit_id_hash, new_york), &[&admin]);
+    recorder.measure(&mut env, "set_hold_period", ix::set_hold_period(&admin.pubkey(), reit_id_hash, 30 * DAY), &[&admin]);
+    let usdt = env.create_mint(common::USDC_DECIMALS);
+    recorder.measure(&mut env, "add_accepted_mint", ix::add_accepted_mint(&admin.pubkey(), reit_id_hash, &usdc_mint, &usdt, *b"USD"), &[&admin]);
+    recorder.measure(&mut env, "set_fundraiser_status", ix::set_fundraiser_status(&admin.pubkey(), reit_id_hash, FundraiserStatus::Open), &[&admin]);
+
+    let amounts = [1_000 * USDC, 500 * USDC, 300 * USDC, 100 * USDC];
+    let investments: Vec<Pubkey> = (0..amounts.len() as u64).map(|counter| pda::investment(&wallet, &fundraiser, counter)).collect();
+    for (counter, amount) in amounts.into_iter().enumerate() {
+        recorder.measure(&mut env, "invest", ix::invest(&wallet, reit_id_hash, &usdc_mint, &escrow_vault, amount, counter as u64), &[&investor]);
+    }
+
+    env.execute(ix::set_fundraiser_status(&admin.pubkey(), reit_id_hash, FundraiserStatus::Closed), &[&admin]);
+    env.execute(ix::set_fundraiser_status(&admin.pubkey(), reit_id_hash, FundraiserStatus::Funded), &[&admin]);
+
+    env.fund_ata(&usdc_mint, &admin.pubkey(), 100 * USDC);
+    for investment in &investments {
+        recorder.measure(&mut env, "release", ix::release(&admin.pubkey(), reit_id_hash, investment, &usdc_mint, &escrow_vault), &[&admin]);
+    }
+    recorder.measure(&mut env, "wire", ix::wire(&admin.pubkey(), reit_id_hash, &investments[0], 136_250, 1_362_500, START_TIME, [9u8; 32]), &[&admin]);
+    let batch_reference = [4u8; 32];
+    recorder.measure(
+        &mut env,
+        "wire_batch",
+        ix::wire_batch(&admin.pubkey(), reit_id_hash, &investments[2..], 54_500, 1_362_500, START_TIME, batch_reference),
+        &[&admin],
+    );
+    recorder.measure(&mut env, "refund", ix::refund(&admin.pubkey(), reit_id_hash, &investments[1], &wallet, &usdc_mint, &escrow_vault), &[&admin]);
+    recorder.measure(&mut env, "close_investment", ix::close_investment(&wallet, &fundraiser, &investments[1], true), &[&investor]);
+
+    let reit_mint = Keypair::new();
+    let reit_mint_key = reit_mint.pubkey();
+    recorder.measure(
+        &mut env,
+        "create_reit_mint_2022",
+        ix::create_reit_mint_2022(
+            &admin.pubkey(),
+            reit_id_hash,
+            &reit_mint_key,
+            &TRANSFER_HOOK_ID,
+            "Test REIT".to_string(),
+            "TREIT".to_string(),
+            "https://example.com/reit.json".to_string(),
+        ),
+        &[&admin, &reit_mint],
+    );
+    recorder.measure(
+        &mut env,
+        "update_reit_mint",
+        ix::update_reit_mint(
+            &admin.pubkey(),
+            reit_id_hash,
+            &reit_mint_key,
+            &spl_token_2022::ID,
+            "Renamed Canadian Residential REIT".to_string(),
+            "RCREIT".to_string(),
+            "https://example.com/renamed.json".to_string(),
+        ),
+        &[&admin],
+    );
+    recorder.measure(
+        &mut env,
+        "issue_share",
+        ix::issue_share(&admin.pubkey(), reit_id_hash, &investments[0], &wallet, &reit_mint_key, &spl_token_2022::ID, false, None, 1_362),
+        &[&admin],
+    );
+
+    let publisher = Keypair::new();
+    env.svm.airdrop(&publisher.pubkey(), 1_000_000_000).unwrap();
+    recorder.measure(&mut env, "initialize_fx_rate", ix::initialize_fx_rate(&admin.pubkey(), *b"USD", *b"CAD", &publisher.pubkey(), 3_600), &[&admin]);
+    recorder.measure(
+        &mut env,
+        "publish_fx_rate",
+        ix::publish_fx_rate(&publisher.pubkey(), *b"USD", *b"CAD", 1_250_000, 1_000, START_TIME),
+        &[&publisher],
+    );
+    recorder.measure(
+        &mut env,
+        "set_fx_rate_publisher",
+        ix::set_fx_rate_publisher(&admin.pubkey(), *b"USD", *b"CAD", &publisher.pubkey()),
+        &[&admin],
+    );
+    let fx_rate = pda::fx_rate(b"USD", b"CAD");
+    recorder.measure(
+        &mut env,
+        "issue_share",
+        ix::issue_share(&admin.pubkey(), reit_id_hash, &investments[2], &wallet, &reit_mint_key, &spl_token_2022::ID, false, Some(fx_rate), 2_500),
+        &[&admin],
+    );
+
+    env.execute(ix::set_fundraiser_status(&admin.pubkey(), reit_id_hash, FundraiserStatus::Operating), &[&admin]);
+    recorder.measure(&mut env, "issue_dividend", ix::issue_dividend(&admin.pubkey(), reit_id_hash, &investments[0], &wallet, &usdc_mint, 5 * USDC), &[&admin]);
+
+    let holder_shares = pda::associated_token_account(&wallet, &reit_mint_key, &spl_token_2022::ID);
+    recorder.measure(
+        &mut env,
+        "freeze_holder",
+        ix::freeze_holder(&compliance.pubkey(), reit_id_hash, &reit_mint_key, &holder_shares, &spl_token_2022::ID, [1u8; 32]),
+        &[&compliance],
+    );
+    recorder.measure(
+        &mut env,
+        "thaw_holder",
+        ix::thaw_holder(&compliance.pubkey(), reit_id_hash, &reit_mint_key, &holder_shares, &spl_token_2022::ID, [1u8; 32]),
+        &[&compliance],
+    );
+
+    // A holder past the hold period transfers to another verified investor through the hook
+    env.warp_to(START_TIME + 31 * DAY);
+    let recipient = env.onboard_investor(0).pubkey();
+    let recipient_shares = pda::associated_token_account(&recipient, &reit_mint_key, &spl_token_2022::ID);
+    env.execute_admin(spl_associated_token_account::instruction::create_associated_token_account(
+        &admin.pubkey(),
+        &recipient,
+        &reit_mint_key,
+        &spl_token_2022::ID,
+    ));
+    let mut transfer =
+        spl_token_2022::instruction::transfer_checked(&spl_token_2022::ID, &holder_shares, &reit_mint_key, &recipient_shares, &wallet, &[], 10, 0)
+            .unwrap();
+    // The hook's extra accounts, then the hook program and its account list, as Token-2022 resolves them
+    transfer.accounts.extend([
+        AccountMeta::new_readonly(canadianreitinvest::ID, false),
+        AccountMeta::new_readonly(pda::investor(&wallet), false),
+        AccountMeta::new_readonly(pda::kyc_attestation(&wallet), false),
+        AccountMeta::new_readonly(pda::investor(&recipient), false),
+        AccountMeta::new_readonly(pda::kyc_attestation(&recipient), false),
+        AccountMeta::new_readonly(pda::holder_lockup(&reit_mint_key, &wallet), false),
+        AccountMeta::new_readonly(TRANSFER_HOOK_ID, false),
+        AccountMeta::new_readonly(pda::extra_account_meta_list(&reit_mint_key, &TRANSFER_HOOK_ID), false),
+    ]);
+    recorder.measure(&mut env, "transfer_hook", transfer, &[&investor]);
+
+    let new_owner = env.onboard_investor(0).pubkey();
+    recorder.measure(
+        &mut env,
+        "recover_position",
+        ix::recover_position(
+            &compliance.pubkey(),
+            &admin.pubkey(),
+            reit_id_hash,
+            &investments[0],
+            &wallet,
+            &reit_mint_key,
+            &spl_token_2022::ID,
+            &holder_shares,
+            &new_owner,
+            [2u8; 32],
+        ),
+        &[&compliance, &admin],
+    );
+
+    let lockup_until = env.account::<state::Investment>(&investments[0]).lockup_until;
+    env.warp_to(lockup_until);
+    recorder.measure(&mut env, "record_share_sale", ix::record_share_sale(&admin.pubkey(), reit_id_hash, &investments[0]), &[&admin]);
+
+    // Account types created after the deployed baseline have nothing to migrate from, so their
+    // handlers only ever reject accounts that are already current
+    let current_accounts: [(&'static str, MigrateBuilder, Pubkey); 5] = [
+        ("migrate_compliance_config", ix::migrate_compliance_config, pda::compliance_config()),
+        ("migrate_kyc_attestation", ix::migrate_kyc_attestation, pda::kyc_attestation(&wallet)),
+        ("migrate_holder_lockup", ix::migrate_holder_lockup, pda::holder_lockup(&reit_mint_key, &wallet)),
+        ("migrate_wire_batch_record", ix::migrate_wire_batch_record, pda::wire_batch(&fundraiser, &batch_reference)),
+        ("migrate_fx_rate", ix::migrate_fx_rate, fx_rate),
+    ];
+    for (instruction, builder, account) in current_accounts {
+        recorder.measure_rejected(&mut env, instruction, builder(&admin.pubkey(), &account), &[&admin], CustomError::AccountAlreadyMigrated);
+    }
+
+    let successor = Keypair::new();
+    recorder.measure(&mut env, "set_compliance_authority", ix::set_compliance_authority(&compliance.pubkey(), &successor.pubkey()), &[&compliance]);
+}
+
+/// A raise that misses its minimum, is refunded, and is closed down with its investor.
+fn failed_raise(recorder: &mut Recorder, build: &Build) {
+    let mut env = new_env(build);
+    initialize_compliance(&mut env);
+    let admin = env.admin.insecure_clone();
+    let usdc_mint = env.usdc_mint;
+    let fundraiser = env.open_fundraiser(10_000 * USDC, 0, 0);
+    let hash = fundraiser.reit_id_hash;
+    let investor = env.onboard_investor(100 * USDC);
+    let wallet = investor.pubkey();
+    let investment = env.invest(&investor, &fundraiser, 100 * USDC);
+
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Failed);
+    env.execute_admin(env.release_ix(&fundraiser, &investment));
+    env.execute_admin(ix::refund(&admin.pubkey(), hash, &investment, &wallet, &usdc_mint, &fundraiser.escrow_vault));
+    env.execute(ix::close_investment(&wallet, &fundraiser.address, &investment, false), &[&investor]);
+    env.set_status(&fundraiser, FundraiserStatus::Terminated);
+
+    recorder.measure(&mut env, "close_fundraiser", ix::close_fundraiser(&admin.pubkey(), hash, &[]), &[&admin]);
+    recorder.measure(&mut env, "close_investor", ix::close_investor(&wallet), &[&investor]);
+}
+
+/// A classic SPL mint: Metaplex metadata, shares held in the lock vault, unlocked after the
+/// hold period and recovered by freezing.
+fn classic_mint(recorder: &mut Recorder, build: &Build) {
+    let mut env = new_env(build);
+    initialize_compliance(&mut env);
+    let admin = env.admin.insecure_clone();
+    let compliance = env.compliance.insecure_clone();
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    let wallet = raise.investor.pubkey();
+    let hash = raise.fundraiser.reit_id_hash;
+    let investment = raise.investments[0];
+
+    let reit_mint = Keypair::new();
+    let reit_mint_key = reit_mint.pubkey();
+    recorder.measure(
+        &mut env,
+        "create_reit_mint",
+        ix::create_reit_mint(
+            &admin.pubkey(),
+            hash,
+            &reit_mint_key,
+            "Test REIT".to_string(),
+            "TREIT".to_string(),
+            "https://example.com/reit.json".to_string(),
+        ),
+        &[&admin, &reit_mint],
+    );
+    raise.fundraiser.reit_mint = reit_mint_key;
+    raise.fundraiser.reit_token_program = spl_token::ID;
+    recorder.measure(
+        &mut env,
+        "update_reit_mint",
+        ix::update_reit_mint(
+            &admin.pubkey(),
+            hash,
+            &reit_mint_key,
+            &spl_token::ID,
+            "Renamed REIT".to_string(),
+            "RREIT".to_string(),
+            "https://example.com/renamed.json".to_string(),
+        ),
+        &[&admin],
+    );
+    let issue_share = env.issue_share_ix(&raise.fundraiser, &investment, &wallet, common::USDC_SHARE_PRICE);
+    recorder.measure(&mut env, "issue_share", issue_share, &[&admin]);
+
+    let lockup_until = env.account::<state::Investment>(&investment).lockup_until;
+    env.warp_to(lockup_until);
+    recorder.measure(
+        &mut env,
+        "unlock_shares",
+        ix::unlock_shares(&wallet, hash, &investment, &admin.pubkey(), &reit_mint_key, &spl_token::ID),
+        &[&raise.investor],
+    );
+
+    let new_owner = env.onboard_investor(0).pubkey();
+    let holder_shares = pda::associated_token_account(&wallet, &reit_mint_key, &spl_token::ID);
+    recorder.measure(
+        &mut env,
+        "recover_position",
+        ix::recover_position(
+            &compliance.pubkey(),
+            &admin.pubkey(),
+            hash,
+            &investment,
+            &wallet,
+            &reit_mint_key,
+            &spl_token::ID,
+            &holder_shares,
+            &new_owner,
+            [2u8; 32],
+        ),
+        &[&compliance, &admin],
+    );
+}
+
+/// Accounts in the deployed baseline's layouts, rewritten in the current ones.
+fn migration(recorder: &mut Recorder, build: &Build) {
+    let mut env = new_env(build);
+    initialize_compliance(&mut env);
+    let admin = env.admin.insecure_clone();
+    let raise = env.funded_raise(&[100 * USDC, 50 * USDC]);
+    let wallet = raise.investor.pubkey();
+    let fundraiser = raise.fundraiser.address;
+    env.write_baseline_raise(&raise);
+
+    recorder.measure(&mut env, "migrate_fundraiser", ix::migrate_fundraiser(&admin.pubkey(), &fundraiser, raise.fundraiser.reit_id.clone()), &[&admin]);
+    recorder.measure(&mut env, "migrate_investor", ix::migrate_investor(&admin.pubkey(), &pda::investor(&wallet)), &[&admin]);
+    recorder.measure(
+        &mut env,
+        "migrate_investor_fundraiser",
+        ix::migrate_investor_fundraiser(&admin.pubkey(), &pda::investor_fundraiser(&wallet, &fundraiser)),
+        &[&admin],
+    );
+    for investment in &raise.investments {
+        recorder.measure(&mut env, "migrate_investment", ix::migrate_investment(&admin.pubkey(), investment, &wallet, &fundraiser), &[&admin]);
+    }
+}
+
+fn run_all(build: &Build) -> Vec<Measurement> {
+    let mut recorder = Recorder::default();
+    offering(&mut recorder, build);
+    failed_raise(&mut recorder, build);
+    classic_mint(&mut recorder, build);
+    migration(&mut recorder, build);
+    recorder.measurements
+}
+
+/// Lines a release build may log: the invoke/success/consumed lines the runtime writes, the
+/// instruction name Anchor logs, Anchor's error report, and `Program data:` lines carrying events.
+fn is_release_log(line: &str) -> bool {
+    match line.strip_prefix("Program log: ") {
+        Some(message) => message.starts_with("Instruction: ") || message.starts_with("AnchorError "),
+        None => true,
+    }
+}
+
+#[test]
+#[ignore = "needs `anchor build`, `npm run anchor-build-debug-logs` and `npm run anchor-fixtures`"]
+fn release_build_fits_compute_budget() {
+    let metadata = read_fixture(METADATA_FIXTURE);
+    let release = Build { program: read_program(PROGRAM), transfer_hook: read_program(TRANSFER_HOOK_PROGRAM), metadata: metadata.clone() };
+    let debug_logs =
+        Build { program: read_program(DEBUG_LOGS_PROGRAM), transfer_hook: read_program(DEBUG_LOGS_TRANSFER_HOOK_PROGRAM), metadata };
+
+    let release_run = run_all(&release);
+    let debug_run = run_all(&debug_logs);
+    assert_eq!(release_run.len(), debug_run.len());
+    for instruction in INSTRUCTIONS {
+        assert!(release_run.iter().any(|measurement| measurement.instruction == instruction), "{instruction} is never measured");
+    }
+
+    let own_programs = [canadianreitinvest::ID.to_string(), TRANSFER_HOOK_ID.to_string()];
+    println!("{:<28} {:>10} {:>12}", "instruction", "release", "debug-logs");
+    for (release, debug) in release_run.iter().zip(&debug_run) {
+        assert_eq!(release.instruction, debug.instruction);
+        println!("{:<28} {:>10} {:>12}", release.instruction, release.compute_units, debug.compute_units);
+
+        assert!(
+            release.compute_units <= CU_BUDGET,
+            "{} used {} CU, over the {} CU budget",
+            release.instruction,
+            release.compute_units,
+            CU_BUDGET
+        );
+        assert!(
+            release.compute_units <= debug.compute_units,
+            "{} costs more without debug logs ({} > {})",
+            release.instruction,
+            release.compute_units,
+            debug.compute_units
+        );
+        // Logs from the token and metadata programs are theirs to keep; only check our own
+        let mut invoked: Vec<&str> = Vec::new();
+        for line in &release.logs {
+            let mut words = line.split(' ');
+            if let (Some("Program"), Some(program_id), Some(action)) = (words.next(), words.next(), words.next()) {
+                match action {
+                    "invoke" => {
+                        invoked.push(program_id);
+                        continue;
+                    }
+                    "success" | "failed:" => {
+                        invoked.pop();
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            if invoked.last().is_some_and(|program_id| own_programs.iter().any(|own| own == program_id)) {
+                assert!(is_release_log(line), "{} logged `{}` in a release build", release.instruction, line);
+            }
+        }
+    }
+}
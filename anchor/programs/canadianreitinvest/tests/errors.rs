@@ -0,0 +1,656 @@
+//! Every `CustomError` variant, each triggered through the instruction that returns it.
+//!
+//! Tests are in the enum's order. Needs `anchor build`; the classic SPL mint cases also need
+//! the Metaplex fixture from `npm run anchor-fixtures`. States that no instruction sequence
+//! reaches within a test (counters at their limits) are forged with `TestEnv::modify_account`.
+
+mod common;
+
+use anchor_lang::InstructionData;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use canadianreitinvest::errors::CustomError;
+use canadianreitinvest::state::{self, AccreditationCategory, FundraiserStatus, KycStatus};
+use canadianreitinvest_client::{instructions as ix, pda};
+use common::{TestEnv, DAY, ONTARIO, START_TIME, USDC};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+/// `InsufficientFunds`, `EscrowNotInitialized` and `RecoveryNotSupported` are no longer
+/// returned: transfers from an underfunded account fail in the token program first, every
+/// escrow vault is created together with its fundraiser or accepted mint, and positions of
+/// every REIT mint can be recovered. They stay in the enum so that every later variant keeps
+/// the code clients already match on.
+#[test]
+fn retired_variants_keep_their_codes() {
+    assert_eq!(u32::from(CustomError::InsufficientFunds), 6000);
+    assert_eq!(u32::from(CustomError::EscrowNotInitialized), 6003);
+    assert_eq!(u32::from(CustomError::RecoveryNotSupported), 6019);
+    assert_eq!(u32::from(CustomError::InvestorHasOpenPositions), 6034);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_amount() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 0), &[&investor], CustomError::InvalidAmount);
+
+    let admin = env.admin.pubkey();
+    env.expect_admin_error(ix::set_hold_period(&admin, fundraiser.reit_id_hash, -1), CustomError::InvalidAmount);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_authority() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.create_fundraiser();
+    let outsider = Keypair::new();
+    env.svm.airdrop(&outsider.pubkey(), 1_000_000_000).unwrap();
+
+    let open = ix::set_fundraiser_status(&outsider.pubkey(), fundraiser.reit_id_hash, FundraiserStatus::Open);
+    env.expect_error(open, &[&outsider], CustomError::InvalidAuthority);
+    let attest = ix::attest_investor(
+        &outsider.pubkey(),
+        &outsider.pubkey(),
+        KycStatus::Verified,
+        ONTARIO,
+        START_TIME + DAY,
+        AccreditationCategory::Accredited,
+        [0u8; 32],
+    );
+    env.expect_error(attest, &[&outsider], CustomError::InvalidAuthority);
+
+    // Only the upgrade authority names FX publishers, and only the named publisher publishes
+    let admin = env.admin.pubkey();
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &admin, 3_600));
+    let rotate = ix::set_fx_rate_publisher(&outsider.pubkey(), *b"USD", *b"CAD", &outsider.pubkey());
+    env.expect_error(rotate, &[&outsider], CustomError::InvalidAuthority);
+    let publish = ix::publish_fx_rate(&outsider.pubkey(), *b"USD", *b"CAD", 1_250_000, 0, START_TIME);
+    env.expect_error(publish, &[&outsider], CustomError::InvalidAuthority);
+
+    // Shares can't be minted to a verified wallet other than the investment's investor
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    let other = env.onboard_investor(0);
+    let issue = env.issue_share_ix(&raise.fundraiser, &raise.investments[0], &other.pubkey(), common::USDC_SHARE_PRICE);
+    env.expect_admin_error(issue, CustomError::InvalidAuthority);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investment_counter_overflow() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(200 * USDC);
+    let wallet = investor.pubkey();
+    env.invest(&investor, &fundraiser, 100 * USDC);
+
+    env.modify_account(&pda::investor_fundraiser(&wallet, &fundraiser.address), |record: &mut state::InvestorFundraiser| {
+        record.investment_counter = u64::MAX;
+    });
+    env.expect_error(env.invest_ix(&wallet, &fundraiser, 100 * USDC), &[&investor], CustomError::InvestmentCounterOverflow);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn arithmetic_overflow() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+
+    env.modify_account(&fundraiser.address, |fundraiser: &mut state::Fundraiser| {
+        fundraiser.total_raised = u64::MAX - 1;
+    });
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::ArithmeticOverflow);
+
+    // More shares than fit in an investment's reit_amount
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    env.modify_account(&raise.investments[0], |investment: &mut state::Investment| {
+        investment.usdc_amount = u64::MAX / 2;
+    });
+    let issue = env.issue_share_ix(&raise.fundraiser, &raise.investments[0], &raise.investor.pubkey(), 1);
+    env.expect_admin_error(issue, CustomError::ArithmeticOverflow);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_reit_id_hash() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let (reit_id, _) = common::new_reit_id();
+    let (other_reit_id, other_hash) = common::new_reit_id();
+
+    // Accounts for `other_reit_id`'s PDA, but registering a different id under it
+    let mut initialize = ix::initialize_fundraiser(&admin, &usdc_mint, &other_reit_id, *b"CAD").unwrap();
+    initialize.data = canadianreitinvest::instruction::InitializeFundraiser {
+        reit_id,
+        reit_id_hash: other_hash,
+        reit_accepted_currency: *b"CAD",
+    }
+    .data();
+    env.expect_admin_error(initialize, CustomError::InvalidReitIdHash);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_investment_status() {
+    let mut env = TestEnv::load();
+    let raise = env.funded_raise(&[100 * USDC]);
+    let release = env.release_ix(&raise.fundraiser, &raise.investments[0]);
+    env.execute_admin(release.clone());
+    env.expect_admin_error(release.clone(), CustomError::InvalidInvestmentStatus);
+
+    // The log names the current status and the ones release accepts
+    let admin = env.admin.insecure_clone();
+    let logs = env.send(&[release], &[&admin]).unwrap_err().meta.logs.join("\n");
+    assert!(logs.contains("Program log: Left:\nProgram log: Released\nProgram log: Right:\nProgram log: [Pending]"), "{logs}");
+
+    // Only issued shares can be sold
+    env.expect_admin_error(ix::record_share_sale(&admin.pubkey(), raise.fundraiser.reit_id_hash, &raise.investments[0]), CustomError::InvalidInvestmentStatus);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_mint() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let fundraiser = env.create_fundraiser();
+
+    // Every accepted stablecoin must share the primary mint's decimals
+    let nine_decimals = env.create_mint(9);
+    env.expect_admin_error(ix::add_accepted_mint(&admin, fundraiser.reit_id_hash, &usdc_mint, &nine_decimals, *b"USD"), CustomError::InvalidMint);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_fundraiser_mismatch() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let raise = env.funded_raise(&[100 * USDC]);
+    let other = env.open_fundraiser(0, 0, 0);
+    env.fund_ata(&usdc_mint, &raise.investor.pubkey(), 50 * USDC);
+    let foreign = env.invest(&raise.investor, &other, 50 * USDC);
+
+    let batch = ix::wire_batch(&admin, raise.fundraiser.reit_id_hash, &[foreign], 6_812, 1_362_500, START_TIME, [3u8; 32]);
+    env.expect_admin_error(batch, CustomError::InvalidFundraiserMismatch);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn kyc_not_verified() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    env.attest(&investor.pubkey(), KycStatus::Revoked, ONTARIO, START_TIME + 365 * DAY, AccreditationCategory::Accredited);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::KycNotVerified);
+
+    // A recovered position can only go to an owner with a valid KYC attestation
+    let (admin, compliance) = (env.admin.insecure_clone(), env.compliance.insecure_clone());
+    let raise = env.issued_raise(&[100 * USDC]);
+    let old_owner = raise.investor.pubkey();
+    let holder_shares = pda::associated_token_account(&old_owner, &raise.fundraiser.reit_mint, &spl_token_2022::ID);
+    let new_owner = env.onboard_investor(0).pubkey();
+    env.attest(&new_owner, KycStatus::Revoked, ONTARIO, START_TIME + 365 * DAY, AccreditationCategory::Accredited);
+    let recover = ix::recover_position(
+        &compliance.pubkey(),
+        &admin.pubkey(),
+        raise.fundraiser.reit_id_hash,
+        &raise.investments[0],
+        &old_owner,
+        &raise.fundraiser.reit_mint,
+        &spl_token_2022::ID,
+        &holder_shares,
+        &new_owner,
+        [2u8; 32],
+    );
+    env.expect_error(recover, &[&compliance, &admin], CustomError::KycNotVerified);
+
+    // Dividends go out on the holder's own attestation only; another investor's can't stand in for it
+    env.set_status(&raise.fundraiser, FundraiserStatus::Operating);
+    let usdc_mint = env.usdc_mint;
+    let dividend = ix::issue_dividend(&admin.pubkey(), raise.fundraiser.reit_id_hash, &raise.investments[0], &old_owner, &usdc_mint, USDC);
+    let mut substituted = dividend.clone();
+    let verified = env.onboard_investor(0).pubkey();
+    substituted.accounts[4].pubkey = pda::kyc_attestation(&verified);
+    let failed = env.send(&[substituted], &[&admin]).unwrap_err();
+    assert_eq!(common::custom_error_code(&failed.err), Some(anchor_lang::error::ErrorCode::ConstraintSeeds.into()));
+    env.attest(&old_owner, KycStatus::Revoked, ONTARIO, START_TIME + 365 * DAY, AccreditationCategory::Accredited);
+    env.expect_admin_error(dividend, CustomError::KycNotVerified);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn kyc_expired() {
+    let mut env = TestEnv::load();
+    let compliance = env.compliance.pubkey();
+    let wallet = Keypair::new().pubkey();
+    let already_expired =
+        ix::attest_investor(&compliance, &wallet, KycStatus::Verified, ONTARIO, START_TIME, AccreditationCategory::Accredited, [0u8; 32]);
+    env.expect_compliance_error(already_expired, CustomError::KycExpired);
+
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    env.warp_to(START_TIME + 365 * DAY);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::KycExpired);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investor_not_eligible() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    let no_limits = state::InvestmentLimits { non_eligible: 0, eligible: 0 };
+    env.execute_admin(ix::set_offering_exemption(&admin, fundraiser.reit_id_hash, state::OfferingExemption::AccreditedInvestor, no_limits));
+    env.set_status(&fundraiser, FundraiserStatus::Open);
+
+    let investor = env.onboard_investor_as(AccreditationCategory::Eligible, ONTARIO, 100 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::InvestorNotEligible);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investment_limit_exceeded() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    let limits = state::InvestmentLimits { non_eligible: 10 * USDC, eligible: 50 * USDC };
+    env.execute_admin(ix::set_offering_exemption(&admin, fundraiser.reit_id_hash, state::OfferingExemption::OfferingMemorandum, limits));
+    env.set_status(&fundraiser, FundraiserStatus::Open);
+
+    let investor = env.onboard_investor_as(AccreditationCategory::Eligible, ONTARIO, 100 * USDC);
+    env.invest(&investor, &fundraiser, 30 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 30 * USDC), &[&investor], CustomError::InvestmentLimitExceeded);
+
+    // Twelve buckets on, less than a year has passed and the first investment still counts
+    let twelve_buckets_on = (START_TIME.div_euclid(state::ROLLING_BUCKET_SECONDS) + 12) * state::ROLLING_BUCKET_SECONDS;
+    assert!(twelve_buckets_on < START_TIME + 365 * DAY);
+    env.warp_to(twelve_buckets_on);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 30 * USDC), &[&investor], CustomError::InvestmentLimitExceeded);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn jurisdiction_blocked() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    let british_columbia = state::Jurisdiction { country: *b"CA", subdivision: *b"BC\0" };
+    env.execute_admin(ix::set_jurisdiction_rules(&admin, fundraiser.reit_id_hash, state::JurisdictionMode::Allowlist, vec![british_columbia]));
+    env.set_status(&fundraiser, FundraiserStatus::Open);
+
+    let investor = env.onboard_investor(100 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::JurisdictionBlocked);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn too_many_jurisdiction_rules() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    let hash = fundraiser.reit_id_hash;
+    let countries: Vec<state::Jurisdiction> = (0..=state::MAX_JURISDICTION_RULES as u8)
+        .map(|index| state::Jurisdiction { country: [b'A' + index / 26, b'A' + index % 26], subdivision: [0u8; 3] })
+        .collect();
+    let (full, extra) = countries.split_at(state::MAX_JURISDICTION_RULES);
+
+    env.expect_admin_error(
+        ix::set_jurisdiction_rules(&admin, hash, state::JurisdictionMode::Denylist, countries.clone()),
+        CustomError::TooManyJurisdictionRules,
+    );
+    env.execute_admin(ix::set_jurisdiction_rules(&admin, hash, state::JurisdictionMode::Denylist, full.to_vec()));
+    env.expect_admin_error(ix::add_jurisdiction_rule(&admin, hash, extra[0]), CustomError::TooManyJurisdictionRules);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn jurisdiction_rule_not_found() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    env.expect_admin_error(ix::remove_jurisdiction_rule(&admin, fundraiser.reit_id_hash, ONTARIO), CustomError::JurisdictionRuleNotFound);
+}
+
+#[test]
+#[ignore = "needs `anchor build` and `npm run anchor-fixtures`"]
+fn hold_period_active() {
+    let mut env = TestEnv::load_with_metadata();
+    let admin = env.admin.pubkey();
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint(&mut raise.fundraiser);
+    let (wallet, investment) = (raise.investor.pubkey(), raise.investments[0]);
+    env.execute_admin(env.issue_share_ix(&raise.fundraiser, &investment, &wallet, common::USDC_SHARE_PRICE));
+
+    let unlock = ix::unlock_shares(&wallet, raise.fundraiser.reit_id_hash, &investment, &admin, &raise.fundraiser.reit_mint, &spl_token::ID);
+    env.expect_error(unlock, &[&raise.investor], CustomError::HoldPeriodActive);
+    env.expect_admin_error(ix::record_share_sale(&admin, raise.fundraiser.reit_id_hash, &investment), CustomError::HoldPeriodActive);
+}
+
+#[test]
+#[ignore = "needs `anchor build` and `npm run anchor-fixtures`"]
+fn lock_vault_required() {
+    let mut env = TestEnv::load_with_metadata();
+    let admin = env.admin.pubkey();
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint(&mut raise.fundraiser);
+    let fundraiser = &raise.fundraiser;
+
+    let without_lock_vault = ix::issue_share(
+        &admin,
+        fundraiser.reit_id_hash,
+        &raise.investments[0],
+        &raise.investor.pubkey(),
+        &fundraiser.reit_mint,
+        &spl_token::ID,
+        false,
+        None,
+        common::USDC_SHARE_PRICE,
+    );
+    env.expect_admin_error(without_lock_vault, CustomError::LockVaultRequired);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_wire_details() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let raise = env.funded_raise(&[100 * USDC]);
+    let (hash, investment) = (raise.fundraiser.reit_id_hash, raise.investments[0]);
+    env.execute_admin(env.release_ix(&raise.fundraiser, &investment));
+
+    env.expect_admin_error(env.wire_ix(&raise.fundraiser, &investment, 0), CustomError::InvalidWireDetails);
+    let settles_tomorrow = ix::wire(&admin, hash, &investment, 13_625, 1_362_500, START_TIME + DAY, [9u8; 32]);
+    env.expect_admin_error(settles_tomorrow, CustomError::InvalidWireDetails);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_wire_batch() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let raise = env.funded_raise(&[100 * USDC]);
+    let (hash, investment) = (raise.fundraiser.reit_id_hash, raise.investments[0]);
+    env.execute_admin(env.release_ix(&raise.fundraiser, &investment));
+
+    env.expect_admin_error(ix::wire_batch(&admin, hash, &[], 13_625, 1_362_500, START_TIME, [3u8; 32]), CustomError::InvalidWireBatch);
+    let duplicated = ix::wire_batch(&admin, hash, &[investment, investment], 13_625, 1_362_500, START_TIME, [3u8; 32]);
+    env.expect_admin_error(duplicated, CustomError::InvalidWireBatch);
+
+    // One wire converts one stablecoin, so a batch can't mix deposit mints
+    let usdc_mint = env.usdc_mint;
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let hash = fundraiser.reit_id_hash;
+    let usdt = env.create_mint(common::USDC_DECIMALS);
+    let usdt_vault = pda::accepted_mint_escrow_vault(&fundraiser.address, &usdt);
+    env.execute_admin(ix::add_accepted_mint(&admin, hash, &usdc_mint, &usdt, *b"USD"));
+    let investor = env.onboard_investor(100 * USDC);
+    let wallet = investor.pubkey();
+    let usdc_investment = env.invest(&investor, &fundraiser, 100 * USDC);
+    env.fund_ata(&usdt, &wallet, 100 * USDC);
+    env.execute(ix::invest(&wallet, hash, &usdt, &usdt_vault, 100 * USDC, 1), &[&investor]);
+    let usdt_investment = pda::investment(&wallet, &fundraiser.address, 1);
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Funded);
+    env.execute_admin(env.release_ix(&fundraiser, &usdc_investment));
+    env.execute_admin(ix::release(&admin, hash, &usdt_investment, &usdt, &usdt_vault));
+    let mixed = ix::wire_batch(&admin, hash, &[usdc_investment, usdt_investment], 27_250, 1_362_500, START_TIME, [3u8; 32]);
+    env.expect_admin_error(mixed, CustomError::InvalidWireBatch);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_fx_rate() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    env.expect_admin_error(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &admin, 0), CustomError::InvalidFxRate);
+    env.expect_admin_error(ix::initialize_fx_rate(&admin, *b"CAD", *b"CAD", &admin, 3_600), CustomError::InvalidFxRate);
+
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &admin, 3_600));
+    env.expect_admin_error(ix::publish_fx_rate(&admin, *b"USD", *b"CAD", 0, 0, START_TIME), CustomError::InvalidFxRate);
+
+    // The rate must price the deposit's currency, USD for USDC, in the fundraiser currency
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"EUR", *b"CAD", &admin, 3_600));
+    env.execute_admin(ix::publish_fx_rate(&admin, *b"EUR", *b"CAD", 1_500_000, 0, START_TIME));
+    let issue = ix::issue_share(
+        &admin,
+        raise.fundraiser.reit_id_hash,
+        &raise.investments[0],
+        &raise.investor.pubkey(),
+        &raise.fundraiser.reit_mint,
+        &spl_token_2022::ID,
+        false,
+        Some(pda::fx_rate(b"EUR", b"CAD")),
+        2_500,
+    );
+    env.expect_admin_error(issue, CustomError::InvalidFxRate);
+}
+
+/// A wired Token-2022 raise with a USD/CAD rate published by the admin, `max_staleness` seconds fresh.
+fn priced_raise(env: &mut TestEnv, confidence: u64, max_staleness: i64) -> common::Raise {
+    let admin = env.admin.pubkey();
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &admin, max_staleness));
+    env.execute_admin(ix::publish_fx_rate(&admin, *b"USD", *b"CAD", 1_250_000, confidence, START_TIME));
+    raise
+}
+
+fn issue_share_at_fx_rate(env: &TestEnv, raise: &common::Raise) -> anchor_lang::solana_program::instruction::Instruction {
+    ix::issue_share(
+        &env.admin.pubkey(),
+        raise.fundraiser.reit_id_hash,
+        &raise.investments[0],
+        &raise.investor.pubkey(),
+        &raise.fundraiser.reit_mint,
+        &spl_token_2022::ID,
+        false,
+        Some(pda::fx_rate(b"USD", b"CAD")),
+        2_500,
+    )
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn stale_fx_rate() {
+    let mut env = TestEnv::load();
+    let raise = priced_raise(&mut env, 1_000, 3_600);
+    env.warp_to(START_TIME + 3_601);
+    env.expect_admin_error(issue_share_at_fx_rate(&env, &raise), CustomError::StaleFxRate);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn fx_rate_confidence_too_wide() {
+    let mut env = TestEnv::load();
+    // +/- 0.025 on 1.25 is 200 bps, twice the allowed interval
+    let raise = priced_raise(&mut env, 25_000, 3_600);
+    env.expect_admin_error(issue_share_at_fx_rate(&env, &raise), CustomError::FxRateConfidenceTooWide);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn unsupported_currency() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let (reit_id, _) = common::new_reit_id();
+    // JPY has no minor units, so cent amounts can't be expressed in it
+    env.expect_admin_error(ix::initialize_fundraiser(&admin, &usdc_mint, &reit_id, *b"JPY").unwrap(), CustomError::UnsupportedCurrency);
+
+    // The same goes for the currency a stablecoin is pegged to
+    let fundraiser = env.create_fundraiser();
+    let jpyc = env.create_mint(common::USDC_DECIMALS);
+    env.expect_admin_error(ix::add_accepted_mint(&admin, fundraiser.reit_id_hash, &usdc_mint, &jpyc, *b"JPY"), CustomError::UnsupportedCurrency);
+
+    // And to either side of an FX pair
+    env.expect_admin_error(ix::initialize_fx_rate(&admin, *b"JPY", *b"CAD", &admin, 3_600), CustomError::UnsupportedCurrency);
+    env.expect_admin_error(ix::initialize_fx_rate(&admin, *b"USD", *b"JPY", &admin, 3_600), CustomError::UnsupportedCurrency);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn fundraiser_terms_locked() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    env.expect_admin_error(
+        ix::update_fundraiser_terms(&admin, fundraiser.reit_id_hash, *b"CAD", 0, 1_000 * USDC, 0),
+        CustomError::FundraiserTermsLocked,
+    );
+
+    // Investments are counted under the exemption they were made under, so it can't change after one
+    let investor = env.onboard_investor(100 * USDC);
+    env.invest(&investor, &fundraiser, 100 * USDC);
+    let limits = state::InvestmentLimits { non_eligible: 10 * USDC, eligible: 50 * USDC };
+    env.expect_admin_error(
+        ix::set_offering_exemption(&admin, fundraiser.reit_id_hash, state::OfferingExemption::OfferingMemorandum, limits),
+        CustomError::FundraiserTermsLocked,
+    );
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn too_many_accepted_mints() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let fundraiser = env.create_fundraiser();
+    for _ in 1..state::MAX_ACCEPTED_MINTS {
+        let mint = env.create_mint(common::USDC_DECIMALS);
+        env.execute_admin(ix::add_accepted_mint(&admin, fundraiser.reit_id_hash, &usdc_mint, &mint, *b"USD"));
+    }
+    let one_too_many = env.create_mint(common::USDC_DECIMALS);
+    env.expect_admin_error(ix::add_accepted_mint(&admin, fundraiser.reit_id_hash, &usdc_mint, &one_too_many, *b"USD"), CustomError::TooManyAcceptedMints);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_fundraiser_status() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.create_fundraiser();
+    let investor = env.onboard_investor(100 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::InvalidFundraiserStatus);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn invalid_fundraiser_transition() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    env.expect_admin_error(
+        ix::set_fundraiser_status(&admin, fundraiser.reit_id_hash, FundraiserStatus::Funded),
+        CustomError::InvalidFundraiserTransition,
+    );
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn fundraiser_cap_exceeded() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 100 * USDC, 0);
+    let investor = env.onboard_investor(200 * USDC);
+    env.invest(&investor, &fundraiser, 60 * USDC);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 50 * USDC), &[&investor], CustomError::FundraiserCapExceeded);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn fundraiser_deadline_passed() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, START_TIME + DAY);
+    let investor = env.onboard_investor(100 * USDC);
+    env.warp_to(START_TIME + DAY);
+    env.expect_error(env.invest_ix(&investor.pubkey(), &fundraiser, 100 * USDC), &[&investor], CustomError::FundraiserDeadlinePassed);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn escrow_not_empty() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let fundraiser = env.create_fundraiser();
+    env.set_status(&fundraiser, FundraiserStatus::Terminated);
+
+    // Anyone can send tokens to an escrow vault without going through invest
+    let donor = env.onboard_investor(10 * USDC);
+    let donor_usdc = pda::associated_token_account(&donor.pubkey(), &usdc_mint, &spl_token::ID);
+    let donation =
+        spl_token::instruction::transfer(&spl_token::ID, &donor_usdc, &fundraiser.escrow_vault, &donor.pubkey(), &[], 10 * USDC).unwrap();
+    env.execute(donation, &[&donor]);
+
+    env.expect_admin_error(ix::close_fundraiser(&admin, fundraiser.reit_id_hash, &[]), CustomError::EscrowNotEmpty);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investments_outstanding() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.open_fundraiser(1_000 * USDC, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    env.invest(&investor, &fundraiser, 100 * USDC);
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Failed);
+    env.set_status(&fundraiser, FundraiserStatus::Terminated);
+
+    env.expect_admin_error(ix::close_fundraiser(&admin, fundraiser.reit_id_hash, &[]), CustomError::InvestmentsOutstanding);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investor_has_open_positions() {
+    let mut env = TestEnv::load();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    env.invest(&investor, &fundraiser, 100 * USDC);
+    env.expect_error(ix::close_investor(&investor.pubkey()), &[&investor], CustomError::InvestorHasOpenPositions);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn account_already_migrated() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.open_fundraiser(0, 0, 0);
+    let investor = env.onboard_investor(100 * USDC);
+    let investment = env.invest(&investor, &fundraiser, 100 * USDC);
+    env.expect_admin_error(ix::migrate_investment(&admin, &investment, &investor.pubkey(), &fundraiser.address), CustomError::AccountAlreadyMigrated);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn reit_mint_already_created() {
+    let mut env = TestEnv::load();
+    let mut raise = env.funded_raise(&[100 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    let admin = env.admin.pubkey();
+    let replacement = Keypair::new();
+    let create = ix::create_reit_mint_2022(
+        &admin,
+        raise.fundraiser.reit_id_hash,
+        &replacement.pubkey(),
+        &common::TRANSFER_HOOK_ID,
+        "Replacement".to_string(),
+        "RPL".to_string(),
+        "https://example.com/replacement.json".to_string(),
+    );
+    let admin_keypair = env.admin.insecure_clone();
+    env.expect_error(create, &[&admin_keypair, &replacement], CustomError::ReitMintAlreadyCreated);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn deposit_currency_mismatch() {
+    let mut env = TestEnv::load();
+    let (admin, usdc_mint) = (env.admin.pubkey(), env.usdc_mint);
+    let fundraiser = env.create_fundraiser();
+
+    // Deposits are added together in totals and limits, so a EUR stablecoin can't join a USDC raise
+    let eurc = env.create_mint(common::USDC_DECIMALS);
+    env.expect_admin_error(ix::add_accepted_mint(&admin, fundraiser.reit_id_hash, &usdc_mint, &eurc, *b"EUR"), CustomError::DepositCurrencyMismatch);
+}
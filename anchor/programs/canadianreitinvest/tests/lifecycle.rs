@@ -0,0 +1,658 @@
+//! Happy path of every instruction, run against the release build in LiteSVM.
+//!
+//! Needs `anchor build`; the classic SPL mint test also needs the Metaplex fixture from
+//! `npm run anchor-fixtures`. See `common` for how to run the ignored tests.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022;
+use anchor_spl::token_2022::spl_token_2022::extension::{transfer_hook::TransferHook, BaseStateWithExtensions, StateWithExtensions};
+use canadianreitinvest::errors::CustomError;
+use canadianreitinvest::state::{self, AccreditationCategory, FundraiserStatus, InvestmentStatus, KycStatus, Versioned};
+use canadianreitinvest_client::{instructions as ix, pda};
+use common::{TestEnv, DAY, ONTARIO, START_TIME, USDC};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+fn jurisdiction(country: &[u8; 2], subdivision: &[u8]) -> state::Jurisdiction {
+    let mut padded = [0u8; 3];
+    padded[..subdivision.len()].copy_from_slice(subdivision);
+    state::Jurisdiction { country: *country, subdivision: padded }
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn compliance_authority_attests_and_rotates() {
+    let (program, transfer_hook) = common::programs();
+    let mut env = TestEnv::new(&program, &transfer_hook);
+    let (admin, compliance) = (env.admin.pubkey(), env.compliance.pubkey());
+
+    env.execute_admin(ix::initialize_compliance(&admin, &compliance));
+    let config: state::ComplianceConfig = env.account(&pda::compliance_config());
+    assert_eq!(config.authority, compliance);
+
+    let wallet = Keypair::new().pubkey();
+    env.attest(&wallet, KycStatus::Verified, ONTARIO, START_TIME + 30 * DAY, AccreditationCategory::Eligible);
+    let attestation: state::KycAttestation = env.account(&pda::kyc_attestation(&wallet));
+    assert_eq!(attestation.investor, wallet);
+    assert_eq!(attestation.status, KycStatus::Verified);
+    assert_eq!(attestation.jurisdiction, ONTARIO);
+    assert_eq!(attestation.accreditation, AccreditationCategory::Eligible);
+    assert_eq!(attestation.expires_at, START_TIME + 30 * DAY);
+    assert_eq!(attestation.updated_at, START_TIME);
+
+    let successor = Keypair::new();
+    env.svm.airdrop(&successor.pubkey(), 1_000_000_000).unwrap();
+    env.execute_compliance(ix::set_compliance_authority(&compliance, &successor.pubkey()));
+    let config: state::ComplianceConfig = env.account(&pda::compliance_config());
+    assert_eq!(config.authority, successor.pubkey());
+
+    env.compliance = successor;
+    env.attest(&wallet, KycStatus::Revoked, ONTARIO, START_TIME + 30 * DAY, AccreditationCategory::Eligible);
+    let attestation: state::KycAttestation = env.account(&pda::kyc_attestation(&wallet));
+    assert_eq!(attestation.status, KycStatus::Revoked);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investor_profile_opens_and_closes() {
+    let mut env = TestEnv::load();
+    let investor = Keypair::new();
+    let wallet = investor.pubkey();
+    env.svm.airdrop(&wallet, 1_000_000_000).unwrap();
+
+    env.execute(ix::initialize_investor(&wallet), &[&investor]);
+    let profile: state::Investor = env.account(&pda::investor(&wallet));
+    assert_eq!(profile.investor_pubkey, wallet);
+    assert_eq!(profile.open_investment_count, 0);
+
+    env.execute(ix::close_investor(&wallet), &[&investor]);
+    assert!(!env.exists(&pda::investor(&wallet)));
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn admin_configures_and_opens_a_fundraiser() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let fundraiser = env.create_fundraiser();
+    let hash = fundraiser.reit_id_hash;
+
+    let created: state::Fundraiser = env.account(&fundraiser.address);
+    assert_eq!(created.status, FundraiserStatus::Draft);
+    assert_eq!(created.admin, admin);
+    assert_eq!(created.reit_id, fundraiser.reit_id);
+    assert_eq!(created.escrow_vault, fundraiser.escrow_vault);
+    assert_eq!(created.hold_period_seconds, state::DEFAULT_HOLD_PERIOD_SECONDS);
+    assert_eq!(env.token_balance(&fundraiser.escrow_vault), 0);
+
+    let close_at = START_TIME + 30 * DAY;
+    env.execute_admin(ix::update_fundraiser_terms(&admin, hash, *b"USD", 1_000 * USDC, 5_000 * USDC, close_at));
+    let limits = state::InvestmentLimits { non_eligible: 10_000 * USDC, eligible: 100_000 * USDC };
+    env.execute_admin(ix::set_offering_exemption(&admin, hash, state::OfferingExemption::OfferingMemorandum, limits));
+    let canada = jurisdiction(b"CA", b"");
+    let new_york = jurisdiction(b"US", b"NY");
+    let britain = jurisdiction(b"GB", b"");
+    env.execute_admin(ix::set_jurisdiction_rules(&admin, hash, state::JurisdictionMode::Allowlist, vec![canada, new_york]));
+    env.execute_admin(ix::add_jurisdiction_rule(&admin, hash, britain));
+    env.execute_admin(ix::remove_jurisdiction_rule(&admin, hash, new_york));
+    env.execute_admin(ix::set_hold_period(&admin, hash, 30 * DAY));
+    let usdt = env.create_mint(common::USDC_DECIMALS);
+    let usdc_mint = env.usdc_mint;
+    env.execute_admin(ix::add_accepted_mint(&admin, hash, &usdc_mint, &usdt, *b"USD"));
+    env.set_status(&fundraiser, FundraiserStatus::Open);
+
+    let configured: state::Fundraiser = env.account(&fundraiser.address);
+    assert_eq!(configured.status, FundraiserStatus::Open);
+    assert_eq!(configured.reit_accepted_currency, *b"USD");
+    assert_eq!((configured.min_raise, configured.max_raise, configured.close_at), (1_000 * USDC, 5_000 * USDC, close_at));
+    assert_eq!(configured.exemption, state::OfferingExemption::OfferingMemorandum);
+    assert_eq!(configured.investment_limits, limits);
+    assert_eq!(configured.jurisdiction_mode, state::JurisdictionMode::Allowlist);
+    assert_eq!(configured.jurisdiction_rules, vec![canada, britain]);
+    assert_eq!(configured.hold_period_seconds, 30 * DAY);
+    let usdt_vault = pda::accepted_mint_escrow_vault(&fundraiser.address, &usdt);
+    assert_eq!(configured.accepted_mint(&usdt), Some(&state::AcceptedMint { mint: usdt, escrow_vault: usdt_vault, currency: *b"USD" }));
+    assert_eq!(configured.accepted_mint(&usdc_mint).map(|accepted| accepted.currency), Some(*b"USD"));
+    assert_eq!(env.token_balance(&usdt_vault), 0);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn investment_runs_from_deposit_to_sale() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let usdc_mint = env.usdc_mint;
+    let mut fundraiser = env.open_fundraiser(1_000 * USDC, 0, 0);
+    let hash = fundraiser.reit_id_hash;
+    let investor = env.onboard_investor(2_500 * USDC);
+    let wallet = investor.pubkey();
+    let investor_usdc = pda::associated_token_account(&wallet, &usdc_mint, &spl_token::ID);
+
+    let first = env.invest(&investor, &fundraiser, 1_500 * USDC);
+    let second = env.invest(&investor, &fundraiser, 1_000 * USDC);
+    assert_eq!(first, pda::investment(&wallet, &fundraiser.address, 0));
+    assert_eq!(second, pda::investment(&wallet, &fundraiser.address, 1));
+    assert_eq!(env.token_balance(&fundraiser.escrow_vault), 2_500 * USDC);
+    assert_eq!(env.token_balance(&investor_usdc), 0);
+    let investment: state::Investment = env.account(&first);
+    assert_eq!(investment.status, InvestmentStatus::Pending);
+    assert_eq!((investment.investor, investment.usdc_amount, investment.deposit_mint), (wallet, 1_500 * USDC, usdc_mint));
+    let raised: state::Fundraiser = env.account(&fundraiser.address);
+    assert_eq!((raised.total_raised, raised.investment_count, raised.open_investment_count), (2_500 * USDC, 2, 2));
+    let profile: state::Investor = env.account(&pda::investor(&wallet));
+    assert_eq!(profile.open_investment_count, 2);
+
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Funded);
+
+    env.execute_admin(env.release_ix(&fundraiser, &first));
+    let admin_usdc = pda::associated_token_account(&admin, &usdc_mint, &spl_token::ID);
+    assert_eq!(env.token_balance(&admin_usdc), 1_500 * USDC);
+    assert_eq!(env.token_balance(&fundraiser.escrow_vault), 1_000 * USDC);
+    assert_eq!(env.account::<state::Investment>(&first).status, InvestmentStatus::Released);
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).released_amount, 1_500 * USDC);
+
+    // 1,500 USD at 1.3625 CAD per USD
+    env.execute_admin(env.wire_ix(&fundraiser, &first, 204_375));
+    let investment: state::Investment = env.account(&first);
+    assert_eq!(investment.status, InvestmentStatus::Wired);
+    assert_eq!((investment.wired_amount, investment.fx_rate, investment.wire_date), (204_375, 1_362_500, START_TIME));
+    assert_eq!((investment.wire_reference_hash, investment.currency), ([9u8; 32], *b"CAD"));
+
+    env.create_reit_mint_2022(&mut fundraiser);
+    let minted: state::Fundraiser = env.account(&fundraiser.address);
+    assert_eq!((minted.reit_mint, minted.reit_token_program), (fundraiser.reit_mint, spl_token_2022::ID));
+    // The fundraiser controls the hook configuration, and the hook's account list exists from the start
+    let mint_account = env.svm.get_account(&fundraiser.reit_mint).unwrap();
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_account.data).unwrap();
+    assert_eq!(Option::<Pubkey>::from(mint.get_extension::<TransferHook>().unwrap().authority), Some(fundraiser.address));
+    assert!(env.exists(&pda::extra_account_meta_list(&fundraiser.reit_mint, &common::TRANSFER_HOOK_ID)));
+    // Token-2022 metadata lives on the mint, which grows for the longer name
+    let renamed = "Renamed Canadian Residential REIT";
+    env.execute_admin(ix::update_reit_mint(
+        &admin,
+        hash,
+        &fundraiser.reit_mint,
+        &spl_token_2022::ID,
+        renamed.to_string(),
+        "RCREIT".to_string(),
+        "https://example.com/renamed.json".to_string(),
+    ));
+    let mint_data = env.svm.get_account(&fundraiser.reit_mint).unwrap().data;
+    assert!(mint_data.windows(renamed.len()).any(|window| window == renamed.as_bytes()));
+
+    env.warp_to(START_TIME + DAY);
+    env.execute_admin(env.issue_share_ix(&fundraiser, &first, &wallet, 1_362));
+    let investor_shares = pda::associated_token_account(&wallet, &fundraiser.reit_mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&investor_shares), 150);
+    assert_eq!(env.mint_supply(&fundraiser.reit_mint), 150);
+    let investment: state::Investment = env.account(&first);
+    assert_eq!(investment.status, InvestmentStatus::ShareIssued);
+    assert_eq!(investment.reit_amount, 150);
+    // share_price is in CAD cents; 13.62 CAD at the wire's 1.3625 CAD per USD is just under 10 USDC
+    assert_eq!(investment.share_price, 1_362);
+    assert_eq!(investment.lockup_until, START_TIME + DAY + state::DEFAULT_HOLD_PERIOD_SECONDS);
+    let history: Vec<(InvestmentStatus, i64, Pubkey)> =
+        investment.status_history.iter().map(|change| (change.status, change.changed_at, change.signer)).collect();
+    assert_eq!(
+        history,
+        [
+            (InvestmentStatus::Pending, START_TIME, wallet),
+            (InvestmentStatus::Released, START_TIME, admin),
+            (InvestmentStatus::Wired, START_TIME, admin),
+            (InvestmentStatus::ShareIssued, START_TIME + DAY, admin),
+        ]
+    );
+    assert_eq!((investment.created_at, investment.last_status_change), (START_TIME, START_TIME + DAY));
+    let lockup: state::HolderLockup = env.account(&pda::holder_lockup(&fundraiser.reit_mint, &wallet));
+    assert_eq!((lockup.holder, lockup.mint, lockup.locked_until), (wallet, fundraiser.reit_mint, investment.lockup_until));
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).open_investment_count, 1);
+    assert_eq!(env.account::<state::Investor>(&pda::investor(&wallet)).open_investment_count, 1);
+
+    env.set_status(&fundraiser, FundraiserStatus::Operating);
+    env.execute_admin(ix::issue_dividend(&admin, hash, &first, &wallet, &usdc_mint, 25 * USDC));
+    assert_eq!(env.token_balance(&investor_usdc), 25 * USDC);
+    assert_eq!(env.token_balance(&admin_usdc), 1_475 * USDC);
+
+    // Once the hold period is over the admin records the sale, which settles the investment for closing
+    let lockup_until = investment.lockup_until;
+    env.warp_to(lockup_until);
+    env.execute_admin(ix::record_share_sale(&admin, hash, &first));
+    let sold: state::Investment = env.account(&first);
+    assert_eq!(sold.status, InvestmentStatus::ShareSold);
+    assert_eq!(sold.status_history.last().map(|change| (change.status, change.changed_at)), Some((InvestmentStatus::ShareSold, lockup_until)));
+    env.execute(ix::close_investment(&wallet, &fundraiser.address, &first, true), &[&investor]);
+    assert!(!env.exists(&first));
+    let record: state::InvestorFundraiser = env.account(&pda::investor_fundraiser(&wallet, &fundraiser.address));
+    assert_eq!(record.investment_account_count, 1);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn wire_batch_allocates_the_wire_pro_rata() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let raise = env.funded_raise(&[300 * USDC, 100 * USDC]);
+    for investment in &raise.investments {
+        env.execute_admin(env.release_ix(&raise.fundraiser, investment));
+    }
+
+    let reference = [4u8; 32];
+    env.execute_admin(ix::wire_batch(&admin, raise.fundraiser.reit_id_hash, &raise.investments, 54_500, 1_362_500, START_TIME, reference));
+
+    let record: state::WireBatchRecord = env.account(&pda::wire_batch(&raise.fundraiser.address, &reference));
+    assert_eq!(record.fundraiser, raise.fundraiser.address);
+    assert_eq!((record.wired_amount, record.usdc_amount, record.fx_rate), (54_500, 400 * USDC, 1_362_500));
+    assert_eq!(record.investments, raise.investments);
+    for (investment, wired_amount) in raise.investments.iter().zip([40_875, 13_625]) {
+        let investment: state::Investment = env.account(investment);
+        assert_eq!(investment.status, InvestmentStatus::Wired);
+        assert_eq!(investment.wired_amount, wired_amount);
+        assert_eq!(investment.wire_reference_hash, reference);
+    }
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn failed_raise_is_refunded_and_closed() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let usdc_mint = env.usdc_mint;
+    let fundraiser = env.open_fundraiser(10_000 * USDC, 0, 0);
+    let hash = fundraiser.reit_id_hash;
+    let usdt = env.create_mint(common::USDC_DECIMALS);
+    let usdt_vault = pda::accepted_mint_escrow_vault(&fundraiser.address, &usdt);
+    env.execute_admin(ix::add_accepted_mint(&admin, hash, &usdc_mint, &usdt, *b"USD"));
+
+    let investor = env.onboard_investor(1_000 * USDC);
+    let wallet = investor.pubkey();
+    let investor_usdt = env.fund_ata(&usdt, &wallet, 500 * USDC);
+    let investor_usdc = pda::associated_token_account(&wallet, &usdc_mint, &spl_token::ID);
+    let usdc_investment = env.invest(&investor, &fundraiser, 1_000 * USDC);
+    env.execute(ix::invest(&wallet, hash, &usdt, &usdt_vault, 500 * USDC, 1), &[&investor]);
+    let usdt_investment = pda::investment(&wallet, &fundraiser.address, 1);
+    assert_eq!(env.token_balance(&usdt_vault), 500 * USDC);
+    assert_eq!(env.account::<state::Investment>(&usdt_investment).deposit_mint, usdt);
+
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Failed);
+
+    env.execute_admin(env.release_ix(&fundraiser, &usdc_investment));
+    env.execute_admin(ix::release(&admin, hash, &usdt_investment, &usdt, &usdt_vault));
+    let admin_usdt = pda::associated_token_account(&admin, &usdt, &spl_token::ID);
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).released_amount, 1_500 * USDC);
+
+    // Refunds go back through the escrow vault of the deposited stablecoin
+    env.execute_admin(ix::refund(&admin, hash, &usdc_investment, &wallet, &usdc_mint, &fundraiser.escrow_vault));
+    env.execute_admin(ix::refund(&admin, hash, &usdt_investment, &wallet, &usdt, &usdt_vault));
+    assert_eq!(env.token_balance(&investor_usdc), 1_000 * USDC);
+    assert_eq!(env.token_balance(&investor_usdt), 500 * USDC);
+    assert_eq!((env.token_balance(&admin_usdt), env.token_balance(&usdt_vault)), (0, 0));
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).released_amount, 0);
+    assert_eq!(env.account::<state::Investment>(&usdc_investment).status, InvestmentStatus::Refunded);
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).open_investment_count, 0);
+    assert_eq!(env.account::<state::Investor>(&pda::investor(&wallet)).open_investment_count, 0);
+
+    let investor_fundraiser = pda::investor_fundraiser(&wallet, &fundraiser.address);
+    env.execute(ix::close_investment(&wallet, &fundraiser.address, &usdc_investment, true), &[&investor]);
+    assert!(!env.exists(&usdc_investment));
+    assert!(env.exists(&investor_fundraiser));
+    env.execute(ix::close_investment(&wallet, &fundraiser.address, &usdt_investment, false), &[&investor]);
+    assert!(!env.exists(&usdt_investment));
+    assert!(!env.exists(&investor_fundraiser));
+
+    env.set_status(&fundraiser, FundraiserStatus::Terminated);
+    env.execute_admin(ix::close_fundraiser(&admin, hash, &[usdt_vault]));
+    assert!(!env.exists(&fundraiser.escrow_vault));
+    assert!(!env.exists(&usdt_vault));
+
+    env.execute(ix::close_investor(&wallet), &[&investor]);
+    assert!(!env.exists(&pda::investor(&wallet)));
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn rolling_totals_count_offering_memorandum_raises_net_of_refunds() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let memorandum = env.create_fundraiser();
+    let limits = state::InvestmentLimits { non_eligible: 10 * USDC, eligible: 50 * USDC };
+    env.execute_admin(ix::set_offering_exemption(&admin, memorandum.reit_id_hash, state::OfferingExemption::OfferingMemorandum, limits));
+    env.set_status(&memorandum, FundraiserStatus::Open);
+    let prospectus = env.open_fundraiser(0, 0, 0);
+
+    let investor = env.onboard_investor_as(AccreditationCategory::Eligible, ONTARIO, 130 * USDC);
+    let wallet = investor.pubkey();
+    let investment = env.invest(&investor, &memorandum, 30 * USDC);
+    env.invest(&investor, &prospectus, 100 * USDC);
+    let totals = |env: &TestEnv| {
+        let mut investor: state::Investor = env.account(&pda::investor(&wallet));
+        let mut memorandum_record: state::InvestorFundraiser = env.account(&pda::investor_fundraiser(&wallet, &memorandum.address));
+        let mut prospectus_record: state::InvestorFundraiser = env.account(&pda::investor_fundraiser(&wallet, &prospectus.address));
+        let now = env.now();
+        (
+            investor.rolling_invested.total(now).unwrap(),
+            memorandum_record.rolling_invested.total(now).unwrap(),
+            prospectus_record.rolling_invested.total(now).unwrap(),
+        )
+    };
+    // The prospectus raise is recorded on its own record but not against the memorandum limit
+    assert_eq!(totals(&env), (30 * USDC, 30 * USDC, 100 * USDC));
+
+    env.set_status(&memorandum, FundraiserStatus::Closed);
+    env.set_status(&memorandum, FundraiserStatus::Failed);
+    env.execute_admin(env.release_ix(&memorandum, &investment));
+    let usdc_mint = env.usdc_mint;
+    env.execute_admin(ix::refund(&admin, memorandum.reit_id_hash, &investment, &wallet, &usdc_mint, &memorandum.escrow_vault));
+    assert_eq!(totals(&env), (0, 0, 100 * USDC));
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn shares_are_priced_from_the_published_fx_rate() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let mut raise = env.wired_raise(&[1_000 * USDC]);
+    env.create_reit_mint_2022(&mut raise.fundraiser);
+    let wallet = raise.investor.pubkey();
+
+    let publisher = Keypair::new();
+    env.svm.airdrop(&publisher.pubkey(), 1_000_000_000).unwrap();
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &publisher.pubkey(), 3_600));
+    env.execute(ix::publish_fx_rate(&publisher.pubkey(), *b"USD", *b"CAD", 1_250_000, 1_000, START_TIME), &[&publisher]);
+    let fx_rate = pda::fx_rate(b"USD", b"CAD");
+    let published: state::FxRate = env.account(&fx_rate);
+    assert_eq!((published.publisher, published.rate, published.confidence), (publisher.pubkey(), 1_250_000, 1_000));
+    assert_eq!((published.published_at, published.max_staleness_seconds), (START_TIME, 3_600));
+
+    // 25.00 CAD per share at 1.25 CAD per USD is 20 USDC, so 1,000 USDC buys 50 shares
+    env.execute_admin(ix::issue_share(
+        &admin,
+        raise.fundraiser.reit_id_hash,
+        &raise.investments[0],
+        &wallet,
+        &raise.fundraiser.reit_mint,
+        &spl_token_2022::ID,
+        false,
+        Some(fx_rate),
+        2_500,
+    ));
+    let investment: state::Investment = env.account(&raise.investments[0]);
+    assert_eq!((investment.reit_amount, investment.share_price), (50, 2_500));
+    let investor_shares = pda::associated_token_account(&wallet, &raise.fundraiser.reit_mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&investor_shares), 50);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn fx_rate_publisher_is_rotated() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let (old_publisher, new_publisher) = (Keypair::new(), Keypair::new());
+    for publisher in [&old_publisher, &new_publisher] {
+        env.svm.airdrop(&publisher.pubkey(), 1_000_000_000).unwrap();
+    }
+    env.execute_admin(ix::initialize_fx_rate(&admin, *b"USD", *b"CAD", &old_publisher.pubkey(), 3_600));
+    env.execute(ix::publish_fx_rate(&old_publisher.pubkey(), *b"USD", *b"CAD", 1_250_000, 0, START_TIME), &[&old_publisher]);
+
+    // The stored rate survives the rotation, and only the new key may publish over it
+    env.execute_admin(ix::set_fx_rate_publisher(&admin, *b"USD", *b"CAD", &new_publisher.pubkey()));
+    let fx_rate = pda::fx_rate(b"USD", b"CAD");
+    let rotated: state::FxRate = env.account(&fx_rate);
+    assert_eq!((rotated.publisher, rotated.rate, rotated.published_at), (new_publisher.pubkey(), 1_250_000, START_TIME));
+    let stale = ix::publish_fx_rate(&old_publisher.pubkey(), *b"USD", *b"CAD", 1_300_000, 0, START_TIME);
+    env.expect_error(stale, &[&old_publisher], CustomError::InvalidAuthority);
+    env.execute(ix::publish_fx_rate(&new_publisher.pubkey(), *b"USD", *b"CAD", 1_300_000, 0, START_TIME), &[&new_publisher]);
+    let published: state::FxRate = env.account(&fx_rate);
+    assert_eq!(published.rate, 1_300_000);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn shares_are_priced_in_the_deposit_currency() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let usdc_mint = env.usdc_mint;
+    let mut fundraiser = env.create_fundraiser();
+    let hash = fundraiser.reit_id_hash;
+    env.execute_admin(ix::update_fundraiser_terms(&admin, hash, *b"USD", 0, 0, 0));
+    let usdt = env.create_mint(common::USDC_DECIMALS);
+    let usdt_vault = pda::accepted_mint_escrow_vault(&fundraiser.address, &usdt);
+    env.execute_admin(ix::add_accepted_mint(&admin, hash, &usdc_mint, &usdt, *b"USD"));
+    env.set_status(&fundraiser, FundraiserStatus::Open);
+
+    let investor = env.onboard_investor(0);
+    let wallet = investor.pubkey();
+    env.fund_ata(&usdt, &wallet, 1_000 * USDC);
+    env.execute(ix::invest(&wallet, hash, &usdt, &usdt_vault, 1_000 * USDC, 0), &[&investor]);
+    let investment = pda::investment(&wallet, &fundraiser.address, 0);
+    env.set_status(&fundraiser, FundraiserStatus::Closed);
+    env.set_status(&fundraiser, FundraiserStatus::Funded);
+    env.execute_admin(ix::release(&admin, hash, &investment, &usdt, &usdt_vault));
+    env.execute_admin(ix::wire(&admin, hash, &investment, 100_000, state::FX_RATE_SCALE, START_TIME, [9u8; 32]));
+    env.create_reit_mint_2022(&mut fundraiser);
+
+    // 25.00 USD per share paid in a USD stablecoin needs no FX rate: 1,000 USDT buys 40 shares
+    env.execute_admin(ix::issue_share(&admin, hash, &investment, &wallet, &fundraiser.reit_mint, &spl_token_2022::ID, false, None, 2_500));
+    let issued: state::Investment = env.account(&investment);
+    assert_eq!((issued.reit_amount, issued.share_price, issued.currency), (40, 2_500, *b"USD"));
+    let investor_shares = pda::associated_token_account(&wallet, &fundraiser.reit_mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&investor_shares), 40);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn compliance_freezes_thaws_and_recovers_a_position() {
+    let mut env = TestEnv::load();
+    let (admin, compliance) = (env.admin.insecure_clone(), env.compliance.insecure_clone());
+    let raise = env.issued_raise(&[100 * USDC]);
+    let fundraiser = &raise.fundraiser;
+    let hash = fundraiser.reit_id_hash;
+    let holder_shares = pda::associated_token_account(&raise.investor.pubkey(), &fundraiser.reit_mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&holder_shares), 100);
+
+    let freeze = ix::freeze_holder(&compliance.pubkey(), hash, &fundraiser.reit_mint, &holder_shares, &spl_token_2022::ID, [1u8; 32]);
+    env.execute_compliance(freeze.clone());
+    assert!(env.is_frozen(&holder_shares));
+    env.execute_compliance(ix::thaw_holder(&compliance.pubkey(), hash, &fundraiser.reit_mint, &holder_shares, &spl_token_2022::ID, [1u8; 32]));
+    assert!(!env.is_frozen(&holder_shares));
+
+    // Recovery thaws a frozen holder account before burning from it
+    env.execute_compliance(freeze);
+    let old_owner = raise.investor.pubkey();
+    let new_owner = env.onboard_investor(0).pubkey();
+    env.execute(
+        ix::recover_position(
+            &compliance.pubkey(),
+            &admin.pubkey(),
+            hash,
+            &raise.investments[0],
+            &old_owner,
+            &fundraiser.reit_mint,
+            &spl_token_2022::ID,
+            &holder_shares,
+            &new_owner,
+            [2u8; 32],
+        ),
+        &[&compliance, &admin],
+    );
+    let new_owner_shares = pda::associated_token_account(&new_owner, &fundraiser.reit_mint, &spl_token_2022::ID);
+    assert_eq!(env.token_balance(&holder_shares), 0);
+    assert_eq!(env.token_balance(&new_owner_shares), 100);
+    assert_eq!(env.mint_supply(&fundraiser.reit_mint), 100);
+    let investment: state::Investment = env.account(&raise.investments[0]);
+    assert_eq!(investment.investor, new_owner);
+    let lockup: state::HolderLockup = env.account(&pda::holder_lockup(&fundraiser.reit_mint, &new_owner));
+    assert_eq!(lockup.locked_until, investment.lockup_until);
+
+    // Issued shares are no longer open on either investor; the per-fundraiser record moves with the position
+    assert_eq!(env.account::<state::Investor>(&pda::investor(&old_owner)).open_investment_count, 0);
+    assert_eq!(env.account::<state::Investor>(&pda::investor(&new_owner)).open_investment_count, 0);
+    assert!(!env.exists(&pda::investor_fundraiser(&old_owner, &fundraiser.address)));
+    let record: state::InvestorFundraiser = env.account(&pda::investor_fundraiser(&new_owner, &fundraiser.address));
+    assert_eq!((record.investor, record.fundraiser, record.investment_account_count), (new_owner, fundraiser.address, 1));
+}
+
+#[test]
+#[ignore = "needs `anchor build` and `npm run anchor-fixtures`"]
+fn classic_mint_shares_wait_in_the_lock_vault_and_are_recovered_by_freezing() {
+    let mut env = TestEnv::load_with_metadata();
+    let admin = env.admin.pubkey();
+    let mut raise = env.wired_raise(&[100 * USDC]);
+    let wallet = raise.investor.pubkey();
+    let hash = raise.fundraiser.reit_id_hash;
+
+    env.create_reit_mint(&mut raise.fundraiser);
+    let reit_mint = raise.fundraiser.reit_mint;
+    let metadata = pda::metadata(&reit_mint);
+    assert!(env.exists(&metadata));
+    env.execute_admin(ix::update_reit_mint(
+        &admin,
+        hash,
+        &reit_mint,
+        &spl_token::ID,
+        "Renamed REIT".to_string(),
+        "RREIT".to_string(),
+        "https://example.com/renamed.json".to_string(),
+    ));
+    let metadata_data = env.svm.get_account(&metadata).unwrap().data;
+    assert!(metadata_data.windows(b"Renamed REIT".len()).any(|window| window == b"Renamed REIT"));
+
+    let investment = raise.investments[0];
+    env.execute_admin(env.issue_share_ix(&raise.fundraiser, &investment, &wallet, common::USDC_SHARE_PRICE));
+    let lock_vault = pda::lock_vault(&investment);
+    let investor_shares = pda::associated_token_account(&wallet, &reit_mint, &spl_token::ID);
+    assert_eq!(env.token_balance(&lock_vault), 100);
+    assert!(!env.exists(&investor_shares));
+
+    let lockup_until = env.account::<state::Investment>(&investment).lockup_until;
+    env.warp_to(lockup_until);
+    env.execute(ix::unlock_shares(&wallet, hash, &investment, &admin, &reit_mint, &spl_token::ID), &[&raise.investor]);
+    assert_eq!(env.token_balance(&investor_shares), 100);
+    assert!(!env.exists(&lock_vault));
+
+    // Unlocked classic SPL units can't be burned, so recovery freezes them in place and
+    // mints replacements to the rightful owner
+    let compliance = env.compliance.insecure_clone();
+    let admin_keypair = env.admin.insecure_clone();
+    let new_owner = env.onboard_investor(0).pubkey();
+    env.execute(
+        ix::recover_position(
+            &compliance.pubkey(),
+            &admin,
+            hash,
+            &investment,
+            &wallet,
+            &reit_mint,
+            &spl_token::ID,
+            &investor_shares,
+            &new_owner,
+            [2u8; 32],
+        ),
+        &[&compliance, &admin_keypair],
+    );
+    let new_owner_shares = pda::associated_token_account(&new_owner, &reit_mint, &spl_token::ID);
+    assert!(env.is_frozen(&investor_shares));
+    assert_eq!(env.token_balance(&investor_shares), 100);
+    assert_eq!(env.token_balance(&new_owner_shares), 100);
+    assert_eq!(env.mint_supply(&reit_mint), 200);
+    assert_eq!(env.account::<state::Investment>(&investment).investor, new_owner);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn baseline_accounts_are_migrated() {
+    let mut env = TestEnv::load();
+    let admin = env.admin.pubkey();
+    let raise = env.funded_raise(&[100 * USDC, 50 * USDC]);
+    let wallet = raise.investor.pubkey();
+    let fundraiser = &raise.fundraiser;
+    let (investor, investor_fundraiser) = (pda::investor(&wallet), pda::investor_fundraiser(&wallet, &fundraiser.address));
+    let escrow_balance = env.token_balance(&fundraiser.escrow_vault);
+
+    // Write every account byte for byte in the layouts of the deployed baseline
+    let investor_bump = env.account::<state::Investor>(&investor).bump;
+    let record_bump = env.account::<state::InvestorFundraiser>(&investor_fundraiser).bump;
+    env.write_baseline_raise(&raise);
+
+    // Parents first, so each investment can be counted on them. Migration is open to anyone,
+    // who pays the rent for the larger account
+    let payer = Keypair::new();
+    env.svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    // The baseline never stored the REIT id, so it must be one that derives the fundraiser's address
+    let (other_reit_id, _) = common::new_reit_id();
+    env.expect_error(ix::migrate_fundraiser(&payer.pubkey(), &fundraiser.address, other_reit_id), &[&payer], CustomError::InvalidReitIdHash);
+    for address in [fundraiser.address, investor, investor_fundraiser].iter().chain(&raise.investments) {
+        let data = env.svm.get_account(address).unwrap().data;
+        env.execute(ix::migrate(&payer.pubkey(), address, &data, Some(&fundraiser.reit_id)).unwrap(), &[&payer]);
+        let account = env.svm.get_account(address).unwrap();
+        assert_eq!(account.lamports, env.svm.minimum_balance_for_rent_exemption(account.data.len()), "{address}");
+    }
+
+    let migrated: state::Fundraiser = env.account(&fundraiser.address);
+    assert_eq!(migrated.version, state::Fundraiser::VERSION);
+    assert_eq!((migrated.admin, migrated.usdc_mint, migrated.reit_mint), (admin, env.usdc_mint, Pubkey::default()));
+    assert_eq!((migrated.total_raised, migrated.released_amount, migrated.reit_accepted_currency), (150 * USDC, 0, *b"CAD"));
+    assert_eq!(migrated.accepted_mints, [state::AcceptedMint { mint: env.usdc_mint, escrow_vault: fundraiser.escrow_vault, currency: *b"USD" }]);
+    assert_eq!((migrated.status, migrated.reit_token_program, migrated.reit_id.as_str()), (FundraiserStatus::Open, spl_token::ID, fundraiser.reit_id.as_str()));
+    assert_eq!((migrated.investment_count, migrated.open_investment_count), (2, 2));
+
+    let migrated: state::Investor = env.account(&investor);
+    assert_eq!((migrated.version, migrated.investor_pubkey, migrated.bump), (state::Investor::VERSION, wallet, investor_bump));
+    assert_eq!((migrated.rolling_invested, migrated.open_investment_count), (state::RollingTotal::default(), 2));
+
+    let migrated: state::InvestorFundraiser = env.account(&investor_fundraiser);
+    assert_eq!((migrated.version, migrated.investor, migrated.fundraiser), (state::InvestorFundraiser::VERSION, wallet, fundraiser.address));
+    assert_eq!((migrated.investment_counter, migrated.bump, migrated.investment_account_count), (2, record_bump, 2));
+    assert_eq!(migrated.rolling_invested, state::RollingTotal::default());
+
+    let migrated: state::Investment = env.account(&raise.investments[0]);
+    assert_eq!(migrated.version, state::Investment::VERSION);
+    assert_eq!((migrated.investor, migrated.fundraiser, migrated.usdc_amount), (wallet, fundraiser.address, 100 * USDC));
+    assert_eq!((migrated.status, migrated.deposit_mint, migrated.currency), (InvestmentStatus::Pending, env.usdc_mint, *b"CAD"));
+    assert_eq!((migrated.created_at, migrated.created_slot, migrated.last_status_change), (0, 0, 0));
+    assert!(migrated.status_history.is_empty());
+
+    // The migrated raise carries on through the lifecycle
+    env.set_status(fundraiser, FundraiserStatus::Closed);
+    env.set_status(fundraiser, FundraiserStatus::Funded);
+    env.execute_admin(env.release_ix(fundraiser, &raise.investments[0]));
+    let released: state::Investment = env.account(&raise.investments[0]);
+    assert_eq!(released.status_history, [state::StatusChange { status: InvestmentStatus::Released, changed_at: START_TIME, signer: admin }]);
+    assert_eq!(env.account::<state::Fundraiser>(&fundraiser.address).released_amount, 100 * USDC);
+    assert_eq!(env.token_balance(&fundraiser.escrow_vault), escrow_balance - 100 * USDC);
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn version_1_investor_fundraiser_is_migrated() {
+    let mut env = TestEnv::load();
+    let raise = env.funded_raise(&[100 * USDC]);
+    let wallet = raise.investor.pubkey();
+    let address = pda::investor_fundraiser(&wallet, &raise.fundraiser.address);
+    let record: state::InvestorFundraiser = env.account(&address);
+
+    // Version 1 had no rolling total
+    let mut data = state::InvestorFundraiser::DISCRIMINATOR.to_vec();
+    data.push(1);
+    data.extend_from_slice(wallet.as_ref());
+    data.extend_from_slice(raise.fundraiser.address.as_ref());
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.push(record.bump);
+    data.extend_from_slice(&1u64.to_le_bytes());
+    data.extend_from_slice(&[0u8; state::RESERVED_SPACE]);
+    assert_eq!(data.len(), 154);
+    env.set_baseline(&address, data);
+
+    let payer = Keypair::new();
+    env.svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    env.execute(ix::migrate_investor_fundraiser(&payer.pubkey(), &address), &[&payer]);
+    let migrated: state::InvestorFundraiser = env.account(&address);
+    assert_eq!((migrated.version, migrated.investor, migrated.fundraiser), (state::InvestorFundraiser::VERSION, wallet, raise.fundraiser.address));
+    assert_eq!((migrated.investment_counter, migrated.bump, migrated.investment_account_count), (1, record.bump, 1));
+    assert_eq!(migrated.rolling_invested, state::RollingTotal::default());
+}
@@ -0,0 +1,284 @@
+//! Property-based test of the investment state machine.
+//!
+//! proptest generates random sequences of `invest`, `release`, `refund`, `wire`,
+//! `issue_share`, `issue_dividend` and `set_fundraiser_status` across several investors and
+//! fundraisers, some of them signed by an outsider in place of the admin. Whether a step
+//! succeeds is up to the program; after every step the test checks that
+//!
+//! - each escrow vault holds exactly the sum of its fundraiser's Pending investments,
+//! - each REIT mint's supply is the sum of its fundraiser's `reit_amount`s,
+//! - no investment or fundraiser moved to a status it can't reach from the previous one,
+//! - nothing signed by the outsider succeeded.
+//!
+//! Needs `anchor build`. `PROPTEST_CASES` overrides the number of sequences.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use canadianreitinvest::state::{self, FundraiserStatus, InvestmentStatus};
+use canadianreitinvest_client::{instructions as ix, pda};
+use common::{TestEnv, TestFundraiser, START_TIME, USDC};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+const FUNDRAISERS: usize = 2;
+const INVESTORS: usize = 3;
+const MAX_STEPS: usize = 40;
+
+/// One instruction, with investments picked by index into those created so far.
+#[derive(Clone, Debug)]
+enum Step {
+    Invest { investor: usize, fundraiser: usize, usdc: u64 },
+    Release(usize),
+    Refund(usize),
+    Wire(usize),
+    IssueShare(usize),
+    Dividend(usize),
+    SetStatus { fundraiser: usize, status: FundraiserStatus },
+}
+
+/// A step, and whether an outsider signs it in place of the admin.
+#[derive(Clone, Debug)]
+struct Action {
+    step: Step,
+    by_outsider: bool,
+}
+
+fn fundraiser_status() -> impl Strategy<Value = FundraiserStatus> {
+    use FundraiserStatus::*;
+    prop::sample::select(vec![Open, Closed, Funded, Failed, Operating, WindingDown, Terminated])
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        4 => (0..INVESTORS, 0..FUNDRAISERS, 1..=200u64)
+            .prop_map(|(investor, fundraiser, usdc)| Step::Invest { investor, fundraiser, usdc }),
+        2 => any::<usize>().prop_map(Step::Release),
+        1 => any::<usize>().prop_map(Step::Refund),
+        2 => any::<usize>().prop_map(Step::Wire),
+        2 => any::<usize>().prop_map(Step::IssueShare),
+        1 => any::<usize>().prop_map(Step::Dividend),
+        2 => (0..FUNDRAISERS, fundraiser_status()).prop_map(|(fundraiser, status)| Step::SetStatus { fundraiser, status }),
+    ]
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    // Investing is signed by the investor, so only admin steps get an outsider variant
+    (step(), prop::bool::weighted(0.15)).prop_map(|(step, outsider)| Action {
+        by_outsider: outsider && !matches!(step, Step::Invest { .. }),
+        step,
+    })
+}
+
+/// Whether an investment may go from `from` to `to` in one step.
+fn investment_step_allowed(from: InvestmentStatus, to: InvestmentStatus) -> bool {
+    use InvestmentStatus::*;
+    from == to || matches!((from, to), (Pending, Released) | (Released, Refunded) | (Released, Wired) | (Wired, ShareIssued))
+}
+
+/// Whether a fundraiser may go from `from` to `to` in one step.
+fn fundraiser_step_allowed(from: FundraiserStatus, to: FundraiserStatus) -> bool {
+    use FundraiserStatus::*;
+    from == to
+        || matches!(
+            (from, to),
+            (Draft, Open)
+                | (Draft, Terminated)
+                | (Open, Closed)
+                | (Closed, Funded)
+                | (Closed, Failed)
+                | (Funded, Operating)
+                | (Operating, WindingDown)
+                | (WindingDown, Terminated)
+                | (Failed, Terminated)
+        )
+}
+
+struct Harness {
+    env: TestEnv,
+    outsider: Keypair,
+    investors: Vec<Keypair>,
+    fundraisers: Vec<TestFundraiser>,
+    /// Every investment created so far, with the index of its fundraiser
+    investments: Vec<(Pubkey, usize)>,
+    investment_statuses: Vec<InvestmentStatus>,
+    fundraiser_statuses: Vec<FundraiserStatus>,
+}
+
+impl Harness {
+    /// Open fundraisers with Token-2022 REIT mints, the first with no minimum raise so it can
+    /// be Funded and the second with a 300 USDC minimum so it tends to fail.
+    fn new(program: &[u8], transfer_hook: &[u8]) -> Self {
+        let mut env = TestEnv::new(program, transfer_hook);
+        let admin = env.admin.pubkey();
+        let compliance = env.compliance.pubkey();
+        env.execute_admin(ix::initialize_compliance(&admin, &compliance));
+
+        let fundraisers: Vec<TestFundraiser> = [0, 300 * USDC]
+            .into_iter()
+            .map(|min_raise| {
+                let mut fundraiser = env.create_fundraiser();
+                env.execute_admin(ix::update_fundraiser_terms(&admin, fundraiser.reit_id_hash, *b"CAD", min_raise, 0, 0));
+                env.create_reit_mint_2022(&mut fundraiser);
+                env.set_status(&fundraiser, FundraiserStatus::Open);
+                fundraiser
+            })
+            .collect();
+        let investors = (0..INVESTORS).map(|_| env.onboard_investor(1_000 * USDC)).collect();
+        let outsider = Keypair::new();
+        env.svm.airdrop(&outsider.pubkey(), 10_000_000_000).unwrap();
+
+        Harness {
+            env,
+            outsider,
+            investors,
+            fundraisers,
+            investments: Vec::new(),
+            investment_statuses: Vec::new(),
+            fundraiser_statuses: vec![FundraiserStatus::Open; FUNDRAISERS],
+        }
+    }
+
+    /// Builds the instruction for `step` with `signer` as the admin account, or `None` if it
+    /// picks an investment and there are none yet.
+    fn instruction(&self, step: &Step, signer: &Pubkey) -> Option<Instruction> {
+        let usdc_mint = self.env.usdc_mint;
+        let pick = |index: &usize| {
+            (!self.investments.is_empty()).then(|| {
+                let (investment, fundraiser) = self.investments[index % self.investments.len()];
+                let wallet = self.env.account::<state::Investment>(&investment).investor;
+                (investment, &self.fundraisers[fundraiser], wallet)
+            })
+        };
+        let instruction = match step {
+            Step::Invest { investor, fundraiser, usdc } => {
+                self.env.invest_ix(&self.investors[*investor].pubkey(), &self.fundraisers[*fundraiser], usdc * USDC)
+            }
+            Step::Release(index) => {
+                let (investment, fundraiser, _) = pick(index)?;
+                ix::release(signer, fundraiser.reit_id_hash, &investment, &usdc_mint, &fundraiser.escrow_vault)
+            }
+            Step::Refund(index) => {
+                let (investment, fundraiser, wallet) = pick(index)?;
+                ix::refund(signer, fundraiser.reit_id_hash, &investment, &wallet, &usdc_mint, &fundraiser.escrow_vault)
+            }
+            Step::Wire(index) => {
+                let (investment, fundraiser, _) = pick(index)?;
+                ix::wire(signer, fundraiser.reit_id_hash, &investment, 13_625, 1_362_500, START_TIME, [9u8; 32])
+            }
+            Step::IssueShare(index) => {
+                let (investment, fundraiser, wallet) = pick(index)?;
+                ix::issue_share(
+                    signer,
+                    fundraiser.reit_id_hash,
+                    &investment,
+                    &wallet,
+                    &fundraiser.reit_mint,
+                    &fundraiser.reit_token_program,
+                    false,
+                    None,
+                    common::USDC_SHARE_PRICE,
+                )
+            }
+            Step::Dividend(index) => {
+                let (investment, fundraiser, wallet) = pick(index)?;
+                ix::issue_dividend(signer, fundraiser.reit_id_hash, &investment, &wallet, &usdc_mint, USDC)
+            }
+            Step::SetStatus { fundraiser, status } => {
+                ix::set_fundraiser_status(signer, self.fundraisers[*fundraiser].reit_id_hash, *status)
+            }
+        };
+        Some(instruction)
+    }
+
+    fn apply(&mut self, action: &Action) -> Result<(), TestCaseError> {
+        let signer = if action.by_outsider { self.outsider.insecure_clone() } else { self.env.admin.insecure_clone() };
+        let Some(instruction) = self.instruction(&action.step, &signer.pubkey()) else {
+            return Ok(());
+        };
+
+        // The investment `invest` would create, recorded if it succeeds
+        let created = match action.step {
+            Step::Invest { investor, fundraiser, .. } => {
+                let wallet = self.investors[investor].pubkey();
+                let counter = self.env.next_counter(&wallet, &self.fundraisers[fundraiser]);
+                Some((pda::investment(&wallet, &self.fundraisers[fundraiser].address, counter), fundraiser))
+            }
+            _ => None,
+        };
+        let result = match action.step {
+            Step::Invest { investor, .. } => {
+                let investor = self.investors[investor].insecure_clone();
+                self.env.send(&[instruction], &[&investor])
+            }
+            _ => self.env.send(&[instruction], &[&signer]),
+        };
+
+        if action.by_outsider {
+            prop_assert!(result.is_err(), "{:?} signed by an outsider succeeded", action.step);
+        }
+        if let (Ok(_), Some(created)) = (&result, created) {
+            let status = self.env.account::<state::Investment>(&created.0).status;
+            prop_assert_eq!(status, InvestmentStatus::Pending, "new investment");
+            self.investments.push(created);
+            self.investment_statuses.push(status);
+        }
+        self.check_invariants()
+    }
+
+    fn check_invariants(&mut self) -> Result<(), TestCaseError> {
+        let investments: Vec<(state::Investment, usize)> = self
+            .investments
+            .iter()
+            .map(|(address, fundraiser)| (self.env.account::<state::Investment>(address), *fundraiser))
+            .collect();
+
+        for (index, fundraiser) in self.fundraisers.iter().enumerate() {
+            let own = || investments.iter().filter(move |(_, of)| *of == index).map(|(investment, _)| investment);
+            let pending: u64 = own().filter(|investment| investment.status == InvestmentStatus::Pending).map(|investment| investment.usdc_amount).sum();
+            prop_assert_eq!(self.env.token_balance(&fundraiser.escrow_vault), pending, "escrow of fundraiser {}", index);
+
+            let issued: u64 = own().map(|investment| u64::from(investment.reit_amount)).sum();
+            prop_assert_eq!(self.env.mint_supply(&fundraiser.reit_mint), issued, "REIT supply of fundraiser {}", index);
+
+            let status = self.env.account::<state::Fundraiser>(&fundraiser.address).status;
+            let previous = self.fundraiser_statuses[index];
+            prop_assert!(fundraiser_step_allowed(previous, status), "fundraiser {} went from {:?} to {:?}", index, previous, status);
+            self.fundraiser_statuses[index] = status;
+        }
+
+        for (index, (investment, _)) in investments.iter().enumerate() {
+            let previous = self.investment_statuses[index];
+            prop_assert!(
+                investment_step_allowed(previous, investment.status),
+                "investment {} went from {:?} to {:?}",
+                index,
+                previous,
+                investment.status
+            );
+            self.investment_statuses[index] = investment.status;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+#[ignore = "needs `anchor build`"]
+fn random_instruction_sequences_keep_invariants() {
+    let (program, transfer_hook) = common::programs();
+    let cases = std::env::var("PROPTEST_CASES").ok().and_then(|cases| cases.parse().ok()).unwrap_or(48);
+    let mut runner = TestRunner::new(Config { cases, ..Config::default() });
+    let sequences = prop::collection::vec(action(), 1..=MAX_STEPS);
+    runner
+        .run(&sequences, |actions| {
+            let mut harness = Harness::new(&program, &transfer_hook);
+            for action in &actions {
+                harness.apply(action)?;
+            }
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum HookError {
+    #[msg("Transfer hook invoked outside of a token transfer")]
+    NotTransferring,
+    #[msg("Sender does not hold a valid KYC attestation")]
+    SourceNotVerified,
+    #[msg("Recipient does not hold a valid KYC attestation")]
+    DestinationNotVerified,
+    #[msg("Sender's REIT tokens are still within the resale hold period")]
+    HoldPeriodActive,
+    #[msg("Invalid authority")]
+    InvalidAuthority,
+}
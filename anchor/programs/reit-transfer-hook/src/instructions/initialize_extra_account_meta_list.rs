@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+/// Index of the first extra account; 0-4 are source, mint, destination, owner and the meta list.
+const REIT_PROGRAM_INDEX: u8 = 5;
+const SOURCE_INVESTOR_INDEX: u8 = 6;
+const DESTINATION_INVESTOR_INDEX: u8 = 8;
+
+/// Offset of the owner field in an SPL token account.
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+
+/// Extra accounts Token-2022 resolves for every transfer of a REIT mint: the REIT program,
+/// then for sender and recipient the Investor PDA and its KYC attestation, then the
+/// sender's holder lockup.
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![
+        // 5: canadianreitinvest program, used to derive its PDAs
+        ExtraAccountMeta::new_with_pubkey(&canadianreitinvest::ID, false, false)?,
+        // 6: source Investor PDA, from the source token account owner
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            REIT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"investor".to_vec() },
+                Seed::AccountData { account_index: 0, data_index: TOKEN_ACCOUNT_OWNER_OFFSET, length: 32 },
+            ],
+            false,
+            false,
+        )?,
+        // 7: source KYC attestation
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            REIT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"kyc_attestation".to_vec() },
+                Seed::AccountKey { index: SOURCE_INVESTOR_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        // 8: destination Investor PDA, from the destination token account owner
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            REIT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"investor".to_vec() },
+                Seed::AccountData { account_index: 2, data_index: TOKEN_ACCOUNT_OWNER_OFFSET, length: 32 },
+            ],
+            false,
+            false,
+        )?,
+        // 9: destination KYC attestation
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            REIT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"kyc_attestation".to_vec() },
+                Seed::AccountKey { index: DESTINATION_INVESTOR_INDEX },
+            ],
+            false,
+            false,
+        )?,
+        // 10: source holder lockup
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            REIT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: b"holder_lockup".to_vec() },
+                Seed::AccountKey { index: 1 },
+                Seed::AccountData { account_index: 0, data_index: TOKEN_ACCOUNT_OWNER_OFFSET, length: 32 },
+            ],
+            false,
+            false,
+        )?,
+    ])
+}
+
+pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+    debug_msg!("InitializeExtraAccountMetaList handler start");
+    debug_msg!("Mint: {}", ctx.accounts.mint.key());
+
+    let metas = extra_account_metas()?;
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &metas)?;
+
+    debug_msg!("InitializeExtraAccountMetaList handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// Must be the REIT mint authority
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: ExtraAccountMetaList account, written by the TLV library
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = mint.mint_authority == Some(payer.key()).into() @ crate::errors::HookError::InvalidAuthority
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_hook::TransferHookAccount;
+use anchor_spl::token_interface::spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use anchor_spl::token_interface::spl_token_2022::state::Account as SplTokenAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use canadianreitinvest::program::Canadianreitinvest;
+use canadianreitinvest::state::{HolderLockup, KycAttestation};
+
+use crate::errors::HookError;
+
+/// Deserializes an account owned by the REIT program, failing if it is missing or of another type.
+fn load_reit_account<T: AccountDeserialize>(info: &AccountInfo) -> Result<T> {
+    if info.owner != &canadianreitinvest::ID {
+        return Err(error!(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram));
+    }
+    let data = info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn handler(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+    debug_msg!("TransferHook handler start");
+    debug_msg!("Source: {}, Destination: {}, Amount: {}", ctx.accounts.source_token.key(), ctx.accounts.destination_token.key(), amount);
+
+    // Only run as part of a Token-2022 transfer, never as a standalone call
+    let source_info = ctx.accounts.source_token.to_account_info();
+    let source_data = source_info.try_borrow_data()?;
+    let source_state = StateWithExtensions::<SplTokenAccount>::unpack(&source_data)?;
+    let transferring = source_state
+        .get_extension::<TransferHookAccount>()
+        .map(|extension| bool::from(extension.transferring))
+        .unwrap_or(false);
+    if !transferring {
+        return Err(error!(HookError::NotTransferring));
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Both sides of the transfer must hold a valid, unexpired KYC attestation
+    load_reit_account::<KycAttestation>(&ctx.accounts.source_kyc_attestation)
+        .and_then(|attestation| attestation.require_valid(now))
+        .map_err(|_| error!(HookError::SourceNotVerified))?;
+    load_reit_account::<KycAttestation>(&ctx.accounts.destination_kyc_attestation)
+        .and_then(|attestation| attestation.require_valid(now))
+        .map_err(|_| error!(HookError::DestinationNotVerified))?;
+
+    // The sender must be outside their resale hold period; no lockup account means never locked
+    if !ctx.accounts.source_holder_lockup.data_is_empty() {
+        let lockup = load_reit_account::<HolderLockup>(&ctx.accounts.source_holder_lockup)?;
+        if now < lockup.locked_until {
+            debug_msg!("ERROR: Holder {} is locked until {}", lockup.holder, lockup.locked_until);
+            return Err(error!(HookError::HoldPeriodActive));
+        }
+    }
+
+    debug_msg!("TransferHook handler complete");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: source token account owner or delegate, authority already checked by Token-2022
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList account
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub reit_program: Program<'info, Canadianreitinvest>,
+
+    /// CHECK: address resolved by Token-2022 from the extra account meta list
+    pub source_investor: UncheckedAccount<'info>,
+
+    /// CHECK: address resolved from the meta list, deserialized and owner-checked in the handler
+    pub source_kyc_attestation: UncheckedAccount<'info>,
+
+    /// CHECK: address resolved by Token-2022 from the extra account meta list
+    pub destination_investor: UncheckedAccount<'info>,
+
+    /// CHECK: address resolved from the meta list, deserialized and owner-checked in the handler
+    pub destination_kyc_attestation: UncheckedAccount<'info>,
+
+    /// CHECK: address resolved from the meta list, may be uninitialized
+    pub source_holder_lockup: UncheckedAccount<'info>,
+}
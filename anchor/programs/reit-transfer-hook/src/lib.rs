@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+declare_id!("98ABkLqEXy5MPkVEZv7Wz4TtSTr9be5sCrM1LLKxjj66");
+
+/// `msg!` that only logs when built with the `debug-logs` feature. Without it the call is
+/// dead code and optimized out, so a release build logs nothing on every REIT transfer.
+macro_rules! debug_msg {
+    ($($arg:tt)*) => {
+        if cfg!(feature = "debug-logs") {
+            anchor_lang::prelude::msg!($($arg)*);
+        }
+    };
+}
+
+mod errors;
+mod instructions;
+
+use instructions::initialize_extra_account_meta_list::*;
+use instructions::transfer_hook::*;
+
+#[program]
+pub mod reit_transfer_hook {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        instructions::initialize_extra_account_meta_list::handler(ctx)
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        instructions::transfer_hook::handler(ctx, amount)
+    }
+}
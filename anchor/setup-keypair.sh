@@ -1,17 +1,19 @@
 #!/bin/bash
 
-# Script to ensure the backed-up keypair is used for deployment
+# Script to ensure the backed-up keypairs are used for deployment
 # This prevents program ID changes during builds
 
-KEYPAIR_BACKUP="keys/canadianreitinvest-keypair.json"
-KEYPAIR_TARGET="target/deploy/canadianreitinvest-keypair.json"
+for PROGRAM in canadianreitinvest reit_transfer_hook; do
+    KEYPAIR_BACKUP="keys/${PROGRAM//_/-}-keypair.json"
+    KEYPAIR_TARGET="target/deploy/${PROGRAM}-keypair.json"
 
-if [ -f "$KEYPAIR_BACKUP" ]; then
-    echo "Copying backed-up keypair to deployment location..."
-    cp "$KEYPAIR_BACKUP" "$KEYPAIR_TARGET"
-    echo "Keypair ready for deployment. Program ID: $(solana-keygen pubkey $KEYPAIR_TARGET)"
-else
-    echo "Error: Backed-up keypair not found at $KEYPAIR_BACKUP"
-    echo "Run this script from the anchor/ directory"
-    exit 1
-fi
\ No newline at end of file
+    if [ -f "$KEYPAIR_BACKUP" ]; then
+        echo "Copying backed-up $PROGRAM keypair to deployment location..."
+        cp "$KEYPAIR_BACKUP" "$KEYPAIR_TARGET"
+        echo "Keypair ready for deployment. Program ID: $(solana-keygen pubkey $KEYPAIR_TARGET)"
+    else
+        echo "Error: Backed-up keypair not found at $KEYPAIR_BACKUP"
+        echo "Run this script from the anchor/ directory"
+        exit 1
+    fi
+done
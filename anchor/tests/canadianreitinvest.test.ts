@@ -41,7 +41,7 @@ describe('canadianreitinvest', () => {
     )
 
     await program.methods
-      .initializeFundraiser(uuid, reitIdHash)
+      .initializeFundraiser(uuid, reitIdHash, Array.from(Buffer.from('CAD')))
       .accounts({
         admin: admin.publicKey,
         usdcMint,
@@ -56,6 +56,7 @@ describe('canadianreitinvest', () => {
     expect(fundraiserAccount.admin.toString()).toBe(admin.publicKey.toString())
     expect(fundraiserAccount.totalRaised.eq(new anchor.BN(0))).toBe(true)
     expect(fundraiserAccount.releasedAmount.eq(new anchor.BN(0))).toBe(true)
+    expect(fundraiserAccount.status).toEqual({ draft: {} })
   })
 
   // NOTE: Additional tests for invest, release, and issue_share operations have been
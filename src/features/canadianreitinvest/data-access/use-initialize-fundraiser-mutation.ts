@@ -9,12 +9,24 @@ import { CANADIANREITINVEST_PROGRAM_ADDRESS } from '@/generated/programs/canadia
 import { supabase } from '@/lib/supabase'
 import { v4 as uuidv4, parse as uuidParse } from 'uuid'
 
+// ISO 4217 codes the program accepts as a fundraiser currency (ACCEPTED_CURRENCIES on-chain)
+export const ACCEPTED_CURRENCIES = ['CAD', 'USD', 'EUR', 'GBP', 'CHF', 'AUD'] as const
+export type AcceptedCurrency = (typeof ACCEPTED_CURRENCIES)[number]
+
 export function useInitializeFundraiserMutation({ account }: { account: UiWalletAccount }) {
   const txSigner = useWalletUiSigner({ account })
   const signAndSend = useWalletUiSignAndSend()
 
   return useMutation({
-    mutationFn: async ({ reitName, usdcMint }: { reitName: string; usdcMint: PublicKey }) => {
+    mutationFn: async ({
+      reitName,
+      usdcMint,
+      currency,
+    }: {
+      reitName: string
+      usdcMint: PublicKey
+      currency: AcceptedCurrency
+    }) => {
       // Generate UUID string and parse to bytes
     const uuid = uuidv4()
     const reitIdHash = uuidParse(uuid)
@@ -49,6 +61,7 @@ export function useInitializeFundraiserMutation({ account }: { account: UiWallet
         usdcMint: usdcMint.toBase58() as Address,
         reitId: uuid,
         reitIdHash: reitIdHash as unknown as Uint8Array,
+        reitAcceptedCurrency: new TextEncoder().encode(currency),
       })
 
       // Execute onchain transaction first
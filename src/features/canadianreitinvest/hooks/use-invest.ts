@@ -11,7 +11,7 @@ import { CANADIANREITINVEST_PROGRAM_ADDRESS } from '@/generated/programs/canadia
 import { fetchMaybeFundraiser } from '@/generated/accounts/fundraiser'
 import { fetchMaybeInvestorFundraiser } from '@/generated/accounts/investorFundraiser'
 import { supabase } from '@/lib/supabase'
-import { deriveInvestorFundraiserPda, deriveInvestmentPda } from '@/lib/pda-utils'
+import { deriveInvestorFundraiserPda, deriveInvestmentPda, deriveKycAttestationPda } from '@/lib/pda-utils'
 import { getSolanaExplorerUrl } from '@/lib/cluster-endpoints'
 
 export function useInvest({ account }: { account: UiWalletAccount }) {
@@ -112,6 +112,13 @@ export function useInvest({ account }: { account: UiWalletAccount }) {
         seeds: ['investor', investorPublicKey.toBase58()],
       })
 
+      // The compliance authority must have attested this investor; invest checks it on-chain
+      const [kycAttestationPda] = deriveKycAttestationPda(investorPda)
+      console.log('[INVEST] KYC attestation PDA:', {
+        address: kycAttestationPda.toBase58(),
+        seeds: ['kyc_attestation', investorPda.toBase58()],
+      })
+
       // Step 6: Derive InvestorFundraiser PDA and fetch current counter
       const [investorFundraiserPda, investorFundraiserBump] = deriveInvestorFundraiserPda(investorPublicKey, fundraiserPda)
       console.log('[INVEST] InvestorFundraiser PDA derivation:', {
@@ -158,6 +165,7 @@ export function useInvest({ account }: { account: UiWalletAccount }) {
       console.log('[INVEST] Building invest instruction with accounts:', {
         investorSigner: account.publicKey,
         investor: investorPda.toBase58(),
+        kycAttestation: kycAttestationPda.toBase58(),
         investorFundraiser: investorFundraiserPda.toBase58(),
         fundraiser: fundraiserPda.toBase58(),
         investment: investmentPda.toBase58(),
@@ -172,6 +180,7 @@ export function useInvest({ account }: { account: UiWalletAccount }) {
       const instruction = await getInvestInstructionAsync({
         investorSigner: signer,
         investor: investorPda.toBase58() as Address,
+        kycAttestation: kycAttestationPda.toBase58() as Address,
         investorFundraiser: investorFundraiserPda.toBase58() as Address,
         fundraiser: fundraiserPda.toBase58() as Address,
         investment: investmentPda.toBase58() as Address,
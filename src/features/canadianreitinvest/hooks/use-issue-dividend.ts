@@ -10,6 +10,7 @@ import { useSolana } from '@/components/solana/use-solana'
 import { CANADIANREITINVEST_PROGRAM_ADDRESS } from '@/generated/programs/canadianreitinvest'
 import { fetchMaybeFundraiser } from '@/generated/accounts/fundraiser'
 import { fetchMaybeInvestment } from '@/generated/accounts/investment'
+import { deriveInvestorPda, deriveKycAttestationPda } from '@/lib/pda-utils'
 import { getSolanaExplorerUrl } from '@/lib/cluster-endpoints'
 
 /**
@@ -109,6 +110,14 @@ export function useIssueDividend({ account }: { account: UiWalletAccount }) {
         investor: investor.toBase58(),
       })
 
+      // KYC attestation is keyed by the investor's Investor PDA, not the wallet
+      const [investorPda] = deriveInvestorPda(investor)
+      const [kycAttestation] = deriveKycAttestationPda(investorPda)
+      console.log('[DIVIDEND] KYC attestation:', {
+        address: kycAttestation.toBase58(),
+        investorPda: investorPda.toBase58(),
+      })
+
       // Convert USDC amount to smallest unit (6 decimals)
       const amountInSmallestUnit = Math.floor(amountUSDC * 1_000_000)
       console.log('[DIVIDEND] Amount conversion:', {
@@ -122,6 +131,8 @@ export function useIssueDividend({ account }: { account: UiWalletAccount }) {
         admin: adminPublicKey.toBase58(),
         investment: investmentPdaPubkey.toBase58(),
         investor: investor.toBase58(),
+        investorProfile: investorPda.toBase58(),
+        kycAttestation: kycAttestation.toBase58(),
         fundraiser: fundraiserPda.toBase58(),
         adminUsdcAta: adminUsdcAta.toBase58(),
         investorUsdcAta: investorUsdcAta.toBase58(),
@@ -133,6 +144,8 @@ export function useIssueDividend({ account }: { account: UiWalletAccount }) {
         admin: signer,
         investment: investmentPdaPubkey.toBase58() as Address,
         investor: investor.toBase58() as Address,
+        investorProfile: investorPda.toBase58() as Address,
+        kycAttestation: kycAttestation.toBase58() as Address,
         fundraiser: fundraiserPda.toBase58() as Address,
         adminUsdcAta: adminUsdcAta.toBase58() as Address,
         investorUsdcAta: investorUsdcAta.toBase58() as Address,
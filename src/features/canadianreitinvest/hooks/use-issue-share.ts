@@ -128,6 +128,11 @@ export function useIssueShare({ account }: { account: UiWalletAccount }) {
       )
       console.log('[ISSUE SHARE DEBUG] Investor ATA (owned by wallet):', investorAta.toBase58())
 
+      // Classic-token mints with a hold period mint into a per-investment lock vault; otherwise
+      // pass the program address so the generated client leaves the optional account out
+      const usesLockVault = fundraiser.reitTokenProgram === TOKEN_PROGRAM_ID.toBase58() && fundraiser.holdPeriodSeconds > 0n
+      console.log('[ISSUE SHARE DEBUG] Uses lock vault:', usesLockVault)
+
       // Build issue share instruction
       console.log('[ISSUE SHARE DEBUG] Building issue share instruction...')
       
@@ -155,10 +160,12 @@ export function useIssueShare({ account }: { account: UiWalletAccount }) {
         investorWallet: investorPubkey.toBase58() as Address,
         reitMint: reitMintPubkey.toBase58() as Address,
         investorAta: investorAta.toBase58() as Address,
+        lockVault: usesLockVault ? undefined : CANADIANREITINVEST_PROGRAM_ADDRESS,
         tokenProgram: TOKEN_PROGRAM_ID.toBase58() as Address,
         investorPubkey: investorPubkey.toBase58() as Address,
         reitIdHash: reitIdHash,
-        sharePrice: finalSharePrice,
+        // The metadata price is in units of the fundraiser currency; the program takes cents
+        sharePrice: Math.round(finalSharePrice * 100),
       })
       console.log('[ISSUE SHARE DEBUG] Issue share instruction built successfully')
       console.log('[ISSUE SHARE DEBUG] Instruction accounts:', instruction.accounts?.map((acc: any) => ({
@@ -10,6 +10,7 @@ import { useSolana } from '@/components/solana/use-solana'
 import { CANADIANREITINVEST_PROGRAM_ADDRESS } from '@/generated/programs/canadianreitinvest'
 import { fetchMaybeFundraiser } from '@/generated/accounts/fundraiser'
 import { fetchMaybeInvestment } from '@/generated/accounts/investment'
+import { deriveInvestorFundraiserPda, deriveInvestorPda } from '@/lib/pda-utils'
 import { parse as uuidParse } from 'uuid'
 
 export function useRefund({ account }: { account: UiWalletAccount }) {
@@ -95,9 +96,22 @@ export function useRefund({ account }: { account: UiWalletAccount }) {
         throw new Error('Signer is not the fundraiser admin')
       }
 
-      // Get USDC mint from fundraiser
-      const usdcMint = new PublicKey(fundraiser.usdcMint)
+      // Refund in the stablecoin the investment was deposited in, through that mint's escrow vault
+      const acceptedMint = fundraiser.acceptedMints.find((accepted) => accepted.mint === investment.depositMint)
+      if (!acceptedMint) {
+        console.error('[REFUND DEBUG] Deposit mint is not accepted by the fundraiser:', investment.depositMint)
+        throw new Error('Deposit mint is not accepted by this fundraiser')
+      }
+      const usdcMint = new PublicKey(acceptedMint.mint)
+      const escrowVault = new PublicKey(acceptedMint.escrowVault)
+      console.log('[REFUND DEBUG] Deposit mint:', usdcMint.toBase58())
+      console.log('[REFUND DEBUG] Escrow vault:', escrowVault.toBase58())
+
       const investor = new PublicKey(investment.investor)
+      const [investorPda] = deriveInvestorPda(investor)
+      console.log('[REFUND DEBUG] Investor PDA:', investorPda.toBase58())
+      const [investorFundraiserPda] = deriveInvestorFundraiserPda(investor, fundraiserPda)
+      console.log('[REFUND DEBUG] InvestorFundraiser PDA:', investorFundraiserPda.toBase58())
 
       // Get admin USDC ATA
       const adminUsdcAta = getAssociatedTokenAddressSync(usdcMint, adminPublicKey)
@@ -114,9 +128,12 @@ export function useRefund({ account }: { account: UiWalletAccount }) {
         fundraiser: fundraiserPda.toBase58() as Address,
         investment: investmentPda as Address,
         investor: investor.toBase58() as Address,
+        investorPda: investorPda.toBase58() as Address,
+        investorFundraiser: investorFundraiserPda.toBase58() as Address,
         adminUsdcAta: adminUsdcAta.toBase58() as Address,
         investorUsdcAta: investorUsdcAta.toBase58() as Address,
         usdcMint: usdcMint.toBase58() as Address,
+        escrowVault: escrowVault.toBase58() as Address,
         reitIdHash: reitIdHash,
       })
       console.log('[REFUND DEBUG] Refund instruction built successfully')
@@ -102,14 +102,21 @@ export function useRelease({ account }: { account: UiWalletAccount }) {
         throw new Error('Signer is not the fundraiser admin')
       }
 
-      // Get admin's USDC ATA
-      const usdcMint = new PublicKey(fundraiser.usdcMint)
+      // Release from the escrow vault of the stablecoin the investment was deposited in
+      const acceptedMint = fundraiser.acceptedMints.find((accepted) => accepted.mint === investment.depositMint)
+      if (!acceptedMint) {
+        console.error('[RELEASE DEBUG] Deposit mint is not accepted by the fundraiser:', investment.depositMint)
+        throw new Error('Deposit mint is not accepted by this fundraiser')
+      }
+
+      // Get admin's ATA for the deposit mint
+      const usdcMint = new PublicKey(acceptedMint.mint)
       const adminUsdcAta = getAssociatedTokenAddressSync(usdcMint, adminPublicKey)
-      console.log('[RELEASE DEBUG] USDC mint:', usdcMint.toBase58())
+      console.log('[RELEASE DEBUG] Deposit mint:', usdcMint.toBase58())
       console.log('[RELEASE DEBUG] Admin USDC ATA:', adminUsdcAta.toBase58())
 
       // Get escrow vault
-      const escrowVault = new PublicKey(fundraiser.escrowVault)
+      const escrowVault = new PublicKey(acceptedMint.escrowVault)
       console.log('[RELEASE DEBUG] Escrow vault:', escrowVault.toBase58())
 
       // Log the transfer details
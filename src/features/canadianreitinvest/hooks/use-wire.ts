@@ -12,6 +12,18 @@ import { fetchMaybeInvestment } from '@/generated/accounts/investment'
 import { getSolanaExplorerUrl } from '@/lib/cluster-endpoints'
 import { parse as uuidParse } from 'uuid'
 
+// Matches FX_RATE_SCALE in the program: rates are stored as integers scaled by 1e6
+const FX_RATE_SCALE = 1_000_000
+
+export type WireDetails = {
+  investmentPda: string
+  reitId: string
+  wiredAmount: number // Fiat received by the REIT, in the fundraiser currency (e.g. 1234.56 CAD)
+  fxRate: number // Fundraiser currency per unit of the deposit mint's currency (e.g. 1.37)
+  wireDate: Date // When the bank wire settled
+  wireReference: string // Bank confirmation / wire reference number, stored on-chain as its SHA-256
+}
+
 export function useWire({ account }: { account: UiWalletAccount }) {
   const signer = useWalletUiSigner({ account })
   const signAndSend = useWalletUiSignAndSend()
@@ -19,7 +31,7 @@ export function useWire({ account }: { account: UiWalletAccount }) {
   const queryClient = useQueryClient()
 
   return useMutation({
-    mutationFn: async ({ investmentPda, reitId }: { investmentPda: string; reitId: string }) => {
+    mutationFn: async ({ investmentPda, reitId, wiredAmount, fxRate, wireDate, wireReference }: WireDetails) => {
       if (!account?.publicKey) throw new Error('Wallet not connected')
 
       const programId = new PublicKey(CANADIANREITINVEST_PROGRAM_ADDRESS as string)
@@ -95,6 +107,26 @@ export function useWire({ account }: { account: UiWalletAccount }) {
         throw new Error('Signer is not the fundraiser admin')
       }
 
+      // Convert the wire details to the program's units
+      const wiredAmountCents = BigInt(Math.round(wiredAmount * 100))
+      const fxRateScaled = BigInt(Math.round(fxRate * FX_RATE_SCALE))
+      const wireDateSeconds = BigInt(Math.floor(wireDate.getTime() / 1000))
+      const wireReferenceHash = new Uint8Array(
+        await crypto.subtle.digest('SHA-256', new TextEncoder().encode(wireReference.trim()))
+      )
+      if (wiredAmountCents <= 0n || fxRateScaled <= 0n) {
+        throw new Error('Wired amount and FX rate must be greater than zero')
+      }
+      if (wireDate.getTime() > Date.now()) {
+        throw new Error('Wire date cannot be in the future')
+      }
+      console.log('[WIRE DEBUG] Wire details:', {
+        wiredAmountCents: wiredAmountCents.toString(),
+        fxRateScaled: fxRateScaled.toString(),
+        wireDateSeconds: wireDateSeconds.toString(),
+        wireReferenceHash: Buffer.from(wireReferenceHash).toString('hex'),
+      })
+
       // Build wire instruction
       console.log('[WIRE DEBUG] Building wire instruction...')
       const instruction = await getWireInstructionAsync({
@@ -102,6 +134,10 @@ export function useWire({ account }: { account: UiWalletAccount }) {
         fundraiser: fundraiserPda.toBase58() as Address,
         investment: investmentPda as Address,
         reitIdHash: reitIdHash,
+        wiredAmount: wiredAmountCents,
+        fxRate: fxRateScaled,
+        wireDate: wireDateSeconds,
+        wireReferenceHash,
       })
       console.log('[WIRE DEBUG] Wire instruction built successfully')
 
@@ -2,6 +2,9 @@ import { InvestmentStatus } from '@/generated/types/investmentStatus'
 import { Table, TableHeader, TableBody, TableRow, TableHead, TableCell } from '@/components/ui/table'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
 import { AppModal } from '@/components/app-modal'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import { useState } from 'react'
 import { useRelease } from '../hooks/use-release'
 import { useRefund } from '../hooks/use-refund'
 import { useWire } from '../hooks/use-wire'
@@ -27,6 +30,10 @@ export default function BrowseInvestments({ isAdmin = false, userId }: { isAdmin
   const refundMutation = useRefund({ account: account! })
   const wireMutation = useWire({ account: account! })
   const issueShareMutation = useIssueShare({ account: account! })
+  const [wiredAmount, setWiredAmount] = useState('')
+  const [fxRate, setFxRate] = useState('')
+  const [wireDate, setWireDate] = useState('')
+  const [wireReference, setWireReference] = useState('')
 
   if (isLoading) return <span className="loading loading-spinner loading-md" />
   if (error) return <div className="text-red-600">Error: {error instanceof Error ? error.message : String(error)}</div>
@@ -185,7 +192,7 @@ export default function BrowseInvestments({ isAdmin = false, userId }: { isAdmin
                                   </p>
                                 </div>
                                 <p className="text-sm text-muted-foreground">
-                                  This will mark the investment as refunded. The investor will need to be refunded off-chain.
+                                  This will return the deposit from your admin wallet to the escrow vault, refund it to the investor, and mark the investment as refunded.
                                 </p>
                               </div>
                             </AppModal>
@@ -195,8 +202,12 @@ export default function BrowseInvestments({ isAdmin = false, userId }: { isAdmin
                               submit={() => wireMutation.mutate({
                                 investmentPda: row.investment_pda,
                                 reitId: row.reit_id!,
+                                wiredAmount: Number(wiredAmount),
+                                fxRate: Number(fxRate),
+                                wireDate: new Date(wireDate),
+                                wireReference,
                               })}
-                              submitDisabled={wireMutation.isPending}
+                              submitDisabled={wireMutation.isPending || !wiredAmount || !fxRate || !wireDate || !wireReference.trim()}
                             >
                               <div className="space-y-4">
                                 <p>Are you sure you want to wire this investment?</p>
@@ -214,6 +225,24 @@ export default function BrowseInvestments({ isAdmin = false, userId }: { isAdmin
                                 <p className="text-sm text-muted-foreground">
                                   This will mark the investment as wired, indicating that the funds have been transferred to the REIT.
                                 </p>
+                                <div className="grid gap-3">
+                                  <div className="space-y-1">
+                                    <Label htmlFor="wiredAmount">Amount received by the REIT (fundraiser currency)</Label>
+                                    <Input id="wiredAmount" type="number" min="0" step="0.01" value={wiredAmount} onChange={(e) => setWiredAmount(e.target.value)} />
+                                  </div>
+                                  <div className="space-y-1">
+                                    <Label htmlFor="fxRate">FX rate (fundraiser currency per USDC)</Label>
+                                    <Input id="fxRate" type="number" min="0" step="0.000001" value={fxRate} onChange={(e) => setFxRate(e.target.value)} />
+                                  </div>
+                                  <div className="space-y-1">
+                                    <Label htmlFor="wireDate">Wire settlement date</Label>
+                                    <Input id="wireDate" type="date" value={wireDate} onChange={(e) => setWireDate(e.target.value)} />
+                                  </div>
+                                  <div className="space-y-1">
+                                    <Label htmlFor="wireReference">Wire reference number</Label>
+                                    <Input id="wireReference" value={wireReference} onChange={(e) => setWireReference(e.target.value)} />
+                                  </div>
+                                </div>
                               </div>
                             </AppModal>
                           </div>
@@ -3,7 +3,11 @@ import { Button } from '@/components/ui/button'
 import { Input } from '@/components/ui/input'
 import { Label } from '@/components/ui/label'
 import { useState } from 'react'
-import { useInitializeFundraiserMutation } from '../data-access/use-initialize-fundraiser-mutation'
+import {
+  ACCEPTED_CURRENCIES,
+  AcceptedCurrency,
+  useInitializeFundraiserMutation,
+} from '../data-access/use-initialize-fundraiser-mutation'
 import { PublicKey } from '@solana/web3.js'
 import { useSolana } from '@/components/solana/use-solana'
 import { CLUSTER_CONFIG } from '@/lib/cluster-config'
@@ -13,6 +17,7 @@ export function CanadianreitinvestUiInitializeFundraiser({ account }: { account:
   const { cluster } = useSolana()
   const [reitName, setReitName] = useState('')
   const [usdcMint, setUsdcMint] = useState('')
+  const [currency, setCurrency] = useState<AcceptedCurrency>('CAD')
 
   const initializeMutation = useInitializeFundraiserMutation({ account })
 
@@ -29,8 +34,8 @@ export function CanadianreitinvestUiInitializeFundraiser({ account }: { account:
     }
     try {
       const usdcMintPubkey = new PublicKey(mintToUse)
-      console.debug('[InitializeFundraiser] Starting mutation with REIT name:', reitName, 'USDC Mint:', mintToUse)
-      await initializeMutation.mutateAsync({ reitName, usdcMint: usdcMintPubkey })
+      console.debug('[InitializeFundraiser] Starting mutation with REIT name:', reitName, 'USDC Mint:', mintToUse, 'Currency:', currency)
+      await initializeMutation.mutateAsync({ reitName, usdcMint: usdcMintPubkey, currency })
     } catch (error) {
       console.error('Invalid USDC mint address or mutation error:', error)
       toast.error(`Error: ${(error as Error).message}`)
@@ -61,6 +66,21 @@ export function CanadianreitinvestUiInitializeFundraiser({ account }: { account:
           placeholder="e.g. Maple REIT"
         />
       </div>
+      <div>
+        <Label htmlFor="currency">REIT Currency</Label>
+        <select
+          id="currency"
+          value={currency}
+          onChange={(e) => setCurrency(e.target.value as AcceptedCurrency)}
+          className="border-input h-9 w-full rounded-md border bg-transparent px-3 py-1 text-sm shadow-xs"
+        >
+          {ACCEPTED_CURRENCIES.map((code) => (
+            <option key={code} value={code}>
+              {code}
+            </option>
+          ))}
+        </select>
+      </div>
       {!(isDevnet || isLocalnet) && (
         <div>
           <Label htmlFor="usdcMint">USDC Mint Address</Label>
@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   assertAccountExists,
   assertAccountsExist,
   combineCodec,
@@ -17,27 +19,61 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getAcceptedMintDecoder,
+  getAcceptedMintEncoder,
+  getFundraiserStatusDecoder,
+  getFundraiserStatusEncoder,
+  getInvestmentLimitsDecoder,
+  getInvestmentLimitsEncoder,
+  getJurisdictionDecoder,
+  getJurisdictionEncoder,
+  getJurisdictionModeDecoder,
+  getJurisdictionModeEncoder,
+  getOfferingExemptionDecoder,
+  getOfferingExemptionEncoder,
+  type AcceptedMint,
+  type AcceptedMintArgs,
+  type FundraiserStatus,
+  type FundraiserStatusArgs,
+  type InvestmentLimits,
+  type InvestmentLimitsArgs,
+  type Jurisdiction,
+  type JurisdictionArgs,
+  type JurisdictionMode,
+  type JurisdictionModeArgs,
+  type OfferingExemption,
+  type OfferingExemptionArgs,
+} from '../types';
 
 export const FUNDRAISER_DISCRIMINATOR = new Uint8Array([
   167, 106, 143, 202, 135, 131, 204, 196,
@@ -49,62 +85,123 @@ export function getFundraiserDiscriminatorBytes() {
 
 export type Fundraiser = {
   discriminator: ReadonlyUint8Array;
+  version: number;
   admin: Address;
   usdcMint: Address;
   reitMint: Address;
+  reitTokenProgram: Address;
   escrowVault: Address;
   totalRaised: bigint;
   releasedAmount: bigint;
   bump: number;
   reitAcceptedCurrency: ReadonlyUint8Array;
+  exemption: OfferingExemption;
+  investmentLimits: InvestmentLimits;
+  jurisdictionMode: JurisdictionMode;
+  jurisdictionRules: Array<Jurisdiction>;
+  holdPeriodSeconds: bigint;
+  acceptedMints: Array<AcceptedMint>;
+  reitId: string;
+  status: FundraiserStatus;
+  minRaise: bigint;
+  maxRaise: bigint;
+  closeAt: bigint;
+  investmentCount: bigint;
+  openInvestmentCount: bigint;
+  reserved: ReadonlyUint8Array;
 };
 
 export type FundraiserArgs = {
+  version: number;
   admin: Address;
   usdcMint: Address;
   reitMint: Address;
+  reitTokenProgram: Address;
   escrowVault: Address;
   totalRaised: number | bigint;
   releasedAmount: number | bigint;
   bump: number;
   reitAcceptedCurrency: ReadonlyUint8Array;
+  exemption: OfferingExemptionArgs;
+  investmentLimits: InvestmentLimitsArgs;
+  jurisdictionMode: JurisdictionModeArgs;
+  jurisdictionRules: Array<JurisdictionArgs>;
+  holdPeriodSeconds: number | bigint;
+  acceptedMints: Array<AcceptedMintArgs>;
+  reitId: string;
+  status: FundraiserStatusArgs;
+  minRaise: number | bigint;
+  maxRaise: number | bigint;
+  closeAt: number | bigint;
+  investmentCount: number | bigint;
+  openInvestmentCount: number | bigint;
+  reserved: ReadonlyUint8Array;
 };
 
-export function getFundraiserEncoder(): FixedSizeEncoder<FundraiserArgs> {
+export function getFundraiserEncoder(): Encoder<FundraiserArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['admin', getAddressEncoder()],
       ['usdcMint', getAddressEncoder()],
       ['reitMint', getAddressEncoder()],
+      ['reitTokenProgram', getAddressEncoder()],
       ['escrowVault', getAddressEncoder()],
       ['totalRaised', getU64Encoder()],
       ['releasedAmount', getU64Encoder()],
       ['bump', getU8Encoder()],
       ['reitAcceptedCurrency', fixEncoderSize(getBytesEncoder(), 3)],
+      ['exemption', getOfferingExemptionEncoder()],
+      ['investmentLimits', getInvestmentLimitsEncoder()],
+      ['jurisdictionMode', getJurisdictionModeEncoder()],
+      ['jurisdictionRules', getArrayEncoder(getJurisdictionEncoder())],
+      ['holdPeriodSeconds', getI64Encoder()],
+      ['acceptedMints', getArrayEncoder(getAcceptedMintEncoder())],
+      ['reitId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['status', getFundraiserStatusEncoder()],
+      ['minRaise', getU64Encoder()],
+      ['maxRaise', getU64Encoder()],
+      ['closeAt', getI64Encoder()],
+      ['investmentCount', getU64Encoder()],
+      ['openInvestmentCount', getU64Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 64)],
     ]),
     (value) => ({ ...value, discriminator: FUNDRAISER_DISCRIMINATOR })
   );
 }
 
-export function getFundraiserDecoder(): FixedSizeDecoder<Fundraiser> {
+export function getFundraiserDecoder(): Decoder<Fundraiser> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['admin', getAddressDecoder()],
     ['usdcMint', getAddressDecoder()],
     ['reitMint', getAddressDecoder()],
+    ['reitTokenProgram', getAddressDecoder()],
     ['escrowVault', getAddressDecoder()],
     ['totalRaised', getU64Decoder()],
     ['releasedAmount', getU64Decoder()],
     ['bump', getU8Decoder()],
     ['reitAcceptedCurrency', fixDecoderSize(getBytesDecoder(), 3)],
+    ['exemption', getOfferingExemptionDecoder()],
+    ['investmentLimits', getInvestmentLimitsDecoder()],
+    ['jurisdictionMode', getJurisdictionModeDecoder()],
+    ['jurisdictionRules', getArrayDecoder(getJurisdictionDecoder())],
+    ['holdPeriodSeconds', getI64Decoder()],
+    ['acceptedMints', getArrayDecoder(getAcceptedMintDecoder())],
+    ['reitId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['status', getFundraiserStatusDecoder()],
+    ['minRaise', getU64Decoder()],
+    ['maxRaise', getU64Decoder()],
+    ['closeAt', getI64Decoder()],
+    ['investmentCount', getU64Decoder()],
+    ['openInvestmentCount', getU64Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 64)],
   ]);
 }
 
-export function getFundraiserCodec(): FixedSizeCodec<
-  FundraiserArgs,
-  Fundraiser
-> {
+export function getFundraiserCodec(): Codec<FundraiserArgs, Fundraiser> {
   return combineCodec(getFundraiserEncoder(), getFundraiserDecoder());
 }
 
@@ -160,7 +257,3 @@ export async function fetchAllMaybeFundraiser(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeFundraiser(maybeAccount));
 }
-
-export function getFundraiserSize(): number {
-  return 156;
-}
@@ -6,7 +6,12 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './complianceConfig';
 export * from './fundraiser';
+export * from './fxRate';
+export * from './holderLockup';
 export * from './investment';
 export * from './investor';
 export * from './investorFundraiser';
+export * from './kycAttestation';
+export * from './wireBatchRecord';
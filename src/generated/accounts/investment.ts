@@ -17,8 +17,12 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -30,12 +34,12 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
@@ -43,8 +47,12 @@ import {
 import {
   getInvestmentStatusDecoder,
   getInvestmentStatusEncoder,
+  getStatusChangeDecoder,
+  getStatusChangeEncoder,
   type InvestmentStatus,
   type InvestmentStatusArgs,
+  type StatusChange,
+  type StatusChangeArgs,
 } from '../types';
 
 export const INVESTMENT_DISCRIMINATOR = new Uint8Array([
@@ -57,54 +65,107 @@ export function getInvestmentDiscriminatorBytes() {
 
 export type Investment = {
   discriminator: ReadonlyUint8Array;
+  version: number;
   investor: Address;
   fundraiser: Address;
   usdcAmount: bigint;
   reitAmount: number;
   status: InvestmentStatus;
   bump: number;
+  lockupUntil: bigint;
+  wiredAmount: bigint;
+  fxRate: bigint;
+  wireDate: bigint;
+  wireReferenceHash: ReadonlyUint8Array;
+  currency: ReadonlyUint8Array;
+  sharePrice: bigint;
+  depositMint: Address;
+  createdAt: bigint;
+  createdSlot: bigint;
+  lastStatusChange: bigint;
+  statusHistory: Array<StatusChange>;
+  reserved: ReadonlyUint8Array;
 };
 
 export type InvestmentArgs = {
+  version: number;
   investor: Address;
   fundraiser: Address;
   usdcAmount: number | bigint;
   reitAmount: number;
   status: InvestmentStatusArgs;
   bump: number;
+  lockupUntil: number | bigint;
+  wiredAmount: number | bigint;
+  fxRate: number | bigint;
+  wireDate: number | bigint;
+  wireReferenceHash: ReadonlyUint8Array;
+  currency: ReadonlyUint8Array;
+  sharePrice: number | bigint;
+  depositMint: Address;
+  createdAt: number | bigint;
+  createdSlot: number | bigint;
+  lastStatusChange: number | bigint;
+  statusHistory: Array<StatusChangeArgs>;
+  reserved: ReadonlyUint8Array;
 };
 
-export function getInvestmentEncoder(): FixedSizeEncoder<InvestmentArgs> {
+export function getInvestmentEncoder(): Encoder<InvestmentArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['investor', getAddressEncoder()],
       ['fundraiser', getAddressEncoder()],
       ['usdcAmount', getU64Encoder()],
       ['reitAmount', getU32Encoder()],
       ['status', getInvestmentStatusEncoder()],
       ['bump', getU8Encoder()],
+      ['lockupUntil', getI64Encoder()],
+      ['wiredAmount', getU64Encoder()],
+      ['fxRate', getU64Encoder()],
+      ['wireDate', getI64Encoder()],
+      ['wireReferenceHash', fixEncoderSize(getBytesEncoder(), 32)],
+      ['currency', fixEncoderSize(getBytesEncoder(), 3)],
+      ['sharePrice', getU64Encoder()],
+      ['depositMint', getAddressEncoder()],
+      ['createdAt', getI64Encoder()],
+      ['createdSlot', getU64Encoder()],
+      ['lastStatusChange', getI64Encoder()],
+      ['statusHistory', getArrayEncoder(getStatusChangeEncoder())],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 64)],
     ]),
     (value) => ({ ...value, discriminator: INVESTMENT_DISCRIMINATOR })
   );
 }
 
-export function getInvestmentDecoder(): FixedSizeDecoder<Investment> {
+export function getInvestmentDecoder(): Decoder<Investment> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['investor', getAddressDecoder()],
     ['fundraiser', getAddressDecoder()],
     ['usdcAmount', getU64Decoder()],
     ['reitAmount', getU32Decoder()],
     ['status', getInvestmentStatusDecoder()],
     ['bump', getU8Decoder()],
+    ['lockupUntil', getI64Decoder()],
+    ['wiredAmount', getU64Decoder()],
+    ['fxRate', getU64Decoder()],
+    ['wireDate', getI64Decoder()],
+    ['wireReferenceHash', fixDecoderSize(getBytesDecoder(), 32)],
+    ['currency', fixDecoderSize(getBytesDecoder(), 3)],
+    ['sharePrice', getU64Decoder()],
+    ['depositMint', getAddressDecoder()],
+    ['createdAt', getI64Decoder()],
+    ['createdSlot', getU64Decoder()],
+    ['lastStatusChange', getI64Decoder()],
+    ['statusHistory', getArrayDecoder(getStatusChangeDecoder())],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 64)],
   ]);
 }
 
-export function getInvestmentCodec(): FixedSizeCodec<
-  InvestmentArgs,
-  Investment
-> {
+export function getInvestmentCodec(): Codec<InvestmentArgs, Investment> {
   return combineCodec(getInvestmentEncoder(), getInvestmentDecoder());
 }
 
@@ -160,7 +221,3 @@ export async function fetchAllMaybeInvestment(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeInvestment(maybeAccount));
 }
-
-export function getInvestmentSize(): number {
-  return 86;
-}
@@ -21,6 +21,8 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -36,6 +38,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getRollingTotalDecoder,
+  getRollingTotalEncoder,
+  type RollingTotal,
+  type RollingTotalArgs,
+} from '../types';
 
 export const INVESTOR_DISCRIMINATOR = new Uint8Array([
   174, 129, 17, 83, 36, 116, 26, 196,
@@ -47,18 +55,33 @@ export function getInvestorDiscriminatorBytes() {
 
 export type Investor = {
   discriminator: ReadonlyUint8Array;
+  version: number;
   investorPubkey: Address;
   bump: number;
+  rollingInvested: RollingTotal;
+  openInvestmentCount: bigint;
+  reserved: ReadonlyUint8Array;
 };
 
-export type InvestorArgs = { investorPubkey: Address; bump: number };
+export type InvestorArgs = {
+  version: number;
+  investorPubkey: Address;
+  bump: number;
+  rollingInvested: RollingTotalArgs;
+  openInvestmentCount: number | bigint;
+  reserved: ReadonlyUint8Array;
+};
 
 export function getInvestorEncoder(): FixedSizeEncoder<InvestorArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['investorPubkey', getAddressEncoder()],
       ['bump', getU8Encoder()],
+      ['rollingInvested', getRollingTotalEncoder()],
+      ['openInvestmentCount', getU64Encoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 64)],
     ]),
     (value) => ({ ...value, discriminator: INVESTOR_DISCRIMINATOR })
   );
@@ -67,8 +90,12 @@ export function getInvestorEncoder(): FixedSizeEncoder<InvestorArgs> {
 export function getInvestorDecoder(): FixedSizeDecoder<Investor> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['investorPubkey', getAddressDecoder()],
     ['bump', getU8Decoder()],
+    ['rollingInvested', getRollingTotalDecoder()],
+    ['openInvestmentCount', getU64Decoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 64)],
   ]);
 }
 
@@ -130,5 +157,5 @@ export async function fetchAllMaybeInvestor(
 }
 
 export function getInvestorSize(): number {
-  return 41;
+  return 226;
 }
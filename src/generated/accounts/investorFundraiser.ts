@@ -38,6 +38,12 @@ import {
   type MaybeEncodedAccount,
   type ReadonlyUint8Array,
 } from 'gill';
+import {
+  getRollingTotalDecoder,
+  getRollingTotalEncoder,
+  type RollingTotal,
+  type RollingTotalArgs,
+} from '../types';
 
 export const INVESTOR_FUNDRAISER_DISCRIMINATOR = new Uint8Array([
   9, 157, 88, 28, 2, 147, 58, 109,
@@ -51,27 +57,39 @@ export function getInvestorFundraiserDiscriminatorBytes() {
 
 export type InvestorFundraiser = {
   discriminator: ReadonlyUint8Array;
+  version: number;
   investor: Address;
   fundraiser: Address;
   investmentCounter: bigint;
   bump: number;
+  investmentAccountCount: bigint;
+  rollingInvested: RollingTotal;
+  reserved: ReadonlyUint8Array;
 };
 
 export type InvestorFundraiserArgs = {
+  version: number;
   investor: Address;
   fundraiser: Address;
   investmentCounter: number | bigint;
   bump: number;
+  investmentAccountCount: number | bigint;
+  rollingInvested: RollingTotalArgs;
+  reserved: ReadonlyUint8Array;
 };
 
 export function getInvestorFundraiserEncoder(): FixedSizeEncoder<InvestorFundraiserArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['investor', getAddressEncoder()],
       ['fundraiser', getAddressEncoder()],
       ['investmentCounter', getU64Encoder()],
       ['bump', getU8Encoder()],
+      ['investmentAccountCount', getU64Encoder()],
+      ['rollingInvested', getRollingTotalEncoder()],
+      ['reserved', fixEncoderSize(getBytesEncoder(), 64)],
     ]),
     (value) => ({ ...value, discriminator: INVESTOR_FUNDRAISER_DISCRIMINATOR })
   );
@@ -80,10 +98,14 @@ export function getInvestorFundraiserEncoder(): FixedSizeEncoder<InvestorFundrai
 export function getInvestorFundraiserDecoder(): FixedSizeDecoder<InvestorFundraiser> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['investor', getAddressDecoder()],
     ['fundraiser', getAddressDecoder()],
     ['investmentCounter', getU64Decoder()],
     ['bump', getU8Decoder()],
+    ['investmentAccountCount', getU64Decoder()],
+    ['rollingInvested', getRollingTotalDecoder()],
+    ['reserved', fixDecoderSize(getBytesDecoder(), 64)],
   ]);
 }
 
@@ -161,5 +183,5 @@ export async function fetchAllMaybeInvestorFundraiser(
 }
 
 export function getInvestorFundraiserSize(): number {
-  return 81;
+  return 266;
 }
@@ -34,34 +34,146 @@ export const CANADIANREITINVEST_ERROR__INVALID_INVESTMENT_STATUS = 0x1777; // 60
 export const CANADIANREITINVEST_ERROR__INVALID_MINT = 0x1778; // 6008
 /** InvalidFundraiserMismatch: Invalid fundraiser mismatch */
 export const CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_MISMATCH = 0x1779; // 6009
+/** KycNotVerified: Investor KYC attestation is not verified */
+export const CANADIANREITINVEST_ERROR__KYC_NOT_VERIFIED = 0x177a; // 6010
+/** KycExpired: Investor KYC attestation has expired */
+export const CANADIANREITINVEST_ERROR__KYC_EXPIRED = 0x177b; // 6011
+/** InvestorNotEligible: Investor category is not eligible for this offering */
+export const CANADIANREITINVEST_ERROR__INVESTOR_NOT_ELIGIBLE = 0x177c; // 6012
+/** InvestmentLimitExceeded: Investment exceeds the investor's rolling 12-month limit */
+export const CANADIANREITINVEST_ERROR__INVESTMENT_LIMIT_EXCEEDED = 0x177d; // 6013
+/** JurisdictionBlocked: Investor jurisdiction is not permitted for this offering */
+export const CANADIANREITINVEST_ERROR__JURISDICTION_BLOCKED = 0x177e; // 6014
+/** TooManyJurisdictionRules: Too many jurisdiction rules */
+export const CANADIANREITINVEST_ERROR__TOO_MANY_JURISDICTION_RULES = 0x177f; // 6015
+/** JurisdictionRuleNotFound: Jurisdiction rule not found */
+export const CANADIANREITINVEST_ERROR__JURISDICTION_RULE_NOT_FOUND = 0x1780; // 6016
+/** HoldPeriodActive: Shares are still within the resale hold period */
+export const CANADIANREITINVEST_ERROR__HOLD_PERIOD_ACTIVE = 0x1781; // 6017
+/** LockVaultRequired: Lock vault account is required for this REIT mint */
+export const CANADIANREITINVEST_ERROR__LOCK_VAULT_REQUIRED = 0x1782; // 6018
+/** RecoveryNotSupported: Position recovery is not supported for this REIT mint */
+export const CANADIANREITINVEST_ERROR__RECOVERY_NOT_SUPPORTED = 0x1783; // 6019
+/** InvalidWireDetails: Invalid wire details */
+export const CANADIANREITINVEST_ERROR__INVALID_WIRE_DETAILS = 0x1784; // 6020
+/** InvalidWireBatch: Invalid wire batch */
+export const CANADIANREITINVEST_ERROR__INVALID_WIRE_BATCH = 0x1785; // 6021
+/** InvalidFxRate: Invalid FX rate */
+export const CANADIANREITINVEST_ERROR__INVALID_FX_RATE = 0x1786; // 6022
+/** StaleFxRate: FX rate is stale */
+export const CANADIANREITINVEST_ERROR__STALE_FX_RATE = 0x1787; // 6023
+/** FxRateConfidenceTooWide: FX rate confidence interval is too wide */
+export const CANADIANREITINVEST_ERROR__FX_RATE_CONFIDENCE_TOO_WIDE = 0x1788; // 6024
+/** UnsupportedCurrency: Currency is not an accepted ISO 4217 code */
+export const CANADIANREITINVEST_ERROR__UNSUPPORTED_CURRENCY = 0x1789; // 6025
+/** FundraiserTermsLocked: Fundraiser terms can no longer be changed */
+export const CANADIANREITINVEST_ERROR__FUNDRAISER_TERMS_LOCKED = 0x178a; // 6026
+/** TooManyAcceptedMints: Fundraiser accepts the maximum number of stablecoin mints */
+export const CANADIANREITINVEST_ERROR__TOO_MANY_ACCEPTED_MINTS = 0x178b; // 6027
+/** InvalidFundraiserStatus: Instruction not allowed in the fundraiser's current status */
+export const CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_STATUS = 0x178c; // 6028
+/** InvalidFundraiserTransition: Fundraiser status transition not allowed */
+export const CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_TRANSITION = 0x178d; // 6029
+/** FundraiserCapExceeded: Investment would exceed the fundraiser cap */
+export const CANADIANREITINVEST_ERROR__FUNDRAISER_CAP_EXCEEDED = 0x178e; // 6030
+/** FundraiserDeadlinePassed: Fundraiser deadline has passed */
+export const CANADIANREITINVEST_ERROR__FUNDRAISER_DEADLINE_PASSED = 0x178f; // 6031
+/** EscrowNotEmpty: Escrow vault still holds funds */
+export const CANADIANREITINVEST_ERROR__ESCROW_NOT_EMPTY = 0x1790; // 6032
+/** InvestmentsOutstanding: Fundraiser still has open investments */
+export const CANADIANREITINVEST_ERROR__INVESTMENTS_OUTSTANDING = 0x1791; // 6033
+/** InvestorHasOpenPositions: Investor still has open investments */
+export const CANADIANREITINVEST_ERROR__INVESTOR_HAS_OPEN_POSITIONS = 0x1792; // 6034
+/** AccountAlreadyMigrated: Account already uses the current layout */
+export const CANADIANREITINVEST_ERROR__ACCOUNT_ALREADY_MIGRATED = 0x1793; // 6035
+/** ReitMintAlreadyCreated: Fundraiser already has a REIT mint */
+export const CANADIANREITINVEST_ERROR__REIT_MINT_ALREADY_CREATED = 0x1794; // 6036
+/** DepositCurrencyMismatch: Stablecoin is not pegged to the fundraiser's deposit currency */
+export const CANADIANREITINVEST_ERROR__DEPOSIT_CURRENCY_MISMATCH = 0x1795; // 6037
 
 export type CanadianreitinvestError =
+  | typeof CANADIANREITINVEST_ERROR__ACCOUNT_ALREADY_MIGRATED
   | typeof CANADIANREITINVEST_ERROR__ARITHMETIC_OVERFLOW
+  | typeof CANADIANREITINVEST_ERROR__DEPOSIT_CURRENCY_MISMATCH
+  | typeof CANADIANREITINVEST_ERROR__ESCROW_NOT_EMPTY
   | typeof CANADIANREITINVEST_ERROR__ESCROW_NOT_INITIALIZED
+  | typeof CANADIANREITINVEST_ERROR__FUNDRAISER_CAP_EXCEEDED
+  | typeof CANADIANREITINVEST_ERROR__FUNDRAISER_DEADLINE_PASSED
+  | typeof CANADIANREITINVEST_ERROR__FUNDRAISER_TERMS_LOCKED
+  | typeof CANADIANREITINVEST_ERROR__FX_RATE_CONFIDENCE_TOO_WIDE
+  | typeof CANADIANREITINVEST_ERROR__HOLD_PERIOD_ACTIVE
   | typeof CANADIANREITINVEST_ERROR__INSUFFICIENT_FUNDS
   | typeof CANADIANREITINVEST_ERROR__INVALID_AMOUNT
   | typeof CANADIANREITINVEST_ERROR__INVALID_AUTHORITY
   | typeof CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_MISMATCH
+  | typeof CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_STATUS
+  | typeof CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_TRANSITION
+  | typeof CANADIANREITINVEST_ERROR__INVALID_FX_RATE
   | typeof CANADIANREITINVEST_ERROR__INVALID_INVESTMENT_STATUS
   | typeof CANADIANREITINVEST_ERROR__INVALID_MINT
   | typeof CANADIANREITINVEST_ERROR__INVALID_REIT_ID_HASH
-  | typeof CANADIANREITINVEST_ERROR__INVESTMENT_COUNTER_OVERFLOW;
+  | typeof CANADIANREITINVEST_ERROR__INVALID_WIRE_BATCH
+  | typeof CANADIANREITINVEST_ERROR__INVALID_WIRE_DETAILS
+  | typeof CANADIANREITINVEST_ERROR__INVESTMENTS_OUTSTANDING
+  | typeof CANADIANREITINVEST_ERROR__INVESTMENT_COUNTER_OVERFLOW
+  | typeof CANADIANREITINVEST_ERROR__INVESTMENT_LIMIT_EXCEEDED
+  | typeof CANADIANREITINVEST_ERROR__INVESTOR_HAS_OPEN_POSITIONS
+  | typeof CANADIANREITINVEST_ERROR__INVESTOR_NOT_ELIGIBLE
+  | typeof CANADIANREITINVEST_ERROR__JURISDICTION_BLOCKED
+  | typeof CANADIANREITINVEST_ERROR__JURISDICTION_RULE_NOT_FOUND
+  | typeof CANADIANREITINVEST_ERROR__KYC_EXPIRED
+  | typeof CANADIANREITINVEST_ERROR__KYC_NOT_VERIFIED
+  | typeof CANADIANREITINVEST_ERROR__LOCK_VAULT_REQUIRED
+  | typeof CANADIANREITINVEST_ERROR__RECOVERY_NOT_SUPPORTED
+  | typeof CANADIANREITINVEST_ERROR__REIT_MINT_ALREADY_CREATED
+  | typeof CANADIANREITINVEST_ERROR__STALE_FX_RATE
+  | typeof CANADIANREITINVEST_ERROR__TOO_MANY_ACCEPTED_MINTS
+  | typeof CANADIANREITINVEST_ERROR__TOO_MANY_JURISDICTION_RULES
+  | typeof CANADIANREITINVEST_ERROR__UNSUPPORTED_CURRENCY;
 
 let canadianreitinvestErrorMessages:
   | Record<CanadianreitinvestError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   canadianreitinvestErrorMessages = {
+    [CANADIANREITINVEST_ERROR__ACCOUNT_ALREADY_MIGRATED]: `Account already uses the current layout`,
     [CANADIANREITINVEST_ERROR__ARITHMETIC_OVERFLOW]: `Arithmetic overflow`,
+    [CANADIANREITINVEST_ERROR__DEPOSIT_CURRENCY_MISMATCH]: `Stablecoin is not pegged to the fundraiser's deposit currency`,
+    [CANADIANREITINVEST_ERROR__ESCROW_NOT_EMPTY]: `Escrow vault still holds funds`,
     [CANADIANREITINVEST_ERROR__ESCROW_NOT_INITIALIZED]: `Escrow not initialized`,
+    [CANADIANREITINVEST_ERROR__FUNDRAISER_CAP_EXCEEDED]: `Investment would exceed the fundraiser cap`,
+    [CANADIANREITINVEST_ERROR__FUNDRAISER_DEADLINE_PASSED]: `Fundraiser deadline has passed`,
+    [CANADIANREITINVEST_ERROR__FUNDRAISER_TERMS_LOCKED]: `Fundraiser terms can no longer be changed`,
+    [CANADIANREITINVEST_ERROR__FX_RATE_CONFIDENCE_TOO_WIDE]: `FX rate confidence interval is too wide`,
+    [CANADIANREITINVEST_ERROR__HOLD_PERIOD_ACTIVE]: `Shares are still within the resale hold period`,
     [CANADIANREITINVEST_ERROR__INSUFFICIENT_FUNDS]: `Insufficient funds`,
     [CANADIANREITINVEST_ERROR__INVALID_AMOUNT]: `Invalid amount`,
     [CANADIANREITINVEST_ERROR__INVALID_AUTHORITY]: `Invalid authority`,
     [CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_MISMATCH]: `Invalid fundraiser mismatch`,
+    [CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_STATUS]: `Instruction not allowed in the fundraiser's current status`,
+    [CANADIANREITINVEST_ERROR__INVALID_FUNDRAISER_TRANSITION]: `Fundraiser status transition not allowed`,
+    [CANADIANREITINVEST_ERROR__INVALID_FX_RATE]: `Invalid FX rate`,
     [CANADIANREITINVEST_ERROR__INVALID_INVESTMENT_STATUS]: `Invalid investment status`,
     [CANADIANREITINVEST_ERROR__INVALID_MINT]: `Invalid mint`,
     [CANADIANREITINVEST_ERROR__INVALID_REIT_ID_HASH]: `Invalid REIT ID hash`,
+    [CANADIANREITINVEST_ERROR__INVALID_WIRE_BATCH]: `Invalid wire batch`,
+    [CANADIANREITINVEST_ERROR__INVALID_WIRE_DETAILS]: `Invalid wire details`,
+    [CANADIANREITINVEST_ERROR__INVESTMENTS_OUTSTANDING]: `Fundraiser still has open investments`,
     [CANADIANREITINVEST_ERROR__INVESTMENT_COUNTER_OVERFLOW]: `Investment counter overflow`,
+    [CANADIANREITINVEST_ERROR__INVESTMENT_LIMIT_EXCEEDED]: `Investment exceeds the investor's rolling 12-month limit`,
+    [CANADIANREITINVEST_ERROR__INVESTOR_HAS_OPEN_POSITIONS]: `Investor still has open investments`,
+    [CANADIANREITINVEST_ERROR__INVESTOR_NOT_ELIGIBLE]: `Investor category is not eligible for this offering`,
+    [CANADIANREITINVEST_ERROR__JURISDICTION_BLOCKED]: `Investor jurisdiction is not permitted for this offering`,
+    [CANADIANREITINVEST_ERROR__JURISDICTION_RULE_NOT_FOUND]: `Jurisdiction rule not found`,
+    [CANADIANREITINVEST_ERROR__KYC_EXPIRED]: `Investor KYC attestation has expired`,
+    [CANADIANREITINVEST_ERROR__KYC_NOT_VERIFIED]: `Investor KYC attestation is not verified`,
+    [CANADIANREITINVEST_ERROR__LOCK_VAULT_REQUIRED]: `Lock vault account is required for this REIT mint`,
+    [CANADIANREITINVEST_ERROR__RECOVERY_NOT_SUPPORTED]: `Position recovery is not supported for this REIT mint`,
+    [CANADIANREITINVEST_ERROR__REIT_MINT_ALREADY_CREATED]: `Fundraiser already has a REIT mint`,
+    [CANADIANREITINVEST_ERROR__STALE_FX_RATE]: `FX rate is stale`,
+    [CANADIANREITINVEST_ERROR__TOO_MANY_ACCEPTED_MINTS]: `Fundraiser accepts the maximum number of stablecoin mints`,
+    [CANADIANREITINVEST_ERROR__TOO_MANY_JURISDICTION_RULES]: `Too many jurisdiction rules`,
+    [CANADIANREITINVEST_ERROR__UNSUPPORTED_CURRENCY]: `Currency is not an accepted ISO 4217 code`,
   };
 }
 
@@ -6,14 +6,46 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addAcceptedMint';
+export * from './addJurisdictionRule';
+export * from './attestInvestor';
+export * from './closeFundraiser';
+export * from './closeInvestment';
 export * from './closeInvestor';
 export * from './createReitMint';
+export * from './createReitMint2022';
+export * from './freezeHolder';
+export * from './initializeCompliance';
 export * from './initializeFundraiser';
+export * from './initializeFxRate';
 export * from './initializeInvestor';
 export * from './invest';
 export * from './issueDividend';
 export * from './issueShare';
+export * from './migrateComplianceConfig';
+export * from './migrateFundraiser';
+export * from './migrateFxRate';
+export * from './migrateHolderLockup';
+export * from './migrateInvestment';
+export * from './migrateInvestor';
+export * from './migrateInvestorFundraiser';
+export * from './migrateKycAttestation';
+export * from './migrateWireBatchRecord';
+export * from './publishFxRate';
+export * from './recordShareSale';
+export * from './recoverPosition';
 export * from './refund';
 export * from './release';
+export * from './removeJurisdictionRule';
+export * from './setComplianceAuthority';
+export * from './setFundraiserStatus';
+export * from './setFxRatePublisher';
+export * from './setHoldPeriod';
+export * from './setJurisdictionRules';
+export * from './setOfferingExemption';
+export * from './thawHolder';
+export * from './unlockShares';
+export * from './updateFundraiserTerms';
 export * from './updateReitMint';
 export * from './wire';
+export * from './wireBatch';
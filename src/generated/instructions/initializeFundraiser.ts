@@ -106,11 +106,13 @@ export type InitializeFundraiserInstructionData = {
   discriminator: ReadonlyUint8Array;
   reitId: string;
   reitIdHash: ReadonlyUint8Array;
+  reitAcceptedCurrency: ReadonlyUint8Array;
 };
 
 export type InitializeFundraiserInstructionDataArgs = {
   reitId: string;
   reitIdHash: ReadonlyUint8Array;
+  reitAcceptedCurrency: ReadonlyUint8Array;
 };
 
 export function getInitializeFundraiserInstructionDataEncoder(): Encoder<InitializeFundraiserInstructionDataArgs> {
@@ -119,6 +121,7 @@ export function getInitializeFundraiserInstructionDataEncoder(): Encoder<Initial
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['reitId', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['reitIdHash', fixEncoderSize(getBytesEncoder(), 16)],
+      ['reitAcceptedCurrency', fixEncoderSize(getBytesEncoder(), 3)],
     ]),
     (value) => ({
       ...value,
@@ -132,6 +135,7 @@ export function getInitializeFundraiserInstructionDataDecoder(): Decoder<Initial
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['reitId', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['reitIdHash', fixDecoderSize(getBytesDecoder(), 16)],
+    ['reitAcceptedCurrency', fixDecoderSize(getBytesDecoder(), 3)],
   ]);
 }
 
@@ -163,6 +167,7 @@ export type InitializeFundraiserAsyncInput<
   rent?: Address<TAccountRent>;
   reitId: InitializeFundraiserInstructionDataArgs['reitId'];
   reitIdHash: InitializeFundraiserInstructionDataArgs['reitIdHash'];
+  reitAcceptedCurrency: InitializeFundraiserInstructionDataArgs['reitAcceptedCurrency'];
 };
 
 export async function getInitializeFundraiserInstructionAsync<
@@ -304,6 +309,7 @@ export type InitializeFundraiserInput<
   rent?: Address<TAccountRent>;
   reitId: InitializeFundraiserInstructionDataArgs['reitId'];
   reitIdHash: InitializeFundraiserInstructionDataArgs['reitIdHash'];
+  reitAcceptedCurrency: InitializeFundraiserInstructionDataArgs['reitAcceptedCurrency'];
 };
 
 export function getInitializeFundraiserInstruction<
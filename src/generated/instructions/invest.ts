@@ -54,6 +54,7 @@ export type InvestInstruction<
   TProgram extends string = typeof CANADIANREITINVEST_PROGRAM_ADDRESS,
   TAccountInvestorSigner extends string | AccountMeta<string> = string,
   TAccountInvestor extends string | AccountMeta<string> = string,
+  TAccountKycAttestation extends string | AccountMeta<string> = string,
   TAccountInvestorFundraiser extends string | AccountMeta<string> = string,
   TAccountFundraiser extends string | AccountMeta<string> = string,
   TAccountInvestment extends string | AccountMeta<string> = string,
@@ -84,6 +85,9 @@ export type InvestInstruction<
       TAccountInvestor extends string
         ? WritableAccount<TAccountInvestor>
         : TAccountInvestor,
+      TAccountKycAttestation extends string
+        ? ReadonlyAccount<TAccountKycAttestation>
+        : TAccountKycAttestation,
       TAccountInvestorFundraiser extends string
         ? WritableAccount<TAccountInvestorFundraiser>
         : TAccountInvestorFundraiser,
@@ -165,6 +169,7 @@ export function getInvestInstructionDataCodec(): FixedSizeCodec<
 export type InvestAsyncInput<
   TAccountInvestorSigner extends string = string,
   TAccountInvestor extends string = string,
+  TAccountKycAttestation extends string = string,
   TAccountInvestorFundraiser extends string = string,
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
@@ -177,13 +182,15 @@ export type InvestAsyncInput<
   TAccountRent extends string = string,
 > = {
   investorSigner: TransactionSigner<TAccountInvestorSigner>;
-  /** Investor PDA: init if needed so users don't have to pre-create it */
+  /** Investor PDA, created beforehand with initialize_investor */
   investor?: Address<TAccountInvestor>;
+  /** KYC attestation written by the compliance authority for this investor */
+  kycAttestation?: Address<TAccountKycAttestation>;
   /** NEW: InvestorFundraiser PDA for per-fundraiser tracking */
   investorFundraiser?: Address<TAccountInvestorFundraiser>;
   fundraiser?: Address<TAccountFundraiser>;
   investment?: Address<TAccountInvestment>;
-  /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
+  /** Stablecoin being deposited (USDC or any other mint in fundraiser.accepted_mints) */
   usdcMint: Address<TAccountUsdcMint>;
   /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
   investorUsdcAta?: Address<TAccountInvestorUsdcAta>;
@@ -200,6 +207,7 @@ export type InvestAsyncInput<
 export async function getInvestInstructionAsync<
   TAccountInvestorSigner extends string,
   TAccountInvestor extends string,
+  TAccountKycAttestation extends string,
   TAccountInvestorFundraiser extends string,
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
@@ -215,6 +223,7 @@ export async function getInvestInstructionAsync<
   input: InvestAsyncInput<
     TAccountInvestorSigner,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorFundraiser,
     TAccountFundraiser,
     TAccountInvestment,
@@ -232,6 +241,7 @@ export async function getInvestInstructionAsync<
     TProgramAddress,
     TAccountInvestorSigner,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorFundraiser,
     TAccountFundraiser,
     TAccountInvestment,
@@ -252,6 +262,7 @@ export async function getInvestInstructionAsync<
   const originalAccounts = {
     investorSigner: { value: input.investorSigner ?? null, isWritable: true },
     investor: { value: input.investor ?? null, isWritable: true },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
     investorFundraiser: {
       value: input.investorFundraiser ?? null,
       isWritable: true,
@@ -291,6 +302,20 @@ export async function getInvestInstructionAsync<
       ],
     });
   }
+  if (!accounts.kycAttestation.value) {
+    accounts.kycAttestation.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            107, 121, 99, 95, 97, 116, 116, 101, 115, 116, 97, 116, 105, 111,
+            110,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.investor.value)),
+      ],
+    });
+  }
   if (!accounts.fundraiser.value) {
     accounts.fundraiser.value = await getProgramDerivedAddress({
       programAddress,
@@ -377,6 +402,7 @@ export async function getInvestInstructionAsync<
     accounts: [
       getAccountMeta(accounts.investorSigner),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.kycAttestation),
       getAccountMeta(accounts.investorFundraiser),
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
@@ -396,6 +422,7 @@ export async function getInvestInstructionAsync<
     TProgramAddress,
     TAccountInvestorSigner,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorFundraiser,
     TAccountFundraiser,
     TAccountInvestment,
@@ -412,6 +439,7 @@ export async function getInvestInstructionAsync<
 export type InvestInput<
   TAccountInvestorSigner extends string = string,
   TAccountInvestor extends string = string,
+  TAccountKycAttestation extends string = string,
   TAccountInvestorFundraiser extends string = string,
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
@@ -424,13 +452,15 @@ export type InvestInput<
   TAccountRent extends string = string,
 > = {
   investorSigner: TransactionSigner<TAccountInvestorSigner>;
-  /** Investor PDA: init if needed so users don't have to pre-create it */
+  /** Investor PDA, created beforehand with initialize_investor */
   investor: Address<TAccountInvestor>;
+  /** KYC attestation written by the compliance authority for this investor */
+  kycAttestation: Address<TAccountKycAttestation>;
   /** NEW: InvestorFundraiser PDA for per-fundraiser tracking */
   investorFundraiser: Address<TAccountInvestorFundraiser>;
   fundraiser: Address<TAccountFundraiser>;
   investment: Address<TAccountInvestment>;
-  /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
+  /** Stablecoin being deposited (USDC or any other mint in fundraiser.accepted_mints) */
   usdcMint: Address<TAccountUsdcMint>;
   /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
   investorUsdcAta: Address<TAccountInvestorUsdcAta>;
@@ -447,6 +477,7 @@ export type InvestInput<
 export function getInvestInstruction<
   TAccountInvestorSigner extends string,
   TAccountInvestor extends string,
+  TAccountKycAttestation extends string,
   TAccountInvestorFundraiser extends string,
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
@@ -462,6 +493,7 @@ export function getInvestInstruction<
   input: InvestInput<
     TAccountInvestorSigner,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorFundraiser,
     TAccountFundraiser,
     TAccountInvestment,
@@ -478,6 +510,7 @@ export function getInvestInstruction<
   TProgramAddress,
   TAccountInvestorSigner,
   TAccountInvestor,
+  TAccountKycAttestation,
   TAccountInvestorFundraiser,
   TAccountFundraiser,
   TAccountInvestment,
@@ -497,6 +530,7 @@ export function getInvestInstruction<
   const originalAccounts = {
     investorSigner: { value: input.investorSigner ?? null, isWritable: true },
     investor: { value: input.investor ?? null, isWritable: true },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
     investorFundraiser: {
       value: input.investorFundraiser ?? null,
       isWritable: true,
@@ -545,6 +579,7 @@ export function getInvestInstruction<
     accounts: [
       getAccountMeta(accounts.investorSigner),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.kycAttestation),
       getAccountMeta(accounts.investorFundraiser),
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
@@ -564,6 +599,7 @@ export function getInvestInstruction<
     TProgramAddress,
     TAccountInvestorSigner,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorFundraiser,
     TAccountFundraiser,
     TAccountInvestment,
@@ -584,21 +620,23 @@ export type ParsedInvestInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     investorSigner: TAccountMetas[0];
-    /** Investor PDA: init if needed so users don't have to pre-create it */
+    /** Investor PDA, created beforehand with initialize_investor */
     investor: TAccountMetas[1];
+    /** KYC attestation written by the compliance authority for this investor */
+    kycAttestation: TAccountMetas[2];
     /** NEW: InvestorFundraiser PDA for per-fundraiser tracking */
-    investorFundraiser: TAccountMetas[2];
-    fundraiser: TAccountMetas[3];
-    investment: TAccountMetas[4];
-    /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
-    usdcMint: TAccountMetas[5];
+    investorFundraiser: TAccountMetas[3];
+    fundraiser: TAccountMetas[4];
+    investment: TAccountMetas[5];
+    /** Stablecoin being deposited (USDC or any other mint in fundraiser.accepted_mints) */
+    usdcMint: TAccountMetas[6];
     /** Investor's USDC ATA. Create it if missing so users don't have to pre-create their ATA. */
-    investorUsdcAta: TAccountMetas[6];
-    escrowVault: TAccountMetas[7];
-    tokenProgram: TAccountMetas[8];
-    associatedTokenProgram: TAccountMetas[9];
-    systemProgram: TAccountMetas[10];
-    rent: TAccountMetas[11];
+    investorUsdcAta: TAccountMetas[7];
+    escrowVault: TAccountMetas[8];
+    tokenProgram: TAccountMetas[9];
+    associatedTokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
+    rent: TAccountMetas[12];
   };
   data: InvestInstructionData;
 };
@@ -611,7 +649,7 @@ export function parseInvestInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInvestInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -626,6 +664,7 @@ export function parseInvestInstruction<
     accounts: {
       investorSigner: getNextAccount(),
       investor: getNextAccount(),
+      kycAttestation: getNextAccount(),
       investorFundraiser: getNextAccount(),
       fundraiser: getNextAccount(),
       investment: getNextAccount(),
@@ -10,8 +10,10 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -33,7 +35,11 @@ import {
   type WritableSignerAccount,
 } from 'gill';
 import { CANADIANREITINVEST_PROGRAM_ADDRESS } from '../programs';
-import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+import {
+  expectAddress,
+  getAccountMetaFactory,
+  type ResolvedAccount,
+} from '../shared';
 
 export const ISSUE_DIVIDEND_DISCRIMINATOR = new Uint8Array([
   143, 141, 2, 140, 130, 248, 167, 67,
@@ -50,6 +56,8 @@ export type IssueDividendInstruction<
   TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountInvestment extends string | AccountMeta<string> = string,
   TAccountInvestor extends string | AccountMeta<string> = string,
+  TAccountInvestorProfile extends string | AccountMeta<string> = string,
+  TAccountKycAttestation extends string | AccountMeta<string> = string,
   TAccountFundraiser extends string | AccountMeta<string> = string,
   TAccountAdminUsdcAta extends string | AccountMeta<string> = string,
   TAccountInvestorUsdcAta extends string | AccountMeta<string> = string,
@@ -72,6 +80,12 @@ export type IssueDividendInstruction<
       TAccountInvestor extends string
         ? ReadonlyAccount<TAccountInvestor>
         : TAccountInvestor,
+      TAccountInvestorProfile extends string
+        ? ReadonlyAccount<TAccountInvestorProfile>
+        : TAccountInvestorProfile,
+      TAccountKycAttestation extends string
+        ? ReadonlyAccount<TAccountKycAttestation>
+        : TAccountKycAttestation,
       TAccountFundraiser extends string
         ? ReadonlyAccount<TAccountFundraiser>
         : TAccountFundraiser,
@@ -125,10 +139,185 @@ export function getIssueDividendInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
+export type IssueDividendAsyncInput<
+  TAccountAdmin extends string = string,
+  TAccountInvestment extends string = string,
+  TAccountInvestor extends string = string,
+  TAccountInvestorProfile extends string = string,
+  TAccountKycAttestation extends string = string,
+  TAccountFundraiser extends string = string,
+  TAccountAdminUsdcAta extends string = string,
+  TAccountInvestorUsdcAta extends string = string,
+  TAccountUsdcMint extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  /** Admin who signs and authorizes the dividend payment */
+  admin: TransactionSigner<TAccountAdmin>;
+  /**
+   * Investment PDA to validate investor eligibility
+   * Must have ShareIssued status
+   */
+  investment: Address<TAccountInvestment>;
+  /** Investor receiving the dividend (derived from investment) */
+  investor: Address<TAccountInvestor>;
+  /** Investor PDA address for the wallet, which keys its KYC attestation */
+  investorProfile?: Address<TAccountInvestorProfile>;
+  /** KYC attestation for the investor; dividends are only paid to verified investors */
+  kycAttestation?: Address<TAccountKycAttestation>;
+  /** Fundraiser PDA for admin authorization check */
+  fundraiser: Address<TAccountFundraiser>;
+  /** Admin's USDC token account (source of dividend) */
+  adminUsdcAta: Address<TAccountAdminUsdcAta>;
+  /** Investor's USDC token account (destination for dividend) */
+  investorUsdcAta: Address<TAccountInvestorUsdcAta>;
+  /** USDC mint for validation */
+  usdcMint: Address<TAccountUsdcMint>;
+  /** Token program for transfer */
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: IssueDividendInstructionDataArgs['amount'];
+};
+
+export async function getIssueDividendInstructionAsync<
+  TAccountAdmin extends string,
+  TAccountInvestment extends string,
+  TAccountInvestor extends string,
+  TAccountInvestorProfile extends string,
+  TAccountKycAttestation extends string,
+  TAccountFundraiser extends string,
+  TAccountAdminUsdcAta extends string,
+  TAccountInvestorUsdcAta extends string,
+  TAccountUsdcMint extends string,
+  TAccountTokenProgram extends string,
+  TProgramAddress extends Address = typeof CANADIANREITINVEST_PROGRAM_ADDRESS,
+>(
+  input: IssueDividendAsyncInput<
+    TAccountAdmin,
+    TAccountInvestment,
+    TAccountInvestor,
+    TAccountInvestorProfile,
+    TAccountKycAttestation,
+    TAccountFundraiser,
+    TAccountAdminUsdcAta,
+    TAccountInvestorUsdcAta,
+    TAccountUsdcMint,
+    TAccountTokenProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): Promise<
+  IssueDividendInstruction<
+    TProgramAddress,
+    TAccountAdmin,
+    TAccountInvestment,
+    TAccountInvestor,
+    TAccountInvestorProfile,
+    TAccountKycAttestation,
+    TAccountFundraiser,
+    TAccountAdminUsdcAta,
+    TAccountInvestorUsdcAta,
+    TAccountUsdcMint,
+    TAccountTokenProgram
+  >
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? CANADIANREITINVEST_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    admin: { value: input.admin ?? null, isWritable: true },
+    investment: { value: input.investment ?? null, isWritable: false },
+    investor: { value: input.investor ?? null, isWritable: false },
+    investorProfile: {
+      value: input.investorProfile ?? null,
+      isWritable: false,
+    },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
+    fundraiser: { value: input.fundraiser ?? null, isWritable: false },
+    adminUsdcAta: { value: input.adminUsdcAta ?? null, isWritable: true },
+    investorUsdcAta: { value: input.investorUsdcAta ?? null, isWritable: true },
+    usdcMint: { value: input.usdcMint ?? null, isWritable: false },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.investorProfile.value) {
+    accounts.investorProfile.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([105, 110, 118, 101, 115, 116, 111, 114])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.investor.value)),
+      ],
+    });
+  }
+  if (!accounts.kycAttestation.value) {
+    accounts.kycAttestation.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            107, 121, 99, 95, 97, 116, 116, 101, 115, 116, 97, 116, 105, 111,
+            110,
+          ])
+        ),
+        getAddressEncoder().encode(
+          expectAddress(accounts.investorProfile.value)
+        ),
+      ],
+    });
+  }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  return Object.freeze({
+    accounts: [
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.investment),
+      getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.investorProfile),
+      getAccountMeta(accounts.kycAttestation),
+      getAccountMeta(accounts.fundraiser),
+      getAccountMeta(accounts.adminUsdcAta),
+      getAccountMeta(accounts.investorUsdcAta),
+      getAccountMeta(accounts.usdcMint),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    data: getIssueDividendInstructionDataEncoder().encode(
+      args as IssueDividendInstructionDataArgs
+    ),
+    programAddress,
+  } as IssueDividendInstruction<
+    TProgramAddress,
+    TAccountAdmin,
+    TAccountInvestment,
+    TAccountInvestor,
+    TAccountInvestorProfile,
+    TAccountKycAttestation,
+    TAccountFundraiser,
+    TAccountAdminUsdcAta,
+    TAccountInvestorUsdcAta,
+    TAccountUsdcMint,
+    TAccountTokenProgram
+  >);
+}
+
 export type IssueDividendInput<
   TAccountAdmin extends string = string,
   TAccountInvestment extends string = string,
   TAccountInvestor extends string = string,
+  TAccountInvestorProfile extends string = string,
+  TAccountKycAttestation extends string = string,
   TAccountFundraiser extends string = string,
   TAccountAdminUsdcAta extends string = string,
   TAccountInvestorUsdcAta extends string = string,
@@ -144,6 +333,10 @@ export type IssueDividendInput<
   investment: Address<TAccountInvestment>;
   /** Investor receiving the dividend (derived from investment) */
   investor: Address<TAccountInvestor>;
+  /** Investor PDA address for the wallet, which keys its KYC attestation */
+  investorProfile: Address<TAccountInvestorProfile>;
+  /** KYC attestation for the investor; dividends are only paid to verified investors */
+  kycAttestation: Address<TAccountKycAttestation>;
   /** Fundraiser PDA for admin authorization check */
   fundraiser: Address<TAccountFundraiser>;
   /** Admin's USDC token account (source of dividend) */
@@ -161,6 +354,8 @@ export function getIssueDividendInstruction<
   TAccountAdmin extends string,
   TAccountInvestment extends string,
   TAccountInvestor extends string,
+  TAccountInvestorProfile extends string,
+  TAccountKycAttestation extends string,
   TAccountFundraiser extends string,
   TAccountAdminUsdcAta extends string,
   TAccountInvestorUsdcAta extends string,
@@ -172,6 +367,8 @@ export function getIssueDividendInstruction<
     TAccountAdmin,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorProfile,
+    TAccountKycAttestation,
     TAccountFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
@@ -184,6 +381,8 @@ export function getIssueDividendInstruction<
   TAccountAdmin,
   TAccountInvestment,
   TAccountInvestor,
+  TAccountInvestorProfile,
+  TAccountKycAttestation,
   TAccountFundraiser,
   TAccountAdminUsdcAta,
   TAccountInvestorUsdcAta,
@@ -199,6 +398,11 @@ export function getIssueDividendInstruction<
     admin: { value: input.admin ?? null, isWritable: true },
     investment: { value: input.investment ?? null, isWritable: false },
     investor: { value: input.investor ?? null, isWritable: false },
+    investorProfile: {
+      value: input.investorProfile ?? null,
+      isWritable: false,
+    },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
     fundraiser: { value: input.fundraiser ?? null, isWritable: false },
     adminUsdcAta: { value: input.adminUsdcAta ?? null, isWritable: true },
     investorUsdcAta: { value: input.investorUsdcAta ?? null, isWritable: true },
@@ -225,6 +429,8 @@ export function getIssueDividendInstruction<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.investment),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.investorProfile),
+      getAccountMeta(accounts.kycAttestation),
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.adminUsdcAta),
       getAccountMeta(accounts.investorUsdcAta),
@@ -240,6 +446,8 @@ export function getIssueDividendInstruction<
     TAccountAdmin,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorProfile,
+    TAccountKycAttestation,
     TAccountFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
@@ -263,16 +471,20 @@ export type ParsedIssueDividendInstruction<
     investment: TAccountMetas[1];
     /** Investor receiving the dividend (derived from investment) */
     investor: TAccountMetas[2];
+    /** Investor PDA address for the wallet, which keys its KYC attestation */
+    investorProfile: TAccountMetas[3];
+    /** KYC attestation for the investor; dividends are only paid to verified investors */
+    kycAttestation: TAccountMetas[4];
     /** Fundraiser PDA for admin authorization check */
-    fundraiser: TAccountMetas[3];
+    fundraiser: TAccountMetas[5];
     /** Admin's USDC token account (source of dividend) */
-    adminUsdcAta: TAccountMetas[4];
+    adminUsdcAta: TAccountMetas[6];
     /** Investor's USDC token account (destination for dividend) */
-    investorUsdcAta: TAccountMetas[5];
+    investorUsdcAta: TAccountMetas[7];
     /** USDC mint for validation */
-    usdcMint: TAccountMetas[6];
+    usdcMint: TAccountMetas[8];
     /** Token program for transfer */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[9];
   };
   data: IssueDividendInstructionData;
 };
@@ -285,7 +497,7 @@ export function parseIssueDividendInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedIssueDividendInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -301,6 +513,8 @@ export function parseIssueDividendInstruction<
       admin: getNextAccount(),
       investment: getNextAccount(),
       investor: getNextAccount(),
+      investorProfile: getNextAccount(),
+      kycAttestation: getNextAccount(),
       fundraiser: getNextAccount(),
       adminUsdcAta: getNextAccount(),
       investorUsdcAta: getNextAccount(),
@@ -57,9 +57,13 @@ export type IssueShareInstruction<
   TAccountFundraiser extends string | AccountMeta<string> = string,
   TAccountInvestment extends string | AccountMeta<string> = string,
   TAccountInvestor extends string | AccountMeta<string> = string,
+  TAccountKycAttestation extends string | AccountMeta<string> = string,
   TAccountInvestorWallet extends string | AccountMeta<string> = string,
   TAccountReitMint extends string | AccountMeta<string> = string,
   TAccountInvestorAta extends string | AccountMeta<string> = string,
+  TAccountLockVault extends string | AccountMeta<string> = string,
+  TAccountHolderLockup extends string | AccountMeta<string> = string,
+  TAccountFxRate extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -88,8 +92,11 @@ export type IssueShareInstruction<
         ? WritableAccount<TAccountInvestment>
         : TAccountInvestment,
       TAccountInvestor extends string
-        ? ReadonlyAccount<TAccountInvestor>
+        ? WritableAccount<TAccountInvestor>
         : TAccountInvestor,
+      TAccountKycAttestation extends string
+        ? ReadonlyAccount<TAccountKycAttestation>
+        : TAccountKycAttestation,
       TAccountInvestorWallet extends string
         ? ReadonlyAccount<TAccountInvestorWallet>
         : TAccountInvestorWallet,
@@ -99,6 +106,15 @@ export type IssueShareInstruction<
       TAccountInvestorAta extends string
         ? WritableAccount<TAccountInvestorAta>
         : TAccountInvestorAta,
+      TAccountLockVault extends string
+        ? WritableAccount<TAccountLockVault>
+        : TAccountLockVault,
+      TAccountHolderLockup extends string
+        ? WritableAccount<TAccountHolderLockup>
+        : TAccountHolderLockup,
+      TAccountFxRate extends string
+        ? ReadonlyAccount<TAccountFxRate>
+        : TAccountFxRate,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -164,9 +180,13 @@ export type IssueShareAsyncInput<
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
   TAccountInvestor extends string = string,
+  TAccountKycAttestation extends string = string,
   TAccountInvestorWallet extends string = string,
   TAccountReitMint extends string = string,
   TAccountInvestorAta extends string = string,
+  TAccountLockVault extends string = string,
+  TAccountHolderLockup extends string = string,
+  TAccountFxRate extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -176,10 +196,16 @@ export type IssueShareAsyncInput<
   fundraiser?: Address<TAccountFundraiser>;
   investment: Address<TAccountInvestment>;
   investor?: Address<TAccountInvestor>;
+  kycAttestation?: Address<TAccountKycAttestation>;
   /** Investor wallet - needed as the ATA authority (not a signer for this instruction) */
   investorWallet: Address<TAccountInvestorWallet>;
   reitMint: Address<TAccountReitMint>;
   investorAta?: Address<TAccountInvestorAta>;
+  /** Per-investment vault holding shares of classic SPL mints until the hold period ends */
+  lockVault?: Address<TAccountLockVault>;
+  holderLockup?: Address<TAccountHolderLockup>;
+  /** Rate of the deposit mint's currency in the fundraiser currency, converting share_price at the published rate instead of the wire's */
+  fxRate?: Address<TAccountFxRate>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -194,9 +220,13 @@ export async function getIssueShareInstructionAsync<
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
   TAccountInvestor extends string,
+  TAccountKycAttestation extends string,
   TAccountInvestorWallet extends string,
   TAccountReitMint extends string,
   TAccountInvestorAta extends string,
+  TAccountLockVault extends string,
+  TAccountHolderLockup extends string,
+  TAccountFxRate extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -208,9 +238,13 @@ export async function getIssueShareInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorWallet,
     TAccountReitMint,
     TAccountInvestorAta,
+    TAccountLockVault,
+    TAccountHolderLockup,
+    TAccountFxRate,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
@@ -224,9 +258,13 @@ export async function getIssueShareInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorWallet,
     TAccountReitMint,
     TAccountInvestorAta,
+    TAccountLockVault,
+    TAccountHolderLockup,
+    TAccountFxRate,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
@@ -242,10 +280,14 @@ export async function getIssueShareInstructionAsync<
     admin: { value: input.admin ?? null, isWritable: true },
     fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     investment: { value: input.investment ?? null, isWritable: true },
-    investor: { value: input.investor ?? null, isWritable: false },
+    investor: { value: input.investor ?? null, isWritable: true },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
     investorWallet: { value: input.investorWallet ?? null, isWritable: false },
     reitMint: { value: input.reitMint ?? null, isWritable: true },
     investorAta: { value: input.investorAta ?? null, isWritable: true },
+    lockVault: { value: input.lockVault ?? null, isWritable: true },
+    holderLockup: { value: input.holderLockup ?? null, isWritable: true },
+    fxRate: { value: input.fxRate ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -287,6 +329,20 @@ export async function getIssueShareInstructionAsync<
       ],
     });
   }
+  if (!accounts.kycAttestation.value) {
+    accounts.kycAttestation.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            107, 121, 99, 95, 97, 116, 116, 101, 115, 116, 97, 116, 105, 111,
+            110,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.investor.value)),
+      ],
+    });
+  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -304,6 +360,31 @@ export async function getIssueShareInstructionAsync<
       ],
     });
   }
+  if (!accounts.lockVault.value) {
+    accounts.lockVault.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([108, 111, 99, 107, 95, 118, 97, 117, 108, 116])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.investment.value)),
+      ],
+    });
+  }
+  if (!accounts.holderLockup.value) {
+    accounts.holderLockup.value = await getProgramDerivedAddress({
+      programAddress,
+      seeds: [
+        getBytesEncoder().encode(
+          new Uint8Array([
+            104, 111, 108, 100, 101, 114, 95, 108, 111, 99, 107, 117, 112,
+          ])
+        ),
+        getAddressEncoder().encode(expectAddress(accounts.reitMint.value)),
+        getAddressEncoder().encode(expectSome(args.investorPubkey)),
+      ],
+    });
+  }
   if (!accounts.associatedTokenProgram.value) {
     accounts.associatedTokenProgram.value =
       'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>;
@@ -324,9 +405,13 @@ export async function getIssueShareInstructionAsync<
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.kycAttestation),
       getAccountMeta(accounts.investorWallet),
       getAccountMeta(accounts.reitMint),
       getAccountMeta(accounts.investorAta),
+      getAccountMeta(accounts.lockVault),
+      getAccountMeta(accounts.holderLockup),
+      getAccountMeta(accounts.fxRate),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -342,9 +427,13 @@ export async function getIssueShareInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorWallet,
     TAccountReitMint,
     TAccountInvestorAta,
+    TAccountLockVault,
+    TAccountHolderLockup,
+    TAccountFxRate,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
@@ -357,9 +446,13 @@ export type IssueShareInput<
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
   TAccountInvestor extends string = string,
+  TAccountKycAttestation extends string = string,
   TAccountInvestorWallet extends string = string,
   TAccountReitMint extends string = string,
   TAccountInvestorAta extends string = string,
+  TAccountLockVault extends string = string,
+  TAccountHolderLockup extends string = string,
+  TAccountFxRate extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountAssociatedTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -369,10 +462,16 @@ export type IssueShareInput<
   fundraiser: Address<TAccountFundraiser>;
   investment: Address<TAccountInvestment>;
   investor: Address<TAccountInvestor>;
+  kycAttestation: Address<TAccountKycAttestation>;
   /** Investor wallet - needed as the ATA authority (not a signer for this instruction) */
   investorWallet: Address<TAccountInvestorWallet>;
   reitMint: Address<TAccountReitMint>;
   investorAta: Address<TAccountInvestorAta>;
+  /** Per-investment vault holding shares of classic SPL mints until the hold period ends */
+  lockVault?: Address<TAccountLockVault>;
+  holderLockup: Address<TAccountHolderLockup>;
+  /** Rate of the deposit mint's currency in the fundraiser currency, converting share_price at the published rate instead of the wire's */
+  fxRate?: Address<TAccountFxRate>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -387,9 +486,13 @@ export function getIssueShareInstruction<
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
   TAccountInvestor extends string,
+  TAccountKycAttestation extends string,
   TAccountInvestorWallet extends string,
   TAccountReitMint extends string,
   TAccountInvestorAta extends string,
+  TAccountLockVault extends string,
+  TAccountHolderLockup extends string,
+  TAccountFxRate extends string,
   TAccountTokenProgram extends string,
   TAccountAssociatedTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -401,9 +504,13 @@ export function getIssueShareInstruction<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorWallet,
     TAccountReitMint,
     TAccountInvestorAta,
+    TAccountLockVault,
+    TAccountHolderLockup,
+    TAccountFxRate,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
@@ -416,9 +523,13 @@ export function getIssueShareInstruction<
   TAccountFundraiser,
   TAccountInvestment,
   TAccountInvestor,
+  TAccountKycAttestation,
   TAccountInvestorWallet,
   TAccountReitMint,
   TAccountInvestorAta,
+  TAccountLockVault,
+  TAccountHolderLockup,
+  TAccountFxRate,
   TAccountTokenProgram,
   TAccountAssociatedTokenProgram,
   TAccountSystemProgram,
@@ -433,10 +544,14 @@ export function getIssueShareInstruction<
     admin: { value: input.admin ?? null, isWritable: true },
     fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     investment: { value: input.investment ?? null, isWritable: true },
-    investor: { value: input.investor ?? null, isWritable: false },
+    investor: { value: input.investor ?? null, isWritable: true },
+    kycAttestation: { value: input.kycAttestation ?? null, isWritable: false },
     investorWallet: { value: input.investorWallet ?? null, isWritable: false },
     reitMint: { value: input.reitMint ?? null, isWritable: true },
     investorAta: { value: input.investorAta ?? null, isWritable: true },
+    lockVault: { value: input.lockVault ?? null, isWritable: true },
+    holderLockup: { value: input.holderLockup ?? null, isWritable: true },
+    fxRate: { value: input.fxRate ?? null, isWritable: false },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     associatedTokenProgram: {
       value: input.associatedTokenProgram ?? null,
@@ -478,9 +593,13 @@ export function getIssueShareInstruction<
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.kycAttestation),
       getAccountMeta(accounts.investorWallet),
       getAccountMeta(accounts.reitMint),
       getAccountMeta(accounts.investorAta),
+      getAccountMeta(accounts.lockVault),
+      getAccountMeta(accounts.holderLockup),
+      getAccountMeta(accounts.fxRate),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.associatedTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -496,9 +615,13 @@ export function getIssueShareInstruction<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountKycAttestation,
     TAccountInvestorWallet,
     TAccountReitMint,
     TAccountInvestorAta,
+    TAccountLockVault,
+    TAccountHolderLockup,
+    TAccountFxRate,
     TAccountTokenProgram,
     TAccountAssociatedTokenProgram,
     TAccountSystemProgram,
@@ -516,14 +639,20 @@ export type ParsedIssueShareInstruction<
     fundraiser: TAccountMetas[1];
     investment: TAccountMetas[2];
     investor: TAccountMetas[3];
+    kycAttestation: TAccountMetas[4];
     /** Investor wallet - needed as the ATA authority (not a signer for this instruction) */
-    investorWallet: TAccountMetas[4];
-    reitMint: TAccountMetas[5];
-    investorAta: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    associatedTokenProgram: TAccountMetas[8];
-    systemProgram: TAccountMetas[9];
-    rent: TAccountMetas[10];
+    investorWallet: TAccountMetas[5];
+    reitMint: TAccountMetas[6];
+    investorAta: TAccountMetas[7];
+    /** Per-investment vault holding shares of classic SPL mints until the hold period ends */
+    lockVault?: TAccountMetas[8] | undefined;
+    holderLockup: TAccountMetas[9];
+    /** Rate of the deposit mint's currency in the fundraiser currency, converting share_price at the published rate instead of the wire's */
+    fxRate?: TAccountMetas[10] | undefined;
+    tokenProgram: TAccountMetas[11];
+    associatedTokenProgram: TAccountMetas[12];
+    systemProgram: TAccountMetas[13];
+    rent: TAccountMetas[14];
   };
   data: IssueShareInstructionData;
 };
@@ -536,7 +665,7 @@ export function parseIssueShareInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedIssueShareInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -546,6 +675,12 @@ export function parseIssueShareInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CANADIANREITINVEST_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -553,9 +688,13 @@ export function parseIssueShareInstruction<
       fundraiser: getNextAccount(),
       investment: getNextAccount(),
       investor: getNextAccount(),
+      kycAttestation: getNextAccount(),
       investorWallet: getNextAccount(),
       reitMint: getNextAccount(),
       investorAta: getNextAccount(),
+      lockVault: getNextOptionalAccount(),
+      holderLockup: getNextAccount(),
+      fxRate: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -52,9 +52,12 @@ export type RefundInstruction<
   TAccountFundraiser extends string | AccountMeta<string> = string,
   TAccountInvestment extends string | AccountMeta<string> = string,
   TAccountInvestor extends string | AccountMeta<string> = string,
+  TAccountInvestorPda extends string | AccountMeta<string> = string,
+  TAccountInvestorFundraiser extends string | AccountMeta<string> = string,
   TAccountAdminUsdcAta extends string | AccountMeta<string> = string,
   TAccountInvestorUsdcAta extends string | AccountMeta<string> = string,
   TAccountUsdcMint extends string | AccountMeta<string> = string,
+  TAccountEscrowVault extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
@@ -68,7 +71,7 @@ export type RefundInstruction<
             AccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
       TAccountFundraiser extends string
-        ? ReadonlyAccount<TAccountFundraiser>
+        ? WritableAccount<TAccountFundraiser>
         : TAccountFundraiser,
       TAccountInvestment extends string
         ? WritableAccount<TAccountInvestment>
@@ -76,6 +79,12 @@ export type RefundInstruction<
       TAccountInvestor extends string
         ? ReadonlyAccount<TAccountInvestor>
         : TAccountInvestor,
+      TAccountInvestorPda extends string
+        ? WritableAccount<TAccountInvestorPda>
+        : TAccountInvestorPda,
+      TAccountInvestorFundraiser extends string
+        ? WritableAccount<TAccountInvestorFundraiser>
+        : TAccountInvestorFundraiser,
       TAccountAdminUsdcAta extends string
         ? WritableAccount<TAccountAdminUsdcAta>
         : TAccountAdminUsdcAta,
@@ -85,6 +94,9 @@ export type RefundInstruction<
       TAccountUsdcMint extends string
         ? ReadonlyAccount<TAccountUsdcMint>
         : TAccountUsdcMint,
+      TAccountEscrowVault extends string
+        ? WritableAccount<TAccountEscrowVault>
+        : TAccountEscrowVault,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
@@ -131,9 +143,12 @@ export type RefundAsyncInput<
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
   TAccountInvestor extends string = string,
+  TAccountInvestorPda extends string = string,
+  TAccountInvestorFundraiser extends string = string,
   TAccountAdminUsdcAta extends string = string,
   TAccountInvestorUsdcAta extends string = string,
   TAccountUsdcMint extends string = string,
+  TAccountEscrowVault extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
@@ -141,12 +156,18 @@ export type RefundAsyncInput<
   investment: Address<TAccountInvestment>;
   /** Investor receiving the refund (derived from investment) */
   investor: Address<TAccountInvestor>;
-  /** Admin's USDC token account (source of refund) */
+  /** Investor PDA tracking the investor's open positions */
+  investorPda: Address<TAccountInvestorPda>;
+  /** Investor's record for this fundraiser, tracking what they invested in it */
+  investorFundraiser: Address<TAccountInvestorFundraiser>;
+  /** Admin's USDC token account, returning the released deposit */
   adminUsdcAta: Address<TAccountAdminUsdcAta>;
   /** Investor's USDC token account (destination for refund) */
   investorUsdcAta: Address<TAccountInvestorUsdcAta>;
-  /** USDC mint for validation */
+  /** Stablecoin the investment was deposited in, refunded in kind */
   usdcMint: Address<TAccountUsdcMint>;
+  /** Escrow vault of usdc_mint, paying the refund */
+  escrowVault: Address<TAccountEscrowVault>;
   /** Token program for transfer */
   tokenProgram?: Address<TAccountTokenProgram>;
   reitIdHash: RefundInstructionDataArgs['reitIdHash'];
@@ -157,9 +178,12 @@ export async function getRefundInstructionAsync<
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
   TAccountInvestor extends string,
+  TAccountInvestorPda extends string,
+  TAccountInvestorFundraiser extends string,
   TAccountAdminUsdcAta extends string,
   TAccountInvestorUsdcAta extends string,
   TAccountUsdcMint extends string,
+  TAccountEscrowVault extends string,
   TAccountTokenProgram extends string,
   TProgramAddress extends Address = typeof CANADIANREITINVEST_PROGRAM_ADDRESS,
 >(
@@ -168,9 +192,12 @@ export async function getRefundInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorPda,
+    TAccountInvestorFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
     TAccountUsdcMint,
+    TAccountEscrowVault,
     TAccountTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -181,9 +208,12 @@ export async function getRefundInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorPda,
+    TAccountInvestorFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
     TAccountUsdcMint,
+    TAccountEscrowVault,
     TAccountTokenProgram
   >
 > {
@@ -194,12 +224,18 @@ export async function getRefundInstructionAsync<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: true },
-    fundraiser: { value: input.fundraiser ?? null, isWritable: false },
+    fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     investment: { value: input.investment ?? null, isWritable: true },
     investor: { value: input.investor ?? null, isWritable: false },
+    investorPda: { value: input.investorPda ?? null, isWritable: true },
+    investorFundraiser: {
+      value: input.investorFundraiser ?? null,
+      isWritable: true,
+    },
     adminUsdcAta: { value: input.adminUsdcAta ?? null, isWritable: true },
     investorUsdcAta: { value: input.investorUsdcAta ?? null, isWritable: true },
     usdcMint: { value: input.usdcMint ?? null, isWritable: false },
+    escrowVault: { value: input.escrowVault ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -236,9 +272,12 @@ export async function getRefundInstructionAsync<
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.investorPda),
+      getAccountMeta(accounts.investorFundraiser),
       getAccountMeta(accounts.adminUsdcAta),
       getAccountMeta(accounts.investorUsdcAta),
       getAccountMeta(accounts.usdcMint),
+      getAccountMeta(accounts.escrowVault),
       getAccountMeta(accounts.tokenProgram),
     ],
     data: getRefundInstructionDataEncoder().encode(
@@ -251,9 +290,12 @@ export async function getRefundInstructionAsync<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorPda,
+    TAccountInvestorFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
     TAccountUsdcMint,
+    TAccountEscrowVault,
     TAccountTokenProgram
   >);
 }
@@ -263,9 +305,12 @@ export type RefundInput<
   TAccountFundraiser extends string = string,
   TAccountInvestment extends string = string,
   TAccountInvestor extends string = string,
+  TAccountInvestorPda extends string = string,
+  TAccountInvestorFundraiser extends string = string,
   TAccountAdminUsdcAta extends string = string,
   TAccountInvestorUsdcAta extends string = string,
   TAccountUsdcMint extends string = string,
+  TAccountEscrowVault extends string = string,
   TAccountTokenProgram extends string = string,
 > = {
   admin: TransactionSigner<TAccountAdmin>;
@@ -273,12 +318,18 @@ export type RefundInput<
   investment: Address<TAccountInvestment>;
   /** Investor receiving the refund (derived from investment) */
   investor: Address<TAccountInvestor>;
-  /** Admin's USDC token account (source of refund) */
+  /** Investor PDA tracking the investor's open positions */
+  investorPda: Address<TAccountInvestorPda>;
+  /** Investor's record for this fundraiser, tracking what they invested in it */
+  investorFundraiser: Address<TAccountInvestorFundraiser>;
+  /** Admin's USDC token account, returning the released deposit */
   adminUsdcAta: Address<TAccountAdminUsdcAta>;
   /** Investor's USDC token account (destination for refund) */
   investorUsdcAta: Address<TAccountInvestorUsdcAta>;
-  /** USDC mint for validation */
+  /** Stablecoin the investment was deposited in, refunded in kind */
   usdcMint: Address<TAccountUsdcMint>;
+  /** Escrow vault of usdc_mint, paying the refund */
+  escrowVault: Address<TAccountEscrowVault>;
   /** Token program for transfer */
   tokenProgram?: Address<TAccountTokenProgram>;
   reitIdHash: RefundInstructionDataArgs['reitIdHash'];
@@ -289,9 +340,12 @@ export function getRefundInstruction<
   TAccountFundraiser extends string,
   TAccountInvestment extends string,
   TAccountInvestor extends string,
+  TAccountInvestorPda extends string,
+  TAccountInvestorFundraiser extends string,
   TAccountAdminUsdcAta extends string,
   TAccountInvestorUsdcAta extends string,
   TAccountUsdcMint extends string,
+  TAccountEscrowVault extends string,
   TAccountTokenProgram extends string,
   TProgramAddress extends Address = typeof CANADIANREITINVEST_PROGRAM_ADDRESS,
 >(
@@ -300,9 +354,12 @@ export function getRefundInstruction<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorPda,
+    TAccountInvestorFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
     TAccountUsdcMint,
+    TAccountEscrowVault,
     TAccountTokenProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -312,9 +369,12 @@ export function getRefundInstruction<
   TAccountFundraiser,
   TAccountInvestment,
   TAccountInvestor,
+  TAccountInvestorPda,
+  TAccountInvestorFundraiser,
   TAccountAdminUsdcAta,
   TAccountInvestorUsdcAta,
   TAccountUsdcMint,
+  TAccountEscrowVault,
   TAccountTokenProgram
 > {
   // Program address.
@@ -324,12 +384,18 @@ export function getRefundInstruction<
   // Original accounts.
   const originalAccounts = {
     admin: { value: input.admin ?? null, isWritable: true },
-    fundraiser: { value: input.fundraiser ?? null, isWritable: false },
+    fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     investment: { value: input.investment ?? null, isWritable: true },
     investor: { value: input.investor ?? null, isWritable: false },
+    investorPda: { value: input.investorPda ?? null, isWritable: true },
+    investorFundraiser: {
+      value: input.investorFundraiser ?? null,
+      isWritable: true,
+    },
     adminUsdcAta: { value: input.adminUsdcAta ?? null, isWritable: true },
     investorUsdcAta: { value: input.investorUsdcAta ?? null, isWritable: true },
     usdcMint: { value: input.usdcMint ?? null, isWritable: false },
+    escrowVault: { value: input.escrowVault ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -353,9 +419,12 @@ export function getRefundInstruction<
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.investment),
       getAccountMeta(accounts.investor),
+      getAccountMeta(accounts.investorPda),
+      getAccountMeta(accounts.investorFundraiser),
       getAccountMeta(accounts.adminUsdcAta),
       getAccountMeta(accounts.investorUsdcAta),
       getAccountMeta(accounts.usdcMint),
+      getAccountMeta(accounts.escrowVault),
       getAccountMeta(accounts.tokenProgram),
     ],
     data: getRefundInstructionDataEncoder().encode(
@@ -368,9 +437,12 @@ export function getRefundInstruction<
     TAccountFundraiser,
     TAccountInvestment,
     TAccountInvestor,
+    TAccountInvestorPda,
+    TAccountInvestorFundraiser,
     TAccountAdminUsdcAta,
     TAccountInvestorUsdcAta,
     TAccountUsdcMint,
+    TAccountEscrowVault,
     TAccountTokenProgram
   >);
 }
@@ -386,14 +458,20 @@ export type ParsedRefundInstruction<
     investment: TAccountMetas[2];
     /** Investor receiving the refund (derived from investment) */
     investor: TAccountMetas[3];
-    /** Admin's USDC token account (source of refund) */
-    adminUsdcAta: TAccountMetas[4];
+    /** Investor PDA tracking the investor's open positions */
+    investorPda: TAccountMetas[4];
+    /** Investor's record for this fundraiser, tracking what they invested in it */
+    investorFundraiser: TAccountMetas[5];
+    /** Admin's USDC token account, returning the released deposit */
+    adminUsdcAta: TAccountMetas[6];
     /** Investor's USDC token account (destination for refund) */
-    investorUsdcAta: TAccountMetas[5];
-    /** USDC mint for validation */
-    usdcMint: TAccountMetas[6];
+    investorUsdcAta: TAccountMetas[7];
+    /** Stablecoin the investment was deposited in, refunded in kind */
+    usdcMint: TAccountMetas[8];
+    /** Escrow vault of usdc_mint, paying the refund */
+    escrowVault: TAccountMetas[9];
     /** Token program for transfer */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[10];
   };
   data: RefundInstructionData;
 };
@@ -406,7 +484,7 @@ export function parseRefundInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRefundInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -423,9 +501,12 @@ export function parseRefundInstruction<
       fundraiser: getNextAccount(),
       investment: getNextAccount(),
       investor: getNextAccount(),
+      investorPda: getNextAccount(),
+      investorFundraiser: getNextAccount(),
       adminUsdcAta: getNextAccount(),
       investorUsdcAta: getNextAccount(),
       usdcMint: getNextAccount(),
+      escrowVault: getNextAccount(),
       tokenProgram: getNextAccount(),
     },
     data: getRefundInstructionDataDecoder().decode(instruction.data),
@@ -159,8 +159,9 @@ export type ReleaseAsyncInput<
   investment: Address<TAccountInvestment>;
   /** Admin's USDC ATA */
   adminUsdcAta?: Address<TAccountAdminUsdcAta>;
-  /** USDC mint (must match fundraiser.usdc_mint) */
+  /** Stablecoin the investment was deposited in */
   usdcMint: Address<TAccountUsdcMint>;
+  /** Escrow vault holding deposits of usdc_mint */
   escrowVault: Address<TAccountEscrowVault>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
@@ -336,8 +337,9 @@ export type ReleaseInput<
   investment: Address<TAccountInvestment>;
   /** Admin's USDC ATA */
   adminUsdcAta: Address<TAccountAdminUsdcAta>;
-  /** USDC mint (must match fundraiser.usdc_mint) */
+  /** Stablecoin the investment was deposited in */
   usdcMint: Address<TAccountUsdcMint>;
+  /** Escrow vault holding deposits of usdc_mint */
   escrowVault: Address<TAccountEscrowVault>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
@@ -475,8 +477,9 @@ export type ParsedReleaseInstruction<
     investment: TAccountMetas[2];
     /** Admin's USDC ATA */
     adminUsdcAta: TAccountMetas[3];
-    /** USDC mint (must match fundraiser.usdc_mint) */
+    /** Stablecoin the investment was deposited in */
     usdcMint: TAccountMetas[4];
+    /** Escrow vault holding deposits of usdc_mint */
     escrowVault: TAccountMetas[5];
     tokenProgram: TAccountMetas[6];
     associatedTokenProgram: TAccountMetas[7];
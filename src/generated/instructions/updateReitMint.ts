@@ -59,12 +59,13 @@ export type UpdateReitMintInstruction<
   TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountFundraiser extends string | AccountMeta<string> = string,
   TAccountReitMint extends string | AccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
-  TAccountInstructionsSysvar extends
-    | string
-    | AccountMeta<string> = 'Sysvar1nstructions1111111111111111111111111',
+  TAccountInstructionsSysvar extends string | AccountMeta<string> = string,
   TAccountMetadata extends string | AccountMeta<string> = string,
   TAccountTokenMetadataProgram extends
     | string
@@ -84,6 +85,9 @@ export type UpdateReitMintInstruction<
       TAccountReitMint extends string
         ? WritableAccount<TAccountReitMint>
         : TAccountReitMint,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -152,6 +156,7 @@ export type UpdateReitMintAsyncInput<
   TAccountAdmin extends string = string,
   TAccountFundraiser extends string = string,
   TAccountReitMint extends string = string,
+  TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
   TAccountMetadata extends string = string,
@@ -160,9 +165,10 @@ export type UpdateReitMintAsyncInput<
   admin: TransactionSigner<TAccountAdmin>;
   fundraiser?: Address<TAccountFundraiser>;
   reitMint: Address<TAccountReitMint>;
+  tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
-  metadata: Address<TAccountMetadata>;
+  metadata?: Address<TAccountMetadata>;
   tokenMetadataProgram?: Address<TAccountTokenMetadataProgram>;
   reitIdHash: UpdateReitMintInstructionDataArgs['reitIdHash'];
   name: UpdateReitMintInstructionDataArgs['name'];
@@ -174,6 +180,7 @@ export async function getUpdateReitMintInstructionAsync<
   TAccountAdmin extends string,
   TAccountFundraiser extends string,
   TAccountReitMint extends string,
+  TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
   TAccountMetadata extends string,
@@ -184,6 +191,7 @@ export async function getUpdateReitMintInstructionAsync<
     TAccountAdmin,
     TAccountFundraiser,
     TAccountReitMint,
+    TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar,
     TAccountMetadata,
@@ -196,6 +204,7 @@ export async function getUpdateReitMintInstructionAsync<
     TAccountAdmin,
     TAccountFundraiser,
     TAccountReitMint,
+    TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar,
     TAccountMetadata,
@@ -211,6 +220,7 @@ export async function getUpdateReitMintInstructionAsync<
     admin: { value: input.admin ?? null, isWritable: true },
     fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     reitMint: { value: input.reitMint ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
       value: input.instructionsSysvar ?? null,
@@ -244,14 +254,14 @@ export async function getUpdateReitMintInstructionAsync<
       ],
     });
   }
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.instructionsSysvar.value) {
-    accounts.instructionsSysvar.value =
-      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
-  }
   if (!accounts.tokenMetadataProgram.value) {
     accounts.tokenMetadataProgram.value =
       'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as Address<'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'>;
@@ -263,6 +273,7 @@ export async function getUpdateReitMintInstructionAsync<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.reitMint),
+      getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.instructionsSysvar),
       getAccountMeta(accounts.metadata),
@@ -277,6 +288,7 @@ export async function getUpdateReitMintInstructionAsync<
     TAccountAdmin,
     TAccountFundraiser,
     TAccountReitMint,
+    TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar,
     TAccountMetadata,
@@ -288,6 +300,7 @@ export type UpdateReitMintInput<
   TAccountAdmin extends string = string,
   TAccountFundraiser extends string = string,
   TAccountReitMint extends string = string,
+  TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
   TAccountInstructionsSysvar extends string = string,
   TAccountMetadata extends string = string,
@@ -296,9 +309,10 @@ export type UpdateReitMintInput<
   admin: TransactionSigner<TAccountAdmin>;
   fundraiser: Address<TAccountFundraiser>;
   reitMint: Address<TAccountReitMint>;
+  tokenProgram?: Address<TAccountTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
-  metadata: Address<TAccountMetadata>;
+  metadata?: Address<TAccountMetadata>;
   tokenMetadataProgram?: Address<TAccountTokenMetadataProgram>;
   reitIdHash: UpdateReitMintInstructionDataArgs['reitIdHash'];
   name: UpdateReitMintInstructionDataArgs['name'];
@@ -310,6 +324,7 @@ export function getUpdateReitMintInstruction<
   TAccountAdmin extends string,
   TAccountFundraiser extends string,
   TAccountReitMint extends string,
+  TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
   TAccountInstructionsSysvar extends string,
   TAccountMetadata extends string,
@@ -320,6 +335,7 @@ export function getUpdateReitMintInstruction<
     TAccountAdmin,
     TAccountFundraiser,
     TAccountReitMint,
+    TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar,
     TAccountMetadata,
@@ -331,6 +347,7 @@ export function getUpdateReitMintInstruction<
   TAccountAdmin,
   TAccountFundraiser,
   TAccountReitMint,
+  TAccountTokenProgram,
   TAccountSystemProgram,
   TAccountInstructionsSysvar,
   TAccountMetadata,
@@ -345,6 +362,7 @@ export function getUpdateReitMintInstruction<
     admin: { value: input.admin ?? null, isWritable: true },
     fundraiser: { value: input.fundraiser ?? null, isWritable: true },
     reitMint: { value: input.reitMint ?? null, isWritable: true },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
     instructionsSysvar: {
       value: input.instructionsSysvar ?? null,
@@ -365,14 +383,14 @@ export function getUpdateReitMintInstruction<
   const args = { ...input };
 
   // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
       '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
   }
-  if (!accounts.instructionsSysvar.value) {
-    accounts.instructionsSysvar.value =
-      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
-  }
   if (!accounts.tokenMetadataProgram.value) {
     accounts.tokenMetadataProgram.value =
       'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as Address<'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'>;
@@ -384,6 +402,7 @@ export function getUpdateReitMintInstruction<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.fundraiser),
       getAccountMeta(accounts.reitMint),
+      getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
       getAccountMeta(accounts.instructionsSysvar),
       getAccountMeta(accounts.metadata),
@@ -398,6 +417,7 @@ export function getUpdateReitMintInstruction<
     TAccountAdmin,
     TAccountFundraiser,
     TAccountReitMint,
+    TAccountTokenProgram,
     TAccountSystemProgram,
     TAccountInstructionsSysvar,
     TAccountMetadata,
@@ -414,10 +434,11 @@ export type ParsedUpdateReitMintInstruction<
     admin: TAccountMetas[0];
     fundraiser: TAccountMetas[1];
     reitMint: TAccountMetas[2];
-    systemProgram: TAccountMetas[3];
-    instructionsSysvar: TAccountMetas[4];
-    metadata: TAccountMetas[5];
-    tokenMetadataProgram: TAccountMetas[6];
+    tokenProgram: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
+    instructionsSysvar?: TAccountMetas[5] | undefined;
+    metadata?: TAccountMetas[6] | undefined;
+    tokenMetadataProgram?: TAccountMetas[7] | undefined;
   };
   data: UpdateReitMintInstructionData;
 };
@@ -430,7 +451,7 @@ export function parseUpdateReitMintInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedUpdateReitMintInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -440,16 +461,23 @@ export function parseUpdateReitMintInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === CANADIANREITINVEST_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       admin: getNextAccount(),
       fundraiser: getNextAccount(),
       reitMint: getNextAccount(),
+      tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
-      instructionsSysvar: getNextAccount(),
-      metadata: getNextAccount(),
-      tokenMetadataProgram: getNextAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      metadata: getNextOptionalAccount(),
+      tokenMetadataProgram: getNextOptionalAccount(),
     },
     data: getUpdateReitMintInstructionDataDecoder().decode(instruction.data),
   };
@@ -12,9 +12,13 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -73,15 +77,29 @@ export type WireInstruction<
 export type WireInstructionData = {
   discriminator: ReadonlyUint8Array;
   reitIdHash: ReadonlyUint8Array;
+  wiredAmount: bigint;
+  fxRate: bigint;
+  wireDate: bigint;
+  wireReferenceHash: ReadonlyUint8Array;
 };
 
-export type WireInstructionDataArgs = { reitIdHash: ReadonlyUint8Array };
+export type WireInstructionDataArgs = {
+  reitIdHash: ReadonlyUint8Array;
+  wiredAmount: number | bigint;
+  fxRate: number | bigint;
+  wireDate: number | bigint;
+  wireReferenceHash: ReadonlyUint8Array;
+};
 
 export function getWireInstructionDataEncoder(): FixedSizeEncoder<WireInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['reitIdHash', fixEncoderSize(getBytesEncoder(), 16)],
+      ['wiredAmount', getU64Encoder()],
+      ['fxRate', getU64Encoder()],
+      ['wireDate', getI64Encoder()],
+      ['wireReferenceHash', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
     (value) => ({ ...value, discriminator: WIRE_DISCRIMINATOR })
   );
@@ -91,6 +109,10 @@ export function getWireInstructionDataDecoder(): FixedSizeDecoder<WireInstructio
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['reitIdHash', fixDecoderSize(getBytesDecoder(), 16)],
+    ['wiredAmount', getU64Decoder()],
+    ['fxRate', getU64Decoder()],
+    ['wireDate', getI64Decoder()],
+    ['wireReferenceHash', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -113,6 +135,10 @@ export type WireAsyncInput<
   fundraiser?: Address<TAccountFundraiser>;
   investment: Address<TAccountInvestment>;
   reitIdHash: WireInstructionDataArgs['reitIdHash'];
+  wiredAmount: WireInstructionDataArgs['wiredAmount'];
+  fxRate: WireInstructionDataArgs['fxRate'];
+  wireDate: WireInstructionDataArgs['wireDate'];
+  wireReferenceHash: WireInstructionDataArgs['wireReferenceHash'];
 };
 
 export async function getWireInstructionAsync<
@@ -192,6 +218,10 @@ export type WireInput<
   fundraiser: Address<TAccountFundraiser>;
   investment: Address<TAccountInvestment>;
   reitIdHash: WireInstructionDataArgs['reitIdHash'];
+  wiredAmount: WireInstructionDataArgs['wiredAmount'];
+  fxRate: WireInstructionDataArgs['fxRate'];
+  wireDate: WireInstructionDataArgs['wireDate'];
+  wireReferenceHash: WireInstructionDataArgs['wireReferenceHash'];
 };
 
 export function getWireInstruction<
@@ -14,16 +14,48 @@ import {
   type ReadonlyUint8Array,
 } from 'gill';
 import {
+  type ParsedAddAcceptedMintInstruction,
+  type ParsedAddJurisdictionRuleInstruction,
+  type ParsedAttestInvestorInstruction,
+  type ParsedCloseFundraiserInstruction,
+  type ParsedCloseInvestmentInstruction,
   type ParsedCloseInvestorInstruction,
+  type ParsedCreateReitMint2022Instruction,
   type ParsedCreateReitMintInstruction,
+  type ParsedFreezeHolderInstruction,
+  type ParsedInitializeComplianceInstruction,
   type ParsedInitializeFundraiserInstruction,
+  type ParsedInitializeFxRateInstruction,
   type ParsedInitializeInvestorInstruction,
   type ParsedInvestInstruction,
   type ParsedIssueDividendInstruction,
   type ParsedIssueShareInstruction,
+  type ParsedMigrateComplianceConfigInstruction,
+  type ParsedMigrateFundraiserInstruction,
+  type ParsedMigrateFxRateInstruction,
+  type ParsedMigrateHolderLockupInstruction,
+  type ParsedMigrateInvestmentInstruction,
+  type ParsedMigrateInvestorFundraiserInstruction,
+  type ParsedMigrateInvestorInstruction,
+  type ParsedMigrateKycAttestationInstruction,
+  type ParsedMigrateWireBatchRecordInstruction,
+  type ParsedPublishFxRateInstruction,
+  type ParsedRecordShareSaleInstruction,
+  type ParsedRecoverPositionInstruction,
   type ParsedRefundInstruction,
   type ParsedReleaseInstruction,
+  type ParsedRemoveJurisdictionRuleInstruction,
+  type ParsedSetComplianceAuthorityInstruction,
+  type ParsedSetFundraiserStatusInstruction,
+  type ParsedSetFxRatePublisherInstruction,
+  type ParsedSetHoldPeriodInstruction,
+  type ParsedSetJurisdictionRulesInstruction,
+  type ParsedSetOfferingExemptionInstruction,
+  type ParsedThawHolderInstruction,
+  type ParsedUnlockSharesInstruction,
+  type ParsedUpdateFundraiserTermsInstruction,
   type ParsedUpdateReitMintInstruction,
+  type ParsedWireBatchInstruction,
   type ParsedWireInstruction,
 } from '../instructions';
 
@@ -31,16 +63,32 @@ export const CANADIANREITINVEST_PROGRAM_ADDRESS =
   'FuEhMFWU9Ui35a9mpavfy7AYGqEX8diUSk1CZonEUivH' as Address<'FuEhMFWU9Ui35a9mpavfy7AYGqEX8diUSk1CZonEUivH'>;
 
 export enum CanadianreitinvestAccount {
+  ComplianceConfig,
   Fundraiser,
+  FxRate,
+  HolderLockup,
   Investment,
   Investor,
   InvestorFundraiser,
+  KycAttestation,
+  WireBatchRecord,
 }
 
 export function identifyCanadianreitinvestAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): CanadianreitinvestAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([157, 84, 248, 198, 253, 41, 75, 251])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestAccount.ComplianceConfig;
+  }
   if (
     containsBytes(
       data,
@@ -52,6 +100,28 @@ export function identifyCanadianreitinvestAccount(
   ) {
     return CanadianreitinvestAccount.Fundraiser;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([24, 10, 81, 159, 83, 233, 223, 64])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestAccount.FxRate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([48, 221, 247, 126, 235, 55, 211, 124])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestAccount.HolderLockup;
+  }
   if (
     containsBytes(
       data,
@@ -85,29 +155,138 @@ export function identifyCanadianreitinvestAccount(
   ) {
     return CanadianreitinvestAccount.InvestorFundraiser;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([114, 140, 31, 243, 17, 104, 193, 72])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestAccount.KycAttestation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([47, 127, 38, 225, 159, 232, 139, 182])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestAccount.WireBatchRecord;
+  }
   throw new Error(
     'The provided account could not be identified as a canadianreitinvest account.'
   );
 }
 
 export enum CanadianreitinvestInstruction {
+  AddAcceptedMint,
+  AddJurisdictionRule,
+  AttestInvestor,
+  CloseFundraiser,
+  CloseInvestment,
   CloseInvestor,
   CreateReitMint,
+  CreateReitMint2022,
+  FreezeHolder,
+  InitializeCompliance,
   InitializeFundraiser,
+  InitializeFxRate,
   InitializeInvestor,
   Invest,
   IssueDividend,
   IssueShare,
+  MigrateComplianceConfig,
+  MigrateFundraiser,
+  MigrateFxRate,
+  MigrateHolderLockup,
+  MigrateInvestment,
+  MigrateInvestor,
+  MigrateInvestorFundraiser,
+  MigrateKycAttestation,
+  MigrateWireBatchRecord,
+  PublishFxRate,
+  RecordShareSale,
+  RecoverPosition,
   Refund,
   Release,
+  RemoveJurisdictionRule,
+  SetComplianceAuthority,
+  SetFundraiserStatus,
+  SetFxRatePublisher,
+  SetHoldPeriod,
+  SetJurisdictionRules,
+  SetOfferingExemption,
+  ThawHolder,
+  UnlockShares,
+  UpdateFundraiserTerms,
   UpdateReitMint,
   Wire,
+  WireBatch,
 }
 
 export function identifyCanadianreitinvestInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): CanadianreitinvestInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([241, 72, 208, 73, 223, 58, 81, 69])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.AddAcceptedMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([50, 231, 107, 157, 230, 181, 35, 10])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.AddJurisdictionRule;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([167, 51, 66, 29, 194, 24, 233, 23])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.AttestInvestor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([147, 202, 141, 164, 126, 180, 241, 145])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.CloseFundraiser;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([17, 74, 194, 14, 59, 174, 193, 202])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.CloseInvestment;
+  }
   if (
     containsBytes(
       data,
@@ -130,6 +309,39 @@ export function identifyCanadianreitinvestInstruction(
   ) {
     return CanadianreitinvestInstruction.CreateReitMint;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([9, 42, 253, 7, 48, 76, 142, 123])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.CreateReitMint2022;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([42, 135, 144, 146, 54, 1, 196, 117])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.FreezeHolder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([77, 93, 116, 221, 108, 121, 232, 145])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.InitializeCompliance;
+  }
   if (
     containsBytes(
       data,
@@ -141,6 +353,17 @@ export function identifyCanadianreitinvestInstruction(
   ) {
     return CanadianreitinvestInstruction.InitializeFundraiser;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([161, 67, 116, 17, 67, 111, 234, 227])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.InitializeFxRate;
+  }
   if (
     containsBytes(
       data,
@@ -185,6 +408,138 @@ export function identifyCanadianreitinvestInstruction(
   ) {
     return CanadianreitinvestInstruction.IssueShare;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 159, 26, 241, 61, 111, 202, 132])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateComplianceConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([84, 124, 162, 190, 29, 127, 95, 5])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateFundraiser;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 170, 125, 3, 238, 142, 27, 203])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateFxRate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([88, 150, 227, 87, 6, 13, 213, 46])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateHolderLockup;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([156, 28, 13, 197, 195, 213, 111, 31])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateInvestment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 192, 131, 183, 220, 158, 157, 39])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateInvestor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([130, 35, 21, 44, 228, 37, 93, 104])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateInvestorFundraiser;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([239, 174, 64, 107, 39, 25, 193, 197])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateKycAttestation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 40, 193, 154, 114, 178, 93, 177])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.MigrateWireBatchRecord;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 152, 243, 204, 165, 138, 156, 107])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.PublishFxRate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([16, 104, 235, 232, 159, 254, 149, 202])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.RecordShareSale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 92, 222, 116, 116, 129, 192, 175])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.RecoverPosition;
+  }
   if (
     containsBytes(
       data,
@@ -207,6 +562,116 @@ export function identifyCanadianreitinvestInstruction(
   ) {
     return CanadianreitinvestInstruction.Release;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([110, 117, 92, 119, 191, 88, 8, 221])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.RemoveJurisdictionRule;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([107, 141, 114, 171, 0, 68, 105, 10])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetComplianceAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([149, 184, 96, 81, 255, 78, 0, 101])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetFundraiserStatus;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([212, 193, 118, 244, 206, 60, 146, 57])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetFxRatePublisher;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([216, 158, 76, 224, 25, 221, 212, 35])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetHoldPeriod;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 197, 215, 227, 110, 182, 69, 20])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetJurisdictionRules;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([59, 221, 15, 237, 119, 197, 87, 213])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.SetOfferingExemption;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([56, 60, 31, 119, 186, 131, 171, 109])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.ThawHolder;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 248, 156, 242, 54, 2, 176, 160])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.UnlockShares;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([176, 86, 164, 134, 196, 217, 251, 105])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.UpdateFundraiserTerms;
+  }
   if (
     containsBytes(
       data,
@@ -229,6 +694,17 @@ export function identifyCanadianreitinvestInstruction(
   ) {
     return CanadianreitinvestInstruction.Wire;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([3, 224, 11, 185, 205, 104, 241, 64])
+      ),
+      0
+    )
+  ) {
+    return CanadianreitinvestInstruction.WireBatch;
+  }
   throw new Error(
     'The provided instruction could not be identified as a canadianreitinvest instruction.'
   );
@@ -237,15 +713,42 @@ export function identifyCanadianreitinvestInstruction(
 export type ParsedCanadianreitinvestInstruction<
   TProgram extends string = 'FuEhMFWU9Ui35a9mpavfy7AYGqEX8diUSk1CZonEUivH',
 > =
+  | ({
+      instructionType: CanadianreitinvestInstruction.AddAcceptedMint;
+    } & ParsedAddAcceptedMintInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.AddJurisdictionRule;
+    } & ParsedAddJurisdictionRuleInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.AttestInvestor;
+    } & ParsedAttestInvestorInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.CloseFundraiser;
+    } & ParsedCloseFundraiserInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.CloseInvestment;
+    } & ParsedCloseInvestmentInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.CloseInvestor;
     } & ParsedCloseInvestorInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.CreateReitMint;
     } & ParsedCreateReitMintInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.CreateReitMint2022;
+    } & ParsedCreateReitMint2022Instruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.FreezeHolder;
+    } & ParsedFreezeHolderInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.InitializeCompliance;
+    } & ParsedInitializeComplianceInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.InitializeFundraiser;
     } & ParsedInitializeFundraiserInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.InitializeFxRate;
+    } & ParsedInitializeFxRateInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.InitializeInvestor;
     } & ParsedInitializeInvestorInstruction<TProgram>)
@@ -258,15 +761,84 @@ export type ParsedCanadianreitinvestInstruction<
   | ({
       instructionType: CanadianreitinvestInstruction.IssueShare;
     } & ParsedIssueShareInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateComplianceConfig;
+    } & ParsedMigrateComplianceConfigInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateFundraiser;
+    } & ParsedMigrateFundraiserInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateFxRate;
+    } & ParsedMigrateFxRateInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateHolderLockup;
+    } & ParsedMigrateHolderLockupInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateInvestment;
+    } & ParsedMigrateInvestmentInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateInvestor;
+    } & ParsedMigrateInvestorInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateInvestorFundraiser;
+    } & ParsedMigrateInvestorFundraiserInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateKycAttestation;
+    } & ParsedMigrateKycAttestationInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.MigrateWireBatchRecord;
+    } & ParsedMigrateWireBatchRecordInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.PublishFxRate;
+    } & ParsedPublishFxRateInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.RecordShareSale;
+    } & ParsedRecordShareSaleInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.RecoverPosition;
+    } & ParsedRecoverPositionInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.Refund;
     } & ParsedRefundInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.Release;
     } & ParsedReleaseInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.RemoveJurisdictionRule;
+    } & ParsedRemoveJurisdictionRuleInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetComplianceAuthority;
+    } & ParsedSetComplianceAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetFundraiserStatus;
+    } & ParsedSetFundraiserStatusInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetFxRatePublisher;
+    } & ParsedSetFxRatePublisherInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetHoldPeriod;
+    } & ParsedSetHoldPeriodInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetJurisdictionRules;
+    } & ParsedSetJurisdictionRulesInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.SetOfferingExemption;
+    } & ParsedSetOfferingExemptionInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.ThawHolder;
+    } & ParsedThawHolderInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.UnlockShares;
+    } & ParsedUnlockSharesInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.UpdateFundraiserTerms;
+    } & ParsedUpdateFundraiserTermsInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.UpdateReitMint;
     } & ParsedUpdateReitMintInstruction<TProgram>)
   | ({
       instructionType: CanadianreitinvestInstruction.Wire;
-    } & ParsedWireInstruction<TProgram>);
+    } & ParsedWireInstruction<TProgram>)
+  | ({
+      instructionType: CanadianreitinvestInstruction.WireBatch;
+    } & ParsedWireBatchInstruction<TProgram>);
@@ -6,5 +6,31 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptedMint';
+export * from './accountMigrated';
+export * from './accreditationCategory';
 export * from './dividendIssued';
+export * from './fundraiserInitialized';
+export * from './fundraiserStatus';
+export * from './fundraiserStatusChanged';
+export * from './holderFrozen';
+export * from './holderThawed';
+export * from './investmentCreated';
+export * from './investmentLimits';
+export * from './investmentReceipt';
+export * from './investmentRefunded';
+export * from './investmentReleased';
 export * from './investmentStatus';
+export * from './investmentWired';
+export * from './jurisdiction';
+export * from './jurisdictionMode';
+export * from './kycStatus';
+export * from './offeringExemption';
+export * from './positionRecovered';
+export * from './reitMetadataUpdated';
+export * from './reitMintCreated';
+export * from './rollingTotal';
+export * from './sharesIssued';
+export * from './sharesSold';
+export * from './statusChange';
+export * from './wireBatchRecorded';
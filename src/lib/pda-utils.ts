@@ -70,4 +70,16 @@ export function deriveFundraiserPda(fundraiser: PublicKey): [PublicKey, number]
     [Buffer.from('fundraiser'), fundraiser.toBuffer()],
     new PublicKey(CANADIANREITINVEST_PROGRAM_ADDRESS)
   )
-}
\ No newline at end of file
+}
+
+/**
+ * Derives the KycAttestation PDA written by the compliance authority
+ * @param investorPda - The investor's Investor PDA (not the wallet)
+ * @returns The derived PDA and bump
+ */
+export function deriveKycAttestationPda(investorPda: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('kyc_attestation'), investorPda.toBuffer()],
+    new PublicKey(CANADIANREITINVEST_PROGRAM_ADDRESS)
+  )
+}